@@ -0,0 +1,16 @@
+ledger/build.rs
+```rust
+fn main() {
+    // Protobuf code is only generated when the grpc feature is enabled, so
+    // default builds do not need protoc
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/ledger/v1/ledger.proto");
+        tonic_build::configure()
+            .build_server(true)
+            .build_client(true)
+            .compile(&["proto/ledger/v1/ledger.proto"], &["proto"])
+            .expect("Failed to compile ledger.proto");
+    }
+}
+```
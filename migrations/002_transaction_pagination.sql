@@ -0,0 +1,8 @@
+ledger/migrations/002_transaction_pagination.sql
+```sql
+-- Keyset pagination over transaction history orders by (timestamp, id)
+CREATE INDEX idx_transactions_timestamp_id ON transactions(timestamp DESC, id DESC);
+
+-- Lets the per-account EXISTS probe be answered from the index alone
+CREATE INDEX idx_entries_account_transaction ON entries(account_id, transaction_id);
+```
@@ -0,0 +1,5 @@
+ledger/migrations/003_entry_history.sql
+```sql
+-- Keyset pagination over an account's entries orders by (timestamp, id)
+CREATE INDEX idx_entries_account_timestamp_id ON entries(account_id, timestamp, id);
+```
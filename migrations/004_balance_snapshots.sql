@@ -0,0 +1,61 @@
+ledger/migrations/004_balance_snapshots.sql
+```sql
+-- Current balance per account, maintained by record_transaction in the same
+-- database transaction as the entries it summarises
+CREATE TABLE account_current_balances (
+    account_id UUID PRIMARY KEY REFERENCES accounts(id) ON DELETE RESTRICT,
+    balance DECIMAL(20, 8) NOT NULL DEFAULT 0,
+    entry_count BIGINT NOT NULL DEFAULT 0,
+    last_entry_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Point-in-time balances; historical reads start from the latest checkpoint
+-- and only sum the entries posted after it
+CREATE TABLE balance_checkpoints (
+    account_id UUID NOT NULL REFERENCES accounts(id) ON DELETE RESTRICT,
+    checkpoint_at TIMESTAMPTZ NOT NULL,
+    balance DECIMAL(20, 8) NOT NULL,
+    entry_count BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (account_id, checkpoint_at)
+);
+
+-- Balances are credits minus debits, the sign entries.balance_after is
+-- written with. account_balances summed debits minus credits; rebuild it
+-- with the same convention.
+DROP MATERIALIZED VIEW account_balances;
+
+CREATE MATERIALIZED VIEW account_balances AS
+SELECT 
+    a.id as account_id,
+    a.currency,
+    COALESCE(SUM(
+        CASE 
+            WHEN e.entry_type = 'Credit' THEN e.amount
+            ELSE -e.amount
+        END
+    ), 0) as balance,
+    MAX(e.timestamp) as last_activity
+FROM accounts a
+LEFT JOIN entries e ON a.id = e.account_id
+GROUP BY a.id, a.currency;
+
+CREATE UNIQUE INDEX idx_account_balances_account_id ON account_balances(account_id);
+
+-- Backfill from existing entries
+INSERT INTO account_current_balances (account_id, balance, entry_count, last_entry_at)
+SELECT 
+    a.id,
+    COALESCE(SUM(
+        CASE 
+            WHEN e.entry_type = 'Credit' THEN e.amount
+            ELSE -e.amount
+        END
+    ), 0),
+    COUNT(e.id),
+    MAX(e.timestamp)
+FROM accounts a
+LEFT JOIN entries e ON a.id = e.account_id
+GROUP BY a.id;
+```
@@ -0,0 +1,17 @@
+ledger/migrations/005_balance_view_refreshes.sql
+```sql
+-- Tracks when each materialized view was last refreshed so readers can tell
+-- how stale the data they are looking at is
+CREATE TABLE materialized_view_refreshes (
+    view_name VARCHAR(63) PRIMARY KEY,
+    refreshed_at TIMESTAMPTZ NOT NULL,
+    duration_ms BIGINT NOT NULL DEFAULT 0
+);
+
+-- account_balances was populated when it was created; refresh it now so the
+-- recorded timestamp is accurate
+REFRESH MATERIALIZED VIEW account_balances;
+
+INSERT INTO materialized_view_refreshes (view_name, refreshed_at)
+VALUES ('account_balances', NOW());
+```
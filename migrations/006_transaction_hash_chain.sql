@@ -0,0 +1,21 @@
+ledger/migrations/006_transaction_hash_chain.sql
+```sql
+-- Tamper-evident chain over recorded transactions. Each link hashes the
+-- canonical form of its transaction and entries together with the previous
+-- link's hash, so editing or removing any transaction breaks every later link.
+CREATE TABLE transaction_chain (
+    seq BIGINT PRIMARY KEY CHECK (seq > 0),
+    transaction_id UUID NOT NULL UNIQUE REFERENCES transactions(id) ON DELETE RESTRICT,
+    prev_hash BYTEA NOT NULL CHECK (octet_length(prev_hash) = 32),
+    hash BYTEA NOT NULL CHECK (octet_length(hash) = 32),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Chain heads captured for external notarization
+CREATE TABLE chain_anchors (
+    id BIGSERIAL PRIMARY KEY,
+    seq BIGINT NOT NULL REFERENCES transaction_chain(seq) ON DELETE RESTRICT,
+    hash BYTEA NOT NULL CHECK (octet_length(hash) = 32),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+```
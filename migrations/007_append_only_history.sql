@@ -0,0 +1,62 @@
+ledger/migrations/007_append_only_history.sql
+```sql
+-- Recorded history is append-only. UPDATE is always rejected; DELETE is only
+-- allowed for archival, which requires both membership of ledger_archiver and
+-- opting in for the current transaction:
+--
+--     BEGIN;
+--     SET LOCAL ledger.archival_mode = 'on';
+--     DELETE FROM ...;
+--     COMMIT;
+DO $$
+BEGIN
+    IF NOT EXISTS (SELECT 1 FROM pg_roles WHERE rolname = 'ledger_archiver') THEN
+        CREATE ROLE ledger_archiver NOLOGIN;
+    END IF;
+END
+$$;
+
+CREATE FUNCTION ledger_reject_history_mutation() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE'
+       AND current_setting('ledger.archival_mode', true) = 'on'
+       AND pg_has_role(current_user, 'ledger_archiver', 'MEMBER') THEN
+        RETURN OLD;
+    END IF;
+
+    RAISE EXCEPTION 'ledger history is append-only: % on % is not allowed', TG_OP, TG_TABLE_NAME
+        USING ERRCODE = 'LG001';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION ledger_reject_history_truncate() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'ledger history is append-only: TRUNCATE on % is not allowed', TG_TABLE_NAME
+        USING ERRCODE = 'LG001';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER transactions_append_only
+    BEFORE UPDATE OR DELETE ON transactions
+    FOR EACH ROW EXECUTE FUNCTION ledger_reject_history_mutation();
+
+CREATE TRIGGER entries_append_only
+    BEFORE UPDATE OR DELETE ON entries
+    FOR EACH ROW EXECUTE FUNCTION ledger_reject_history_mutation();
+
+CREATE TRIGGER transaction_chain_append_only
+    BEFORE UPDATE OR DELETE ON transaction_chain
+    FOR EACH ROW EXECUTE FUNCTION ledger_reject_history_mutation();
+
+CREATE TRIGGER transactions_no_truncate
+    BEFORE TRUNCATE ON transactions
+    FOR EACH STATEMENT EXECUTE FUNCTION ledger_reject_history_truncate();
+
+CREATE TRIGGER entries_no_truncate
+    BEFORE TRUNCATE ON entries
+    FOR EACH STATEMENT EXECUTE FUNCTION ledger_reject_history_truncate();
+
+CREATE TRIGGER transaction_chain_no_truncate
+    BEFORE TRUNCATE ON transaction_chain
+    FOR EACH STATEMENT EXECUTE FUNCTION ledger_reject_history_truncate();
+```
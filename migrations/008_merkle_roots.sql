@@ -0,0 +1,16 @@
+ledger/migrations/008_merkle_roots.sql
+```sql
+-- Published Merkle roots over the transactions of each period
+CREATE TABLE merkle_roots (
+    id BIGSERIAL PRIMARY KEY,
+    period_start TIMESTAMPTZ NOT NULL,
+    period_end TIMESTAMPTZ NOT NULL,
+    tree_size BIGINT NOT NULL CHECK (tree_size >= 0),
+    root VARCHAR(64) NOT NULL,
+    published_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (period_end > period_start),
+    UNIQUE (period_start, period_end)
+);
+
+CREATE INDEX idx_merkle_roots_period ON merkle_roots(period_start, period_end);
+```
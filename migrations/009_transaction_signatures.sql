@@ -0,0 +1,20 @@
+ledger/migrations/009_transaction_signatures.sql
+```sql
+-- Detached Ed25519 signatures by the instance that recorded each transaction
+CREATE TABLE transaction_signatures (
+    transaction_id UUID PRIMARY KEY REFERENCES transactions(id) ON DELETE RESTRICT,
+    key_id VARCHAR(255) NOT NULL,
+    signature BYTEA NOT NULL CHECK (octet_length(signature) = 64),
+    signed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_transaction_signatures_key_id ON transaction_signatures(key_id);
+
+CREATE TRIGGER transaction_signatures_append_only
+    BEFORE UPDATE OR DELETE ON transaction_signatures
+    FOR EACH ROW EXECUTE FUNCTION ledger_reject_history_mutation();
+
+CREATE TRIGGER transaction_signatures_no_truncate
+    BEFORE TRUNCATE ON transaction_signatures
+    FOR EACH STATEMENT EXECUTE FUNCTION ledger_reject_history_truncate();
+```
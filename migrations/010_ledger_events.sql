@@ -0,0 +1,34 @@
+ledger/migrations/010_ledger_events.sql
+```sql
+-- Transactional outbox. Events are inserted in the same database transaction
+-- as the change they describe and delivered downstream by the event relay.
+-- seq is allocated while holding the chain advisory lock, so events become
+-- visible in seq order.
+CREATE TABLE ledger_events (
+    seq BIGSERIAL PRIMARY KEY,
+    id UUID NOT NULL UNIQUE,
+    event_type VARCHAR(64) NOT NULL,
+    aggregate_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    -- Set once the relay gives up on the event; it is skipped until replayed
+    dead_lettered_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_ledger_events_pending ON ledger_events(seq) WHERE delivered_at IS NULL AND dead_lettered_at IS NULL;
+CREATE INDEX idx_ledger_events_aggregate ON ledger_events(aggregate_id, seq);
+
+-- Delivery bookkeeping may change; what happened may not
+CREATE TRIGGER ledger_events_immutable_payload
+    BEFORE UPDATE ON ledger_events
+    FOR EACH ROW
+    WHEN (OLD.id IS DISTINCT FROM NEW.id
+          OR OLD.event_type IS DISTINCT FROM NEW.event_type
+          OR OLD.aggregate_id IS DISTINCT FROM NEW.aggregate_id
+          OR OLD.payload IS DISTINCT FROM NEW.payload
+          OR OLD.occurred_at IS DISTINCT FROM NEW.occurred_at)
+    EXECUTE FUNCTION ledger_reject_history_mutation();
+```
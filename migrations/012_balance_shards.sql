@@ -0,0 +1,24 @@
+ledger/migrations/012_balance_shards.sql
+```sql
+-- Hot accounts can spread their current balance over several rows so that
+-- concurrent postings do not all queue on the same row lock. Each posting
+-- updates one shard; the balance is the sum of the account's shards.
+ALTER TABLE accounts
+    ADD COLUMN balance_shards INTEGER NOT NULL DEFAULT 1
+    CHECK (balance_shards BETWEEN 1 AND 64);
+
+-- Existing rows become shard 0, which every account has
+ALTER TABLE account_current_balances
+    ADD COLUMN shard INTEGER NOT NULL DEFAULT 0 CHECK (shard >= 0);
+
+ALTER TABLE account_current_balances DROP CONSTRAINT account_current_balances_pkey;
+ALTER TABLE account_current_balances ADD PRIMARY KEY (account_id, shard);
+
+-- The shard each entry updated; its balance_after is that shard's running
+-- balance. Existing entries were all posted to shard 0.
+ALTER TABLE entries ADD COLUMN balance_shard INTEGER NOT NULL DEFAULT 0;
+
+-- Most accounts never leave shard 0, so this stays small
+CREATE INDEX idx_entries_account_balance_shard ON entries (account_id, balance_shard, timestamp)
+    WHERE balance_shard <> 0;
+```
@@ -0,0 +1,8 @@
+ledger/migrations/013_single_reversal.sql
+```sql
+-- A transaction can be reversed at most once. Reversals name the transaction
+-- they undo in metadata.reverses; the index also serves lookups by it.
+CREATE UNIQUE INDEX idx_transactions_reverses
+    ON transactions ((metadata->>'reverses'))
+    WHERE metadata ? 'reverses';
+```
@@ -0,0 +1,28 @@
+ledger/migrations/014_chain_sequencer.sql
+```sql
+-- Postings no longer append to transaction_chain under a ledger-wide lock.
+-- They queue their transaction here, and PostgresLedgerStore::append_chain_links
+-- links queued transactions in queue order once they have committed.
+CREATE TABLE pending_chain_links (
+    queue_seq BIGSERIAL PRIMARY KEY,
+    transaction_id UUID NOT NULL UNIQUE REFERENCES transactions(id) ON DELETE RESTRICT
+);
+
+-- Outbox events take the same route. Writers queue them here, and
+-- PostgresLedgerStore::append_outbox_events moves committed ones into
+-- ledger_events under the chain lock, which is where seq is allocated. The
+-- outbox therefore still becomes visible in seq order.
+CREATE TABLE pending_ledger_events (
+    queue_seq BIGSERIAL PRIMARY KEY,
+    id UUID NOT NULL UNIQUE,
+    event_type VARCHAR(64) NOT NULL,
+    aggregate_id UUID NOT NULL,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER pending_ledger_events_immutable
+    BEFORE UPDATE ON pending_ledger_events
+    FOR EACH ROW
+    EXECUTE FUNCTION ledger_reject_history_mutation();
+```
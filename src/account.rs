@@ -12,7 +12,7 @@ pub struct Account {
     pub metadata: serde_json::Value,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AccountType {
     Asset,
     Liability,
@@ -31,5 +31,34 @@ impl Account {
             metadata: serde_json::json!({}),
         }
     }
+
+    // Chart-of-accounts position is optional and lives in metadata:
+    // {"code": "1100", "parent_code": "1000", "name": "Cash"}
+    pub fn code(&self) -> Option<&str> {
+        self.metadata.get("code").and_then(|v| v.as_str())
+    }
+
+    pub fn parent_code(&self) -> Option<&str> {
+        self.metadata.get("parent_code").and_then(|v| v.as_str())
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.metadata.get("name").and_then(|v| v.as_str())
+    }
+}
+
+impl AccountType {
+    pub fn is_debit_normal(&self) -> bool {
+        matches!(self, AccountType::Asset | AccountType::Expense)
+    }
+
+    // Balance in the account's natural sign, given raw debit and credit totals
+    pub fn normal_balance(&self, debit_total: Decimal, credit_total: Decimal) -> Decimal {
+        if self.is_debit_normal() {
+            debit_total - credit_total
+        } else {
+            credit_total - debit_total
+        }
+    }
 }
 ```
@@ -0,0 +1,227 @@
+ledger/src/account_statement.rs
+```rust
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+use crate::{AccountEntry, EntryType, LedgerError, LedgerStore, TransactionType};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountStatement {
+    pub account_id: Uuid,
+    pub currency: String,
+    pub period_start: chrono::DateTime<chrono::Utc>,
+    pub period_end: chrono::DateTime<chrono::Utc>,
+    pub opening_balance: Decimal,
+    pub lines: Vec<StatementEntry>,
+    pub total_debits: Decimal,
+    pub total_credits: Decimal,
+    pub closing_balance: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementEntry {
+    pub entry_id: Uuid,
+    pub transaction_id: Uuid,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub transaction_type: TransactionType,
+    pub reason_code: String,
+    pub counterparty_account_id: Option<Uuid>,
+    pub entry_type: EntryType,
+    pub amount: Decimal,
+    pub running_balance: Decimal,
+    // Set when the stored balance_after disagreed with the running sum of
+    // the entry's balance shard; running_balance is always computed
+    pub balance_after_mismatch: bool,
+}
+
+// Statement for entries in (period_start, period_end], opening at the
+// balance as of period_start
+pub async fn account_statement(
+    store: &(impl LedgerStore + ?Sized),
+    account_id: Uuid,
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+) -> Result<AccountStatement, LedgerError> {
+    let account = store
+        .get_account(&account_id)
+        .await?
+        .ok_or(LedgerError::AccountNotFound)?;
+
+    let opening_shard_balances = store
+        .get_account_shard_balances_at(&account_id, period_start)
+        .await?;
+    let entries = store
+        .get_account_entries_between(&account_id, period_start, period_end)
+        .await?;
+
+    Ok(AccountStatement::build(
+        account_id,
+        &account.currency,
+        period_start,
+        period_end,
+        opening_shard_balances,
+        entries,
+    ))
+}
+
+impl AccountStatement {
+    // Opens at the sum of the shard balances; each entry's balance_after is
+    // checked against the running balance of its own shard
+    pub fn build(
+        account_id: Uuid,
+        currency: &str,
+        period_start: chrono::DateTime<chrono::Utc>,
+        period_end: chrono::DateTime<chrono::Utc>,
+        mut opening_shard_balances: HashMap<i32, Decimal>,
+        entries: Vec<AccountEntry>,
+    ) -> Self {
+        let opening_balance: Decimal = opening_shard_balances.values().copied().sum();
+        let mut lines = Vec::with_capacity(entries.len());
+        let mut running_balance = opening_balance;
+        let mut total_debits = Decimal::ZERO;
+        let mut total_credits = Decimal::ZERO;
+
+        for AccountEntry { entry, transaction } in entries {
+            match entry.entry_type {
+                EntryType::Debit => total_debits += entry.amount,
+                EntryType::Credit => total_credits += entry.amount,
+            }
+
+            running_balance += entry.signed_amount();
+            let shard_balance = opening_shard_balances.entry(entry.balance_shard).or_default();
+            *shard_balance += entry.signed_amount();
+            let balance_after_mismatch = entry.balance_after != *shard_balance;
+
+            lines.push(StatementEntry {
+                entry_id: entry.id,
+                transaction_id: transaction.id,
+                timestamp: entry.timestamp,
+                transaction_type: transaction.transaction_type,
+                counterparty_account_id: transaction.counterparty_account_id(account_id),
+                reason_code: transaction.reason_code,
+                entry_type: entry.entry_type,
+                amount: entry.amount,
+                running_balance,
+                balance_after_mismatch,
+            });
+        }
+
+        Self {
+            account_id,
+            currency: currency.to_string(),
+            period_start,
+            period_end,
+            opening_balance,
+            lines,
+            total_debits,
+            total_credits,
+            closing_balance: running_balance,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "timestamp,transaction_id,transaction_type,reason_code,counterparty_account_id,debit,credit,running_balance\n",
+        );
+
+        csv.push_str(&format!(",,,OPENING_BALANCE,,,,{}\n", self.opening_balance));
+
+        for line in &self.lines {
+            let (debit, credit) = debit_credit_columns(line);
+            csv.push_str(&format!(
+                "{},{},{:?},{},{},{},{},{}\n",
+                line.timestamp.to_rfc3339(),
+                line.transaction_id,
+                line.transaction_type,
+                csv_field(&line.reason_code),
+                line.counterparty_account_id.map(|id| id.to_string()).unwrap_or_default(),
+                debit,
+                credit,
+                line.running_balance,
+            ));
+        }
+
+        csv.push_str(&format!(
+            ",,,CLOSING_BALANCE,,{},{},{}\n",
+            self.total_debits, self.total_credits, self.closing_balance
+        ));
+
+        csv
+    }
+
+    // Fixed-width layout for printed customer statements
+    pub fn to_text(&self) -> String {
+        let rule = "-".repeat(STATEMENT_WIDTH);
+        let mut text = String::new();
+
+        text.push_str("ACCOUNT STATEMENT\n");
+        text.push_str(&format!("Account:  {} ({})\n", self.account_id, self.currency));
+        text.push_str(&format!(
+            "Period:   {} to {}\n",
+            self.period_start.format(DATE_FORMAT),
+            self.period_end.format(DATE_FORMAT)
+        ));
+        text.push_str(&rule);
+        text.push('\n');
+        text.push_str(&format!(
+            "{:<19}  {:<20}  {:<36}  {:>14}  {:>14}  {:>16}\n",
+            "Date", "Reason", "Counterparty", "Debit", "Credit", "Balance"
+        ));
+        text.push_str(&rule);
+        text.push('\n');
+        text.push_str(&format!(
+            "{:<19}  {:<20}  {:<36}  {:>14}  {:>14}  {:>16}\n",
+            "", "Opening balance", "", "", "", self.opening_balance
+        ));
+
+        for line in &self.lines {
+            let (debit, credit) = debit_credit_columns(line);
+            text.push_str(&format!(
+                "{:<19}  {:<20}  {:<36}  {:>14}  {:>14}  {:>16}\n",
+                line.timestamp.format(DATE_FORMAT).to_string(),
+                truncate(&line.reason_code, 20),
+                line.counterparty_account_id.map(|id| id.to_string()).unwrap_or_default(),
+                debit,
+                credit,
+                line.running_balance,
+            ));
+        }
+
+        text.push_str(&rule);
+        text.push('\n');
+        text.push_str(&format!(
+            "{:<19}  {:<20}  {:<36}  {:>14}  {:>14}  {:>16}\n",
+            "", "Closing balance", "", self.total_debits, self.total_credits, self.closing_balance
+        ));
+
+        text
+    }
+}
+
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+const STATEMENT_WIDTH: usize = 19 + 2 + 20 + 2 + 36 + 2 + 14 + 2 + 14 + 2 + 16;
+
+fn debit_credit_columns(line: &StatementEntry) -> (String, String) {
+    match line.entry_type {
+        EntryType::Debit => (line.amount.to_string(), String::new()),
+        EntryType::Credit => (String::new(), line.amount.to_string()),
+    }
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    value.chars().take(width).collect()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') || value.contains('\n') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+```
@@ -0,0 +1,637 @@
+ledger/src/bank_statement.rs
+```rust
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+// Direction as the bank reports it: Credit is money into the account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StatementDirection {
+    Credit,
+    Debit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatementLine {
+    pub value_date: chrono::NaiveDate,
+    pub booking_date: Option<chrono::NaiveDate>,
+    // Always positive; see direction
+    pub amount: Decimal,
+    pub direction: StatementDirection,
+    pub reference: Option<String>,
+    pub counterparty: Option<String>,
+    pub description: Option<String>,
+}
+
+impl StatementLine {
+    // Effect on the statement balance: credits positive, debits negative
+    pub fn signed_amount(&self) -> Decimal {
+        match self.direction {
+            StatementDirection::Credit => self.amount,
+            StatementDirection::Debit => -self.amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BankStatement {
+    pub statement_id: Option<String>,
+    // IBAN or the bank's own account number
+    pub account: String,
+    pub currency: String,
+    // Balances are signed like StatementLine::signed_amount
+    pub opening_balance: Decimal,
+    pub opening_date: Option<chrono::NaiveDate>,
+    pub closing_balance: Decimal,
+    pub closing_date: Option<chrono::NaiveDate>,
+    pub lines: Vec<StatementLine>,
+}
+
+impl BankStatement {
+    pub fn lines_total(&self) -> Decimal {
+        self.lines.iter().map(StatementLine::signed_amount).sum()
+    }
+
+    // The opening balance plus every line must give the closing balance,
+    // otherwise lines are missing or were misread
+    pub fn validate_balances(&self) -> Result<(), BankStatementError> {
+        let expected = self.opening_balance + self.lines_total();
+        if expected != self.closing_balance {
+            return Err(BankStatementError::BalanceMismatch {
+                statement_id: self.statement_id.clone().unwrap_or_default(),
+                opening: self.opening_balance,
+                lines_total: self.lines_total(),
+                closing: self.closing_balance,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BankStatementError {
+    #[error("Malformed statement file: {0}")]
+    Malformed(String),
+    #[error("Statement is missing {0}")]
+    MissingField(&'static str),
+    #[error("Invalid {field}: {value}")]
+    InvalidField { field: &'static str, value: String },
+    #[error("Statement {statement_id} does not add up: opening {opening} plus lines {lines_total} is not closing {closing}")]
+    BalanceMismatch {
+        statement_id: String,
+        opening: Decimal,
+        lines_total: Decimal,
+        closing: Decimal,
+    },
+    #[error("Statement {statement_id} is in {expected}, but its {part} is in {found}")]
+    CurrencyMismatch {
+        statement_id: String,
+        part: &'static str,
+        expected: String,
+        found: String,
+    },
+}
+
+fn invalid(field: &'static str, value: &str) -> BankStatementError {
+    BankStatementError::InvalidField {
+        field,
+        value: value.to_string(),
+    }
+}
+
+// ISO 20022 camt.053 (BankToCustomerStatement). Every Stmt in the document
+// becomes one statement; only booked entries are returned, since pending
+// ones are not part of the booked balances they are checked against.
+pub fn parse_camt053(xml: &str) -> Result<Vec<BankStatement>, BankStatementError> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut path: Vec<String> = Vec::new();
+    let mut statements = Vec::new();
+    let mut statement: Option<CamtStatement> = None;
+    let mut balance: Option<CamtBalance> = None;
+    let mut entry: Option<CamtEntry> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|error| BankStatementError::Malformed(error.to_string()))?;
+        match event {
+            Event::Start(start) => {
+                let name = local_name(&start);
+                match name.as_str() {
+                    "Stmt" => statement = Some(CamtStatement::default()),
+                    "Bal" if statement.is_some() => balance = Some(CamtBalance::default()),
+                    "Ntry" if statement.is_some() => entry = Some(CamtEntry::default()),
+                    "Amt" => {
+                        let currency = currency_attribute(&start)?;
+                        if let Some(balance) = balance.as_mut().filter(|_| path.ends_with(&["Bal".to_string()])) {
+                            balance.currency = currency;
+                        } else if let Some(entry) = entry.as_mut().filter(|_| path.ends_with(&["Ntry".to_string()])) {
+                            entry.currency = currency;
+                        }
+                    }
+                    _ => {}
+                }
+                path.push(name);
+            }
+            Event::Text(text) => {
+                let text = text
+                    .unescape()
+                    .map_err(|error| BankStatementError::Malformed(error.to_string()))?
+                    .into_owned();
+                let at = |suffix: &[&str]| {
+                    path.len() >= suffix.len()
+                        && path[path.len() - suffix.len()..].iter().zip(suffix).all(|(a, b)| a == b)
+                };
+
+                if let Some(entry) = entry.as_mut() {
+                    if at(&["Ntry", "Amt"]) {
+                        entry.amount = Some(text);
+                    } else if at(&["Ntry", "CdtDbtInd"]) {
+                        entry.indicator = Some(text);
+                    } else if at(&["Ntry", "Sts"]) || at(&["Ntry", "Sts", "Cd"]) {
+                        entry.status = Some(text);
+                    } else if at(&["ValDt", "Dt"]) || at(&["ValDt", "DtTm"]) {
+                        entry.value_date = Some(text);
+                    } else if at(&["BookgDt", "Dt"]) || at(&["BookgDt", "DtTm"]) {
+                        entry.booking_date = Some(text);
+                    } else if at(&["Refs", "EndToEndId"]) && text != "NOTPROVIDED" {
+                        entry.end_to_end_id.get_or_insert(text);
+                    } else if at(&["Ntry", "AcctSvcrRef"]) {
+                        entry.servicer_reference = Some(text);
+                    } else if at(&["Ntry", "NtryRef"]) {
+                        entry.entry_reference = Some(text);
+                    } else if at(&["RltdPties", "Dbtr", "Nm"]) || at(&["RltdPties", "Dbtr", "Pty", "Nm"]) {
+                        entry.debtor.get_or_insert(text);
+                    } else if at(&["RltdPties", "Cdtr", "Nm"]) || at(&["RltdPties", "Cdtr", "Pty", "Nm"]) {
+                        entry.creditor.get_or_insert(text);
+                    } else if at(&["RmtInf", "Ustrd"]) {
+                        entry.remittance.push(text);
+                    } else if at(&["Ntry", "AddtlNtryInf"]) {
+                        entry.additional_info = Some(text);
+                    }
+                } else if let Some(balance) = balance.as_mut() {
+                    if at(&["Bal", "Tp", "CdOrPrtry", "Cd"]) {
+                        balance.code = Some(text);
+                    } else if at(&["Bal", "Amt"]) {
+                        balance.amount = Some(text);
+                    } else if at(&["Bal", "CdtDbtInd"]) {
+                        balance.indicator = Some(text);
+                    } else if at(&["Bal", "Dt", "Dt"]) || at(&["Bal", "Dt", "DtTm"]) {
+                        balance.date = Some(text);
+                    }
+                } else if let Some(statement) = statement.as_mut() {
+                    if at(&["Stmt", "Id"]) {
+                        statement.id = Some(text);
+                    } else if at(&["Stmt", "Acct", "Id", "IBAN"]) || at(&["Stmt", "Acct", "Id", "Othr", "Id"]) {
+                        statement.account = Some(text);
+                    } else if at(&["Stmt", "Acct", "Ccy"]) {
+                        statement.currency = Some(text);
+                    }
+                }
+            }
+            Event::End(_) => {
+                match path.pop().as_deref() {
+                    Some("Ntry") => {
+                        if let (Some(statement), Some(entry)) = (statement.as_mut(), entry.take()) {
+                            let currency = entry.currency.clone();
+                            if let Some(line) = entry.into_line()? {
+                                statement.lines.push((currency, line));
+                            }
+                        }
+                    }
+                    Some("Bal") => {
+                        if let (Some(statement), Some(balance)) = (statement.as_mut(), balance.take()) {
+                            statement.balances.push(balance);
+                        }
+                    }
+                    Some("Stmt") => {
+                        if let Some(statement) = statement.take() {
+                            let statement = statement.into_statement()?;
+                            statement.validate_balances()?;
+                            statements.push(statement);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    if statements.is_empty() {
+        return Err(BankStatementError::MissingField("a Stmt element"));
+    }
+    Ok(statements)
+}
+
+fn local_name(start: &BytesStart) -> String {
+    String::from_utf8_lossy(start.local_name().as_ref()).into_owned()
+}
+
+fn currency_attribute(start: &BytesStart) -> Result<Option<String>, BankStatementError> {
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|error| BankStatementError::Malformed(error.to_string()))?;
+        if attribute.key.local_name().as_ref() == b"Ccy" {
+            let value = attribute
+                .unescape_value()
+                .map_err(|error| BankStatementError::Malformed(error.to_string()))?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+#[derive(Default)]
+struct CamtStatement {
+    id: Option<String>,
+    account: Option<String>,
+    currency: Option<String>,
+    balances: Vec<CamtBalance>,
+    // Each booked entry's line with the currency of its amount
+    lines: Vec<(Option<String>, StatementLine)>,
+}
+
+#[derive(Default)]
+struct CamtBalance {
+    code: Option<String>,
+    amount: Option<String>,
+    currency: Option<String>,
+    indicator: Option<String>,
+    date: Option<String>,
+}
+
+#[derive(Default)]
+struct CamtEntry {
+    amount: Option<String>,
+    currency: Option<String>,
+    indicator: Option<String>,
+    status: Option<String>,
+    value_date: Option<String>,
+    booking_date: Option<String>,
+    end_to_end_id: Option<String>,
+    servicer_reference: Option<String>,
+    entry_reference: Option<String>,
+    debtor: Option<String>,
+    creditor: Option<String>,
+    remittance: Vec<String>,
+    additional_info: Option<String>,
+}
+
+impl CamtStatement {
+    fn into_statement(self) -> Result<BankStatement, BankStatementError> {
+        // PRCD, the previous statement's closing balance, stands in for an
+        // opening balance some banks leave out
+        let find = |codes: &[&str]| {
+            codes.iter().find_map(|code| {
+                self.balances
+                    .iter()
+                    .find(|balance| balance.code.as_deref() == Some(*code))
+            })
+        };
+        let opening = find(&["OPBD", "PRCD"]).ok_or(BankStatementError::MissingField("an opening balance"))?;
+        let closing = find(&["CLBD"]).ok_or(BankStatementError::MissingField("a closing balance"))?;
+
+        let currency = self
+            .currency
+            .clone()
+            .or_else(|| opening.currency.clone())
+            .ok_or(BankStatementError::MissingField("the account currency"))?;
+
+        // Amounts without a Ccy attribute are taken to be in the account currency
+        let parts = [("opening balance", &opening.currency), ("closing balance", &closing.currency)]
+            .into_iter()
+            .chain(self.lines.iter().map(|(currency, _)| ("entry", currency)));
+        for (part, found) in parts {
+            check_currency(self.id.as_deref(), &currency, part, found.as_deref())?;
+        }
+
+        Ok(BankStatement {
+            statement_id: self.id.clone(),
+            account: self.account.clone().ok_or(BankStatementError::MissingField("the account"))?,
+            currency,
+            opening_balance: opening.signed_amount()?,
+            opening_date: opening.date.as_deref().map(camt_date).transpose()?,
+            closing_balance: closing.signed_amount()?,
+            closing_date: closing.date.as_deref().map(camt_date).transpose()?,
+            lines: self.lines.into_iter().map(|(_, line)| line).collect(),
+        })
+    }
+}
+
+fn check_currency(
+    statement_id: Option<&str>,
+    expected: &str,
+    part: &'static str,
+    found: Option<&str>,
+) -> Result<(), BankStatementError> {
+    match found {
+        Some(found) if found != expected => Err(BankStatementError::CurrencyMismatch {
+            statement_id: statement_id.unwrap_or_default().to_string(),
+            part,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+impl CamtBalance {
+    fn signed_amount(&self) -> Result<Decimal, BankStatementError> {
+        let amount = camt_amount(self.amount.as_deref())?;
+        match camt_direction(self.indicator.as_deref())? {
+            StatementDirection::Credit => Ok(amount),
+            StatementDirection::Debit => Ok(-amount),
+        }
+    }
+}
+
+impl CamtEntry {
+    // None for entries that are not booked
+    fn into_line(self) -> Result<Option<StatementLine>, BankStatementError> {
+        if self.status.as_deref().is_some_and(|status| status != "BOOK") {
+            return Ok(None);
+        }
+
+        let direction = camt_direction(self.indicator.as_deref())?;
+        let booking_date = self.booking_date.as_deref().map(camt_date).transpose()?;
+        let value_date = match self.value_date.as_deref() {
+            Some(date) => camt_date(date)?,
+            None => booking_date.ok_or(BankStatementError::MissingField("an entry value date"))?,
+        };
+        // The other party is the debtor of money received and the creditor
+        // of money paid out
+        let counterparty = match direction {
+            StatementDirection::Credit => self.debtor,
+            StatementDirection::Debit => self.creditor,
+        };
+        let description = if self.remittance.is_empty() {
+            self.additional_info
+        } else {
+            Some(self.remittance.join(" "))
+        };
+
+        Ok(Some(StatementLine {
+            value_date,
+            booking_date,
+            amount: camt_amount(self.amount.as_deref())?,
+            direction,
+            reference: self.end_to_end_id.or(self.servicer_reference).or(self.entry_reference),
+            counterparty,
+            description,
+        }))
+    }
+}
+
+fn camt_amount(value: Option<&str>) -> Result<Decimal, BankStatementError> {
+    let value = value.ok_or(BankStatementError::MissingField("an amount"))?;
+    value.parse().map_err(|_| invalid("amount", value))
+}
+
+fn camt_direction(value: Option<&str>) -> Result<StatementDirection, BankStatementError> {
+    match value {
+        Some("CRDT") => Ok(StatementDirection::Credit),
+        Some("DBIT") => Ok(StatementDirection::Debit),
+        Some(other) => Err(invalid("credit/debit indicator", other)),
+        None => Err(BankStatementError::MissingField("a credit/debit indicator")),
+    }
+}
+
+// ISODate, or the date part of an ISODateTime
+fn camt_date(value: &str) -> Result<chrono::NaiveDate, BankStatementError> {
+    value
+        .get(..10)
+        .and_then(|date| chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .ok_or_else(|| invalid("date", value))
+}
+
+// SWIFT MT940. Files may hold several statements, each ending with a "-"
+// line, and may keep the SWIFT block headers around them.
+pub fn parse_mt940(text: &str) -> Result<Vec<BankStatement>, BankStatementError> {
+    let mut statements = Vec::new();
+    let mut fields: Vec<(String, String)> = Vec::new();
+
+    for raw_line in text.lines() {
+        let line = raw_line.trim_end();
+        // Block headers such as "{1:F01...}{2:...}{4:" and the "-}" trailer
+        let line = match line.rfind("{4:") {
+            Some(index) => &line[index + 3..],
+            None if line.starts_with('{') => continue,
+            None => line,
+        };
+        if line == "-" || line == "-}" {
+            if !fields.is_empty() {
+                statements.push(mt940_statement(std::mem::take(&mut fields))?);
+            }
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        let tag = line
+            .strip_prefix(':')
+            .and_then(|rest| rest.split_once(':'))
+            .filter(|(tag, _)| tag.len() <= 3 && tag.chars().all(|c| c.is_ascii_alphanumeric()));
+        match tag {
+            Some((tag, value)) => fields.push((tag.to_string(), value.to_string())),
+            // Continuation of the previous field
+            None => match fields.last_mut() {
+                Some((_, value)) => {
+                    value.push('\n');
+                    value.push_str(line);
+                }
+                None => return Err(BankStatementError::Malformed(format!("unexpected line: {}", line))),
+            },
+        }
+    }
+    if !fields.is_empty() {
+        statements.push(mt940_statement(fields)?);
+    }
+
+    if statements.is_empty() {
+        return Err(BankStatementError::MissingField("a :20: statement"));
+    }
+    Ok(statements)
+}
+
+fn mt940_statement(fields: Vec<(String, String)>) -> Result<BankStatement, BankStatementError> {
+    let mut statement_id = None;
+    let mut statement_number = None;
+    let mut account = None;
+    let mut opening = None;
+    let mut closing = None;
+    let mut lines: Vec<StatementLine> = Vec::new();
+
+    for (tag, value) in fields {
+        match tag.as_str() {
+            "20" => statement_id = Some(value.trim().to_string()),
+            "28C" | "28" => statement_number = Some(value.trim().to_string()),
+            "25" => account = Some(value.trim().to_string()),
+            "60F" | "60M" => opening = Some(mt940_balance(&value)?),
+            "62F" | "62M" => closing = Some(mt940_balance(&value)?),
+            "61" => lines.push(mt940_line(&value)?),
+            "86" => {
+                // Information to account owner for the preceding :61:
+                if let Some(line) = lines.last_mut() {
+                    let (counterparty, description) = mt940_details(&value);
+                    line.counterparty = line.counterparty.take().or(counterparty);
+                    line.description = Some(description);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let (opening_date, currency, opening_balance) = opening.ok_or(BankStatementError::MissingField("an opening balance (:60F:)"))?;
+    let (closing_date, closing_currency, closing_balance) = closing.ok_or(BankStatementError::MissingField("a closing balance (:62F:)"))?;
+    let statement_id = statement_number.or(statement_id);
+    check_currency(statement_id.as_deref(), &currency, "closing balance", Some(closing_currency.as_str()))?;
+
+    let statement = BankStatement {
+        statement_id,
+        account: account.ok_or(BankStatementError::MissingField("the account (:25:)"))?,
+        currency,
+        opening_balance,
+        opening_date: Some(opening_date),
+        closing_balance,
+        closing_date: Some(closing_date),
+        lines,
+    };
+    statement.validate_balances()?;
+    Ok(statement)
+}
+
+// "C230101EUR1000,00": mark, date, currency, amount
+fn mt940_balance(value: &str) -> Result<(chrono::NaiveDate, String, Decimal), BankStatementError> {
+    let value = value.trim();
+    let field = "balance";
+    let mark = value.get(..1).ok_or_else(|| invalid(field, value))?;
+    let date = mt940_date(value.get(1..7).ok_or_else(|| invalid(field, value))?)?;
+    let currency = value.get(7..10).ok_or_else(|| invalid(field, value))?.to_string();
+    let amount = mt940_amount(value.get(10..).ok_or_else(|| invalid(field, value))?)?;
+    let amount = match mark {
+        "C" => amount,
+        "D" => -amount,
+        _ => return Err(invalid(field, value)),
+    };
+    Ok((date, currency, amount))
+}
+
+// "2301020102D50,00NTRFNONREF//B123": value date, optional entry date
+// (MMDD), mark, optional funds code, amount, type code, references
+fn mt940_line(value: &str) -> Result<StatementLine, BankStatementError> {
+    let field = "statement line (:61:)";
+    let first_line = value.lines().next().unwrap_or_default().trim();
+    let value_date = mt940_date(first_line.get(..6).ok_or_else(|| invalid(field, value))?)?;
+    let mut rest = first_line.get(6..).unwrap_or_default();
+
+    let mut booking_date = None;
+    if let Some(month_day) = rest.get(..4).filter(|digits| digits.chars().all(|c| c.is_ascii_digit())) {
+        booking_date = Some(mt940_entry_date(value_date, month_day).ok_or_else(|| invalid(field, value))?);
+        rest = &rest[4..];
+    }
+
+    // A reversal of a credit takes money out, a reversal of a debit puts
+    // it back
+    let (direction, mark_len) = if rest.starts_with("RC") {
+        (StatementDirection::Debit, 2)
+    } else if rest.starts_with("RD") {
+        (StatementDirection::Credit, 2)
+    } else if rest.starts_with('C') {
+        (StatementDirection::Credit, 1)
+    } else if rest.starts_with('D') {
+        (StatementDirection::Debit, 1)
+    } else {
+        return Err(invalid(field, value));
+    };
+    rest = &rest[mark_len..];
+    if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        rest = &rest[1..];
+    }
+
+    let amount_len = rest.find(|c: char| !(c.is_ascii_digit() || c == ',')).unwrap_or(rest.len());
+    let amount = mt940_amount(&rest[..amount_len])?;
+    // Skip the transaction type code, e.g. NTRF
+    let references = rest[amount_len..].get(4..).unwrap_or_default();
+    let (customer_reference, bank_reference) = match references.split_once("//") {
+        Some((customer, bank)) => (customer, Some(bank)),
+        None => (references, None),
+    };
+    let reference = Some(customer_reference)
+        .filter(|reference| !reference.is_empty() && *reference != "NONREF")
+        .or(bank_reference)
+        .map(|reference| reference.trim().to_string())
+        .filter(|reference| !reference.is_empty());
+
+    Ok(StatementLine {
+        value_date,
+        booking_date,
+        amount,
+        direction,
+        reference,
+        counterparty: None,
+        description: None,
+    })
+}
+
+// Returns the counterparty name, if the :86: field is structured, and the
+// free text. German banks use ?NN subfields with the name in ?32/?33;
+// others use /CODE/value pairs such as /NAME/.
+fn mt940_details(value: &str) -> (Option<String>, String) {
+    let value = value.replace('\n', "");
+
+    if value.contains('?') {
+        let mut name = String::new();
+        let mut remittance = Vec::new();
+        for subfield in value.split('?').skip(1) {
+            let (code, text) = (subfield.get(..2).unwrap_or_default(), subfield.get(2..).unwrap_or_default());
+            match code {
+                "32" | "33" => name.push_str(text),
+                "20" | "21" | "22" | "23" | "24" | "25" | "26" | "27" | "28" | "29" => remittance.push(text),
+                _ => {}
+            }
+        }
+        let name = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+        let description = if remittance.is_empty() { value.clone() } else { remittance.concat() };
+        return (name, description);
+    }
+
+    let mut parts = value.split('/');
+    while let Some(part) = parts.next() {
+        if part == "NAME" {
+            let name = parts.next().map(|name| name.trim().to_string()).filter(|name| !name.is_empty());
+            return (name, value.clone());
+        }
+    }
+    (None, value)
+}
+
+fn mt940_date(value: &str) -> Result<chrono::NaiveDate, BankStatementError> {
+    chrono::NaiveDate::parse_from_str(value, "%y%m%d").map_err(|_| invalid("date", value))
+}
+
+// Entry dates carry no year; take the value date's, moved across the year
+// end when the two straddle it
+fn mt940_entry_date(value_date: chrono::NaiveDate, month_day: &str) -> Option<chrono::NaiveDate> {
+    use chrono::Datelike;
+    let month: u32 = month_day[..2].parse().ok()?;
+    let day: u32 = month_day[2..].parse().ok()?;
+    let year = match (value_date.month(), month) {
+        (12, 1) => value_date.year() + 1,
+        (1, 12) => value_date.year() - 1,
+        _ => value_date.year(),
+    };
+    chrono::NaiveDate::from_ymd_opt(year, month, day)
+}
+
+// Comma is the decimal separator and there are no thousands separators
+fn mt940_amount(value: &str) -> Result<Decimal, BankStatementError> {
+    let normalized = value.trim().replace(',', ".");
+    let normalized = normalized.strip_suffix('.').unwrap_or(&normalized);
+    normalized.parse().map_err(|_| invalid("amount", value))
+}
+```
@@ -0,0 +1,75 @@
+ledger/src/batch.rs
+```rust
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{Entry, Transaction};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BatchMode {
+    // Any rejected item fails the whole batch and nothing is written
+    #[default]
+    AllOrNothing,
+    // Rejected items are reported and every other item is still written
+    BestEffort,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    Recorded,
+    // The idempotency key was already used, by an earlier call or by an
+    // earlier item of the same batch; nothing was written for this item
+    Duplicate { existing_transaction_id: Uuid },
+    Rejected { reason: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchItemOutcome {
+    // Position of the item in the submitted batch
+    pub index: usize,
+    pub transaction_id: Uuid,
+    pub idempotency_key: String,
+    #[serde(flatten)]
+    pub status: BatchItemStatus,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct BatchSummary {
+    pub recorded: usize,
+    pub duplicates: usize,
+    pub rejected: usize,
+}
+
+impl BatchSummary {
+    pub fn from_outcomes(outcomes: &[BatchItemOutcome]) -> Self {
+        let mut summary = Self::default();
+        for outcome in outcomes {
+            match outcome.status {
+                BatchItemStatus::Recorded => summary.recorded += 1,
+                BatchItemStatus::Duplicate { .. } => summary.duplicates += 1,
+                BatchItemStatus::Rejected { .. } => summary.rejected += 1,
+            }
+        }
+        summary
+    }
+}
+
+// Checks that can be made without the database: the transaction itself and
+// that its entries belong to it
+pub fn validate_batch_item(transaction: &Transaction, entries: &[Entry]) -> Result<(), String> {
+    transaction.validate().map_err(|error| error.to_string())?;
+
+    if entries.is_empty() {
+        return Err("Transaction has no entries".to_string());
+    }
+    if let Some(entry) = entries.iter().find(|entry| entry.transaction_id != transaction.id) {
+        return Err(format!("Entry {} belongs to another transaction", entry.id));
+    }
+    if entries.iter().any(|entry| entry.amount <= Decimal::ZERO) {
+        return Err("Entry amounts must be positive".to_string());
+    }
+
+    Ok(())
+}
+```
@@ -0,0 +1,28 @@
+ledger/src/bin/ledger-grpc.rs
+```rust
+use std::sync::Arc;
+use ledger_core::{grpc, LedgerService, PostgresLedgerStore};
+use sqlx::PgPool;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt::init();
+
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let port: u16 = std::env::var("GRPC_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(50051);
+
+    let pool = PgPool::connect(&database_url)
+        .await
+        .expect("Failed to connect to database");
+    let store = PostgresLedgerStore::connect(pool)
+        .await
+        .expect("Database schema does not match this build; run `ledger migrate`");
+    let service = Arc::new(LedgerService::new(Box::new(store)));
+
+    let addr = std::net::SocketAddr::from(([0, 0, 0, 0], port));
+    grpc::serve(service, addr).await.expect("gRPC server failed");
+}
+```
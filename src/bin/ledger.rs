@@ -0,0 +1,686 @@
+ledger/src/bin/ledger.rs
+```rust
+use std::collections::HashMap;
+use ledger_core::{
+    migrate, verify_chain, verify_ledger, AccountType, BatchMode, EntryFilter, ImportOptions,
+    ImportPolicy, ImportRowResult, ImportRowStatus, JournalFormat, JournalImportOptions,
+    LedgerService, PostgresLedgerStore, Transaction,
+};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const USAGE: &str = "\
+usage: ledger <command> [arguments] [--format table|json]
+
+commands:
+  migrate
+  create-account <asset|liability|equity|revenue|expense> <currency> [--code CODE] [--name NAME] [--parent CODE]
+  credit <account-id> <amount> --reason CODE [--key IDEMPOTENCY_KEY]
+  debit <account-id> <amount> --reason CODE [--key IDEMPOTENCY_KEY]
+  transfer <from-account-id> <to-account-id> <amount> --reason CODE [--key IDEMPOTENCY_KEY]
+  reverse <transaction-id> --reason CODE [--key IDEMPOTENCY_KEY]
+  balance <account-id>
+  shard-balance <account-id> <shards>
+  history <account-id> [--limit N] [--cursor CURSOR] [--from RFC3339] [--to RFC3339]
+  reconcile <account-id>... [--expect ACCOUNT_ID=AMOUNT]...
+  trial-balance <currency> [--as-of RFC3339]
+  export <ledger|hledger|beancount> [--from RFC3339] [--to RFC3339]
+  import-journal <ledger|hledger|beancount> <file> [--dry-run] [--best-effort] [--source NAME]
+  import <file.csv> [--dry-run] [--best-effort] [--reason-codes CODE,...] [--max-amount AMOUNT] [--max-backdate-days N] [--reject-overdrafts]
+  verify
+
+DATABASE_URL must point at the ledger database.";
+
+#[tokio::main]
+async fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let exit_code = match args.command.as_str() {
+        "migrate" => run_migrations().await,
+        "verify" => verify(&args).await,
+        _ => match run(&args).await {
+            Ok(code) => code,
+            Err(CliError::Usage(message)) => {
+                eprintln!("{}\n\n{}", message, USAGE);
+                2
+            }
+            Err(CliError::Failed(message)) => {
+                eprintln!("{}", message);
+                1
+            }
+        },
+    };
+
+    std::process::exit(exit_code);
+}
+
+enum CliError {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<ledger_core::LedgerError> for CliError {
+    fn from(error: ledger_core::LedgerError) -> Self {
+        CliError::Failed(error.to_string())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Table,
+    Json,
+}
+
+// Options that take no value
+const FLAGS: &[&str] = &["dry-run", "best-effort", "reject-overdrafts"];
+
+struct Args {
+    command: String,
+    positional: Vec<String>,
+    options: HashMap<String, Vec<String>>,
+    format: Format,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args;
+        let command = args.next().ok_or("missing command")?;
+        let mut positional = Vec::new();
+        let mut options: HashMap<String, Vec<String>> = HashMap::new();
+        let mut format = Format::Table;
+
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                positional.push(arg);
+                continue;
+            };
+            if name == "json" {
+                format = Format::Json;
+                continue;
+            }
+            if FLAGS.contains(&name) {
+                options.entry(name.to_string()).or_default().push("true".to_string());
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("--{} requires a value", name))?;
+            if name == "format" {
+                format = match value.as_str() {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    other => return Err(format!("unknown format: {}", other)),
+                };
+            } else {
+                options.entry(name.to_string()).or_default().push(value);
+            }
+        }
+
+        Ok(Self {
+            command,
+            positional,
+            options,
+            format,
+        })
+    }
+
+    fn positional(&self, index: usize, name: &str) -> Result<&str, CliError> {
+        self.positional
+            .get(index)
+            .map(String::as_str)
+            .ok_or_else(|| CliError::Usage(format!("missing <{}>", name)))
+    }
+
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options
+            .get(name)
+            .and_then(|values| values.last())
+            .map(String::as_str)
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    fn required_option(&self, name: &str) -> Result<&str, CliError> {
+        self.option(name)
+            .ok_or_else(|| CliError::Usage(format!("--{} is required", name)))
+    }
+
+    // Operators retrying a command should pass --key explicitly; otherwise
+    // every invocation is treated as a new request
+    fn idempotency_key(&self) -> String {
+        self.option("key")
+            .map(str::to_string)
+            .unwrap_or_else(|| Uuid::new_v4().to_string())
+    }
+}
+
+// Returns the process exit code: 1 when a report found a discrepancy
+async fn run(args: &Args) -> Result<i32, CliError> {
+    let service = LedgerService::new(Box::new(connect().await.map_err(CliError::Failed)?));
+
+    match args.command.as_str() {
+        "create-account" => {
+            let account_type = parse_account_type(args.positional(0, "type")?)?;
+            let currency = args.positional(1, "currency")?;
+            let mut metadata = serde_json::Map::new();
+            for (option, key) in [("code", "code"), ("name", "name"), ("parent", "parent_code")] {
+                if let Some(value) = args.option(option) {
+                    metadata.insert(key.to_string(), value.into());
+                }
+            }
+            let account = service
+                .create_account_with_metadata(account_type, currency, metadata.into())
+                .await?;
+            print_output(args.format, &account, || {
+                table(
+                    &["id", "type", "currency", "code", "name"],
+                    vec![vec![
+                        account.id.to_string(),
+                        format!("{:?}", account.account_type),
+                        account.currency.clone(),
+                        account.code().unwrap_or_default().to_string(),
+                        account.name().unwrap_or_default().to_string(),
+                    ]],
+                )
+            })?;
+            Ok(0)
+        }
+        "credit" | "debit" => {
+            let account_id = parse_uuid(args.positional(0, "account-id")?)?;
+            let amount = parse_amount(args.positional(1, "amount")?)?;
+            let reason = args.required_option("reason")?;
+            let key = args.idempotency_key();
+            let transaction = if args.command == "credit" {
+                service.credit_account(account_id, amount, reason, &key).await?
+            } else {
+                service.debit_account(account_id, amount, reason, &key).await?
+            };
+            print_transactions(args.format, &[transaction])?;
+            Ok(0)
+        }
+        "transfer" => {
+            let from = parse_uuid(args.positional(0, "from-account-id")?)?;
+            let to = parse_uuid(args.positional(1, "to-account-id")?)?;
+            let amount = parse_amount(args.positional(2, "amount")?)?;
+            let reason = args.required_option("reason")?;
+            let transaction = service
+                .transfer(from, to, amount, reason, &args.idempotency_key())
+                .await?;
+            print_transactions(args.format, &[transaction])?;
+            Ok(0)
+        }
+        "reverse" => {
+            let transaction_id = parse_uuid(args.positional(0, "transaction-id")?)?;
+            let reason = args.required_option("reason")?;
+            let transaction = service
+                .reverse_transaction(transaction_id, reason, &args.idempotency_key())
+                .await?;
+            print_transactions(args.format, &[transaction])?;
+            Ok(0)
+        }
+        "balance" => {
+            let account_id = parse_uuid(args.positional(0, "account-id")?)?;
+            let account = service.get_account(account_id).await?;
+            let balance = service.get_account_balance(account_id).await?;
+            let output = serde_json::json!({
+                "account_id": account_id,
+                "currency": account.currency,
+                "balance": balance,
+            });
+            print_output(args.format, &output, || {
+                table(
+                    &["account_id", "currency", "balance"],
+                    vec![vec![account_id.to_string(), account.currency.clone(), balance.to_string()]],
+                )
+            })?;
+            Ok(0)
+        }
+        "shard-balance" => {
+            let account_id = parse_uuid(args.positional(0, "account-id")?)?;
+            let shards = args.positional(1, "shards")?;
+            let shards = shards
+                .parse()
+                .map_err(|_| CliError::Usage(format!("invalid shard count: {}", shards)))?;
+            service.set_balance_shards(account_id, shards).await?;
+            let output = serde_json::json!({ "account_id": account_id, "balance_shards": shards });
+            print_output(args.format, &output, || {
+                table(
+                    &["account_id", "balance_shards"],
+                    vec![vec![account_id.to_string(), shards.to_string()]],
+                )
+            })?;
+            Ok(0)
+        }
+        "history" => {
+            let account_id = parse_uuid(args.positional(0, "account-id")?)?;
+            let limit = match args.option("limit") {
+                Some(limit) => limit
+                    .parse()
+                    .map_err(|_| CliError::Usage(format!("invalid --limit: {}", limit)))?,
+                None => 50,
+            };
+            let filter = EntryFilter {
+                from: args.option("from").map(parse_timestamp).transpose()?,
+                to: args.option("to").map(parse_timestamp).transpose()?,
+                ..EntryFilter::default()
+            };
+            let page = service
+                .get_account_entries(account_id, &filter, args.option("cursor"), limit)
+                .await?;
+            print_output(args.format, &page, || {
+                let rows = page
+                    .items
+                    .iter()
+                    .map(|item| {
+                        vec![
+                            item.entry.timestamp.to_rfc3339(),
+                            item.transaction.id.to_string(),
+                            item.transaction.transaction_type.as_str().to_string(),
+                            item.transaction.reason_code.clone(),
+                            item.entry.entry_type.as_str().to_string(),
+                            item.entry.amount.to_string(),
+                            item.entry.balance_after.to_string(),
+                        ]
+                    })
+                    .collect();
+                let mut output = table(
+                    &["timestamp", "transaction_id", "type", "reason", "side", "amount", "balance_after"],
+                    rows,
+                );
+                if let Some(cursor) = &page.next_cursor {
+                    output.push_str(&format!("\nnext page: --cursor {}\n", cursor));
+                }
+                output
+            })?;
+            Ok(0)
+        }
+        "reconcile" => {
+            if args.positional.is_empty() {
+                return Err(CliError::Usage("missing <account-id>".to_string()));
+            }
+            let account_ids = args
+                .positional
+                .iter()
+                .map(|id| parse_uuid(id))
+                .collect::<Result<Vec<_>, _>>()?;
+            let expected = args
+                .options
+                .get("expect")
+                .map(|values| {
+                    values
+                        .iter()
+                        .map(|value| parse_expectation(value))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?;
+            let report = service.reconcile(&account_ids, expected.as_deref()).await?;
+            print_output(args.format, &report, || {
+                let rows = report
+                    .account_balances
+                    .iter()
+                    .map(|line| {
+                        vec![
+                            line.account_id.to_string(),
+                            line.calculated_balance.to_string(),
+                            line.expected_balance.map(|b| b.to_string()).unwrap_or_default(),
+                            line.discrepancy.map(|d| d.to_string()).unwrap_or_default(),
+                        ]
+                    })
+                    .collect();
+                table(&["account_id", "calculated", "expected", "discrepancy"], rows)
+            })?;
+            let balanced = report
+                .account_balances
+                .iter()
+                .all(|line| line.discrepancy.is_none_or(|d| d.is_zero()));
+            Ok(if balanced { 0 } else { 1 })
+        }
+        "export" => {
+            let format: JournalFormat = args.positional(0, "format")?.parse().map_err(CliError::Usage)?;
+            let from = match args.option("from") {
+                Some(from) => parse_timestamp(from)?,
+                // Everything; the epoch predates any ledger entry
+                None => chrono::DateTime::from_timestamp(0, 0).expect("the epoch is a valid timestamp"),
+            };
+            let to = match args.option("to") {
+                Some(to) => parse_timestamp(to)?,
+                None => chrono::Utc::now(),
+            };
+            // The journal is the output; --format does not apply
+            print!("{}", service.export_journal(format, from, to).await?);
+            Ok(0)
+        }
+        "import-journal" => {
+            let format: JournalFormat = args.positional(0, "format")?.parse().map_err(CliError::Usage)?;
+            let path = args.positional(1, "file")?;
+            let text = std::fs::read_to_string(path)
+                .map_err(|error| CliError::Failed(format!("Failed to read {}: {}", path, error)))?;
+            // Keys are derived from the source, so the default must not
+            // change when the same file is imported from another directory
+            let source = match args.option("source") {
+                Some(source) => source.to_string(),
+                None => std::path::Path::new(path)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| path.to_string()),
+            };
+            let options = JournalImportOptions {
+                source,
+                dry_run: args.flag("dry-run"),
+                mode: if args.flag("best-effort") {
+                    BatchMode::BestEffort
+                } else {
+                    BatchMode::AllOrNothing
+                },
+            };
+            let report = service.import_journal(format, &text, &options).await?;
+            print_output(args.format, &report, || {
+                let mut output = String::new();
+                for account in &report.accounts_created {
+                    let name = account.metadata["journal_account"].as_str().unwrap_or_default();
+                    output.push_str(&format!("new account {} {} ({})\n", name, account.currency, account.id));
+                }
+                output + &import_table(&report.transactions)
+            })?;
+            Ok(if report.has_errors() { 1 } else { 0 })
+        }
+        "import" => {
+            let path = args.positional(0, "file.csv")?;
+            let file = std::fs::File::open(path)
+                .map_err(|error| CliError::Failed(format!("Failed to open {}: {}", path, error)))?;
+            let max_backdate = args
+                .option("max-backdate-days")
+                .map(|days| {
+                    days.parse()
+                        .map(chrono::Duration::days)
+                        .map_err(|_| CliError::Usage(format!("invalid --max-backdate-days: {}", days)))
+                })
+                .transpose()?;
+            let options = ImportOptions {
+                dry_run: args.flag("dry-run"),
+                mode: if args.flag("best-effort") {
+                    BatchMode::BestEffort
+                } else {
+                    BatchMode::AllOrNothing
+                },
+                policy: ImportPolicy {
+                    allowed_reason_codes: args
+                        .option("reason-codes")
+                        .map(|codes| codes.split(',').map(|code| code.trim().to_string()).collect()),
+                    max_amount: args.option("max-amount").map(parse_amount).transpose()?,
+                    max_backdate,
+                    reject_overdrafts: args.flag("reject-overdrafts"),
+                },
+            };
+            let report = service.import_csv(file, &options).await?;
+            print_output(args.format, &report, || import_table(&report.rows))?;
+            Ok(if report.has_errors() { 1 } else { 0 })
+        }
+        "trial-balance" => {
+            let currency = args.positional(0, "currency")?;
+            let as_of = match args.option("as-of") {
+                Some(value) => parse_timestamp(value)?,
+                None => chrono::Utc::now(),
+            };
+            let report = service.trial_balance(as_of, currency).await?;
+            print_output(args.format, &report, || {
+                let mut rows: Vec<Vec<String>> = report
+                    .lines
+                    .iter()
+                    .map(|line| {
+                        vec![
+                            line.account_id.to_string(),
+                            format!("{:?}", line.account_type),
+                            line.debit_total.to_string(),
+                            line.credit_total.to_string(),
+                            line.net_balance.to_string(),
+                        ]
+                    })
+                    .collect();
+                rows.push(vec![
+                    "TOTAL".to_string(),
+                    String::new(),
+                    report.total_debits.to_string(),
+                    report.total_credits.to_string(),
+                    String::new(),
+                ]);
+                table(&["account_id", "type", "debits", "credits", "net"], rows)
+            })?;
+            Ok(if report.is_balanced { 0 } else { 1 })
+        }
+        other => Err(CliError::Usage(format!("unknown command: {}", other))),
+    }
+}
+
+async fn run_migrations() -> i32 {
+    let pool = match connect_pool().await {
+        Ok(pool) => pool,
+        Err(message) => {
+            eprintln!("{}", message);
+            return 2;
+        }
+    };
+
+    match migrate(&pool).await {
+        Ok(version) => {
+            println!("Schema is at version {}", version);
+            0
+        }
+        Err(error) => {
+            eprintln!("Migration failed: {}", error);
+            1
+        }
+    }
+}
+
+// Runs the entry-level integrity checks and the hash chain walk; exits 1
+// when either found violations
+async fn verify(args: &Args) -> i32 {
+    let store = match connect().await {
+        Ok(store) => store,
+        Err(message) => {
+            eprintln!("{}", message);
+            return 2;
+        }
+    };
+
+    let integrity = match verify_ledger(&store).await {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("Verification failed: {}", error);
+            return 2;
+        }
+    };
+    let chain = match verify_chain(&store).await {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("Chain verification failed: {}", error);
+            return 2;
+        }
+    };
+
+    let output = serde_json::json!({ "integrity": integrity, "chain": chain });
+    let printed = print_output(args.format, &output, || {
+        table(
+            &["check", "checked", "violations"],
+            vec![
+                vec![
+                    "entries".to_string(),
+                    integrity.entries_checked.to_string(),
+                    integrity.violations.len().to_string(),
+                ],
+                vec![
+                    "hash chain".to_string(),
+                    chain.links_checked.to_string(),
+                    chain.violations.len().to_string(),
+                ],
+            ],
+        )
+    });
+    if printed.is_err() {
+        return 2;
+    }
+
+    if integrity.is_clean() && chain.is_intact() { 0 } else { 1 }
+}
+
+async fn connect() -> Result<PostgresLedgerStore, String> {
+    let pool = connect_pool().await?;
+    PostgresLedgerStore::connect(pool)
+        .await
+        .map_err(|error| error.to_string())
+}
+
+async fn connect_pool() -> Result<PgPool, String> {
+    let database_url = std::env::var("DATABASE_URL")
+        .map_err(|_| "DATABASE_URL must be set".to_string())?;
+    PgPool::connect(&database_url)
+        .await
+        .map_err(|error| format!("Failed to connect to database: {}", error))
+}
+
+fn print_output<T: Serialize>(
+    format: Format,
+    value: &T,
+    render_table: impl FnOnce() -> String,
+) -> Result<(), CliError> {
+    match format {
+        Format::Json => {
+            let json = serde_json::to_string_pretty(value)
+                .map_err(|error| CliError::Failed(format!("Failed to render output: {}", error)))?;
+            println!("{}", json);
+        }
+        Format::Table => print!("{}", render_table()),
+    }
+    Ok(())
+}
+
+fn print_transactions(format: Format, transactions: &[Transaction]) -> Result<(), CliError> {
+    print_output(format, &transactions, || {
+        let rows = transactions
+            .iter()
+            .map(|transaction| {
+                vec![
+                    transaction.id.to_string(),
+                    transaction.transaction_type.as_str().to_string(),
+                    transaction.amount.to_string(),
+                    transaction.source_account_id.map(|id| id.to_string()).unwrap_or_default(),
+                    transaction.destination_account_id.map(|id| id.to_string()).unwrap_or_default(),
+                    transaction.reason_code.clone(),
+                    transaction.idempotency_key.clone(),
+                ]
+            })
+            .collect();
+        table(
+            &["id", "type", "amount", "source", "destination", "reason", "idempotency_key"],
+            rows,
+        )
+    })
+}
+
+// One line per imported row or transaction, with what happened to it
+fn import_table(rows: &[ImportRowResult]) -> String {
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let (status, detail) = match &row.status {
+                ImportRowStatus::Valid => ("valid", String::new()),
+                ImportRowStatus::Recorded { transaction_id } => ("recorded", transaction_id.to_string()),
+                ImportRowStatus::Duplicate { existing_transaction_id } => {
+                    ("duplicate", existing_transaction_id.to_string())
+                }
+                ImportRowStatus::Rejected { errors } => (
+                    "rejected",
+                    errors
+                        .iter()
+                        .map(|error| match &error.field {
+                            Some(field) => format!("{}: {}", field, error.message),
+                            None => error.message.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                ),
+            };
+            vec![
+                row.line.to_string(),
+                row.idempotency_key.clone().unwrap_or_default(),
+                status.to_string(),
+                detail,
+            ]
+        })
+        .collect();
+    table(&["line", "idempotency_key", "status", "detail"], rows)
+}
+
+// Left-aligned columns sized to the widest cell
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render = |cells: Vec<&str>| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let mut output = render(headers.to_vec());
+    let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    output.push_str(&render(rules.iter().map(String::as_str).collect()));
+    for row in &rows {
+        output.push_str(&render(row.iter().map(String::as_str).collect()));
+    }
+    output
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, CliError> {
+    Uuid::parse_str(value).map_err(|_| CliError::Usage(format!("invalid id: {}", value)))
+}
+
+fn parse_amount(value: &str) -> Result<Decimal, CliError> {
+    value
+        .parse()
+        .map_err(|_| CliError::Usage(format!("invalid amount: {}", value)))
+}
+
+fn parse_timestamp(value: &str) -> Result<chrono::DateTime<chrono::Utc>, CliError> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&chrono::Utc))
+        .map_err(|_| CliError::Usage(format!("invalid RFC 3339 timestamp: {}", value)))
+}
+
+fn parse_expectation(value: &str) -> Result<(Uuid, Decimal), CliError> {
+    let (account_id, amount) = value
+        .split_once('=')
+        .ok_or_else(|| CliError::Usage(format!("--expect must be ACCOUNT_ID=AMOUNT: {}", value)))?;
+    Ok((parse_uuid(account_id)?, parse_amount(amount)?))
+}
+
+fn parse_account_type(value: &str) -> Result<AccountType, CliError> {
+    match value.to_ascii_lowercase().as_str() {
+        "asset" => Ok(AccountType::Asset),
+        "liability" => Ok(AccountType::Liability),
+        "equity" => Ok(AccountType::Equity),
+        "revenue" => Ok(AccountType::Revenue),
+        "expense" => Ok(AccountType::Expense),
+        _ => Err(CliError::Usage(format!("unknown account type: {}", value))),
+    }
+}
+```
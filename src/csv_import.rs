@@ -0,0 +1,589 @@
+ledger/src/csv_import.rs
+```rust
+use std::collections::{HashMap, HashSet};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+use crate::{
+    validate_batch_item, Account, BatchItemStatus, BatchMode, Entry, EntryType, LedgerError,
+    LedgerStore, Transaction, TransactionType,
+};
+
+// Columns every import file must have. effective_date and metadata are
+// optional; any other column is copied into the transaction metadata.
+const REQUIRED_COLUMNS: &[&str] = &["account", "amount", "direction", "reason_code", "idempotency_key"];
+const OPTIONAL_COLUMNS: &[&str] = &["effective_date", "metadata"];
+
+// Checks applied on top of Transaction::validate. Every rule is off by
+// default except rejecting effective dates in the future.
+#[derive(Debug, Clone, Default)]
+pub struct ImportPolicy {
+    // Reason codes finance may post with; None accepts any
+    pub allowed_reason_codes: Option<HashSet<String>>,
+    pub max_amount: Option<Decimal>,
+    // How far in the past an effective date may be; None accepts any
+    pub max_backdate: Option<chrono::Duration>,
+    // Reject debits that would take an account below zero, counting the
+    // rows above them in the file
+    pub reject_overdrafts: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportOptions {
+    // Validate and report only; nothing is written
+    pub dry_run: bool,
+    // AllOrNothing posts nothing if any row fails validation
+    pub mode: BatchMode,
+    pub policy: ImportPolicy,
+}
+
+// One problem with one row. `field` names the column at fault, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RowError {
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl RowError {
+    pub(crate) fn field(field: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn row(message: impl Into<String>) -> Self {
+        Self {
+            field: None,
+            message: message.into(),
+        }
+    }
+}
+
+// A row as read from the file, before accounts are resolved
+#[derive(Debug, Clone)]
+pub struct CsvRow {
+    // Line in the file the record starts on, counting the header as line 1
+    pub line: u64,
+    // Account id or chart-of-accounts code
+    pub account: String,
+    pub amount: Decimal,
+    pub direction: EntryType,
+    pub reason_code: String,
+    pub idempotency_key: String,
+    pub effective_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ImportRowStatus {
+    // Passed every check; only reported by dry runs and rejected
+    // all-or-nothing imports
+    Valid,
+    Recorded { transaction_id: Uuid },
+    Duplicate { existing_transaction_id: Uuid },
+    Rejected { errors: Vec<RowError> },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRowResult {
+    pub line: u64,
+    pub idempotency_key: Option<String>,
+    #[serde(flatten)]
+    pub status: ImportRowStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    // False when nothing was written: a dry run, an all-or-nothing import
+    // with at least one rejected row, or a file holding only duplicates
+    pub posted: bool,
+    pub rows: Vec<ImportRowResult>,
+}
+
+impl ImportReport {
+    pub fn rejected(&self) -> impl Iterator<Item = &ImportRowResult> {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row.status, ImportRowStatus::Rejected { .. }))
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.rejected().next().is_some()
+    }
+}
+
+// A parsed record, or the line it starts on and why it could not be parsed
+pub type ParsedCsvRow = Result<CsvRow, (u64, Vec<RowError>)>;
+
+// Reads every record of the file. Records that cannot be parsed are
+// returned as errors with their line number instead of failing the file;
+// only an unreadable header does that.
+pub fn parse_csv<R: std::io::Read>(reader: R) -> Result<Vec<ParsedCsvRow>, LedgerError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(reader);
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|error| LedgerError::InvalidImportFile(error.to_string()))?
+        .iter()
+        .map(|header| header.to_ascii_lowercase())
+        .collect();
+    let missing: Vec<&str> = REQUIRED_COLUMNS
+        .iter()
+        .filter(|column| !headers.iter().any(|header| header == *column))
+        .copied()
+        .collect();
+    if !missing.is_empty() {
+        return Err(LedgerError::InvalidImportFile(format!(
+            "missing required columns: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => {
+                let line = error.position().map(|position| position.line()).unwrap_or(0);
+                rows.push(Err((line, vec![RowError::row(error.to_string())])));
+                continue;
+            }
+        };
+        let line = record.position().map(|position| position.line()).unwrap_or(0);
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+
+        let mut values: HashMap<&str, &str> = HashMap::new();
+        for (header, value) in headers.iter().zip(record.iter()) {
+            values.insert(header.as_str(), value);
+        }
+        rows.push(parse_row(line, &headers, &values).map_err(|errors| (line, errors)));
+    }
+
+    Ok(rows)
+}
+
+fn parse_row(line: u64, headers: &[String], values: &HashMap<&str, &str>) -> Result<CsvRow, Vec<RowError>> {
+    let value = |column: &str| values.get(column).copied().unwrap_or("");
+    let mut errors = Vec::new();
+
+    let account = value("account").to_string();
+    if account.is_empty() {
+        errors.push(RowError::field("account", "account id or code is required"));
+    }
+
+    let amount = match value("amount").parse::<Decimal>() {
+        Ok(amount) => amount,
+        Err(_) => {
+            errors.push(RowError::field("amount", format!("invalid amount: {:?}", value("amount"))));
+            Decimal::ZERO
+        }
+    };
+
+    let direction = match value("direction").to_ascii_lowercase().as_str() {
+        "debit" | "dr" => EntryType::Debit,
+        "credit" | "cr" => EntryType::Credit,
+        other => {
+            errors.push(RowError::field(
+                "direction",
+                format!("expected debit or credit, got {:?}", other),
+            ));
+            EntryType::Debit
+        }
+    };
+
+    let reason_code = value("reason_code").to_string();
+    if reason_code.is_empty() {
+        errors.push(RowError::field("reason_code", "reason code is required"));
+    }
+
+    let idempotency_key = value("idempotency_key").to_string();
+    if idempotency_key.is_empty() {
+        errors.push(RowError::field("idempotency_key", "idempotency key is required"));
+    }
+
+    let effective_at = match value("effective_date") {
+        "" => None,
+        date => match parse_effective_date(date) {
+            Some(effective_at) => Some(effective_at),
+            None => {
+                errors.push(RowError::field(
+                    "effective_date",
+                    format!("expected YYYY-MM-DD or RFC 3339, got {:?}", date),
+                ));
+                None
+            }
+        },
+    };
+
+    let mut metadata = serde_json::Map::new();
+    match value("metadata") {
+        "" => {}
+        json => match serde_json::from_str::<serde_json::Value>(json) {
+            Ok(serde_json::Value::Object(object)) => metadata = object,
+            _ => errors.push(RowError::field("metadata", "metadata must be a JSON object")),
+        },
+    }
+    for header in headers {
+        let is_known = REQUIRED_COLUMNS.contains(&header.as_str()) || OPTIONAL_COLUMNS.contains(&header.as_str());
+        let column_value = value(header.as_str());
+        if !is_known && !column_value.is_empty() {
+            metadata.insert(header.clone(), column_value.into());
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(CsvRow {
+        line,
+        account,
+        amount,
+        direction,
+        reason_code,
+        idempotency_key,
+        effective_at,
+        metadata,
+    })
+}
+
+fn parse_effective_date(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|midnight| midnight.and_utc())
+}
+
+// Accounts by id and by chart-of-accounts code
+struct AccountDirectory {
+    by_id: HashMap<Uuid, Account>,
+    by_code: HashMap<String, Vec<Uuid>>,
+}
+
+impl AccountDirectory {
+    fn new(accounts: Vec<Account>) -> Self {
+        let mut by_code: HashMap<String, Vec<Uuid>> = HashMap::new();
+        for account in &accounts {
+            if let Some(code) = account.code() {
+                by_code.entry(code.to_string()).or_default().push(account.id);
+            }
+        }
+        Self {
+            by_id: accounts.into_iter().map(|account| (account.id, account)).collect(),
+            by_code,
+        }
+    }
+
+    // Ids take precedence, so a code that happens to look like a UUID is
+    // still found by id
+    fn resolve(&self, reference: &str) -> Result<&Account, RowError> {
+        if let Ok(id) = Uuid::parse_str(reference) {
+            return self
+                .by_id
+                .get(&id)
+                .ok_or_else(|| RowError::field("account", format!("account {} does not exist", id)));
+        }
+        match self.by_code.get(reference).map(Vec::as_slice) {
+            Some([id]) => Ok(&self.by_id[id]),
+            Some(_) => Err(RowError::field(
+                "account",
+                format!("account code {} is used by more than one account", reference),
+            )),
+            None => Err(RowError::field("account", format!("no account has code {}", reference))),
+        }
+    }
+}
+
+// The transaction and entry a row posts. Debits and credits follow
+// debit_account and credit_account: one entry on the named account. The
+// store fills in balance_after when the batch is written. Rows are posted
+// at the time of the import, like any other posting; the effective date is
+// kept in the metadata, so balances, checkpoints and published roots for
+// earlier periods are left as they were.
+fn build_posting(row: &CsvRow, account_id: Uuid) -> (Transaction, Vec<Entry>) {
+    let (transaction_type, source, destination) = match row.direction {
+        EntryType::Debit => (TransactionType::Debit, Some(account_id), None),
+        EntryType::Credit => (TransactionType::Credit, None, Some(account_id)),
+    };
+    let mut transaction = Transaction::new(
+        transaction_type,
+        row.amount,
+        source,
+        destination,
+        &row.reason_code,
+        &row.idempotency_key,
+    );
+    let mut metadata = row.metadata.clone();
+    if let Some(effective_at) = row.effective_at {
+        metadata.insert("effective_date".to_string(), effective_at.to_rfc3339().into());
+    }
+    transaction.metadata = serde_json::Value::Object(metadata);
+
+    let mut entry = Entry::new(transaction.id, account_id, row.amount, row.direction, Decimal::ZERO);
+    entry.timestamp = transaction.timestamp;
+
+    (transaction, vec![entry])
+}
+
+// A key that is already recorded is only a duplicate of this row if it was
+// recorded for the same posting; otherwise the key was reused by mistake
+fn check_duplicate(existing: &Transaction, transaction: &Transaction) -> Result<Uuid, RowError> {
+    let same = existing.matches_request(
+        transaction.transaction_type,
+        transaction.amount,
+        transaction.source_account_id,
+        transaction.destination_account_id,
+        &transaction.reason_code,
+    );
+    if same {
+        Ok(existing.id)
+    } else {
+        Err(RowError::field(
+            "idempotency_key",
+            format!("idempotency key was already used for a different posting ({})", existing.id),
+        ))
+    }
+}
+
+fn policy_errors(
+    policy: &ImportPolicy,
+    row: &CsvRow,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Vec<RowError> {
+    let mut errors = Vec::new();
+
+    if let Some(allowed) = &policy.allowed_reason_codes {
+        if !allowed.contains(&row.reason_code) {
+            errors.push(RowError::field(
+                "reason_code",
+                format!("reason code {} is not allowed for imports", row.reason_code),
+            ));
+        }
+    }
+    if let Some(max_amount) = policy.max_amount {
+        if row.amount > max_amount {
+            errors.push(RowError::field(
+                "amount",
+                format!("amount {} exceeds the import limit of {}", row.amount, max_amount),
+            ));
+        }
+    }
+    if let Some(effective_at) = row.effective_at {
+        if effective_at > now {
+            errors.push(RowError::field("effective_date", "effective date is in the future"));
+        }
+        if let Some(max_backdate) = policy.max_backdate {
+            if effective_at < now - max_backdate {
+                errors.push(RowError::field(
+                    "effective_date",
+                    format!("effective date is more than {} days in the past", max_backdate.num_days()),
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+// Validates every row of a CSV file and, unless this is a dry run, posts
+// the valid ones through record_batch. Rows whose idempotency key was
+// already used are reported as duplicates, so re-running an import that
+// partly succeeded only posts what is missing. Existing keys and, for
+// reject_overdrafts, balances are loaded once for the whole file.
+pub async fn import_csv<R: std::io::Read>(
+    store: &(impl LedgerStore + ?Sized),
+    reader: R,
+    options: &ImportOptions,
+) -> Result<ImportReport, LedgerError> {
+    let parsed = parse_csv(reader)?;
+    let accounts = AccountDirectory::new(store.list_accounts().await?);
+    let now = chrono::Utc::now();
+
+    let keys: Vec<String> = parsed
+        .iter()
+        .filter_map(|row| row.as_ref().ok())
+        .map(|row| row.idempotency_key.clone())
+        .collect();
+    let existing: HashMap<String, Transaction> = store
+        .get_transactions_by_keys(&keys)
+        .await?
+        .into_iter()
+        .map(|transaction| (transaction.idempotency_key.clone(), transaction))
+        .collect();
+
+    // Running balances of the accounts the file posts to, advanced by
+    // each row that will be posted
+    let mut balances: HashMap<Uuid, Decimal> = HashMap::new();
+    if options.policy.reject_overdrafts {
+        let account_ids: Vec<Uuid> = parsed
+            .iter()
+            .filter_map(|row| row.as_ref().ok())
+            .filter_map(|row| accounts.resolve(&row.account).ok())
+            .map(|account| account.id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        balances = store.get_account_balances(&account_ids).await?;
+    }
+
+    let mut first_line_for_key: HashMap<String, u64> = HashMap::new();
+    let mut rows = Vec::with_capacity(parsed.len());
+    let mut postings = Vec::new();
+
+    for parsed_row in parsed {
+        let row = match parsed_row {
+            Ok(row) => row,
+            Err((line, errors)) => {
+                rows.push(ImportRowResult {
+                    line,
+                    idempotency_key: None,
+                    status: ImportRowStatus::Rejected { errors },
+                });
+                continue;
+            }
+        };
+
+        let mut errors = policy_errors(&options.policy, &row, now);
+        if let Some(first_line) = first_line_for_key.get(&row.idempotency_key) {
+            errors.push(RowError::field(
+                "idempotency_key",
+                format!("idempotency key repeats line {}", first_line),
+            ));
+        } else {
+            first_line_for_key.insert(row.idempotency_key.clone(), row.line);
+        }
+
+        let account_id = match accounts.resolve(&row.account) {
+            Ok(account) => Some(account.id),
+            Err(error) => {
+                errors.push(error);
+                None
+            }
+        };
+
+        let mut status = ImportRowStatus::Valid;
+        if let Some(account_id) = account_id {
+            let (transaction, entries) = build_posting(&row, account_id);
+
+            if let Err(error) = transaction.validate() {
+                errors.push(RowError::row(error.to_string()));
+            } else if let Err(reason) = validate_batch_item(&transaction, &entries) {
+                errors.push(RowError::row(reason));
+            }
+
+            if errors.is_empty() {
+                if let Some(existing) = existing.get(&row.idempotency_key) {
+                    match check_duplicate(existing, &transaction) {
+                        Ok(existing_transaction_id) => {
+                            status = ImportRowStatus::Duplicate { existing_transaction_id };
+                        }
+                        Err(error) => errors.push(error),
+                    }
+                } else if options.policy.reject_overdrafts {
+                    let balance = balances.entry(account_id).or_insert(Decimal::ZERO);
+                    let balance_after = match row.direction {
+                        EntryType::Credit => *balance + row.amount,
+                        EntryType::Debit => *balance - row.amount,
+                    };
+                    if row.direction == EntryType::Debit && balance_after < Decimal::ZERO {
+                        errors.push(RowError::field(
+                            "amount",
+                            format!("debit would overdraw the account, whose balance is {}", balance),
+                        ));
+                    } else {
+                        *balance = balance_after;
+                        postings.push((rows.len(), transaction, entries));
+                    }
+                } else {
+                    postings.push((rows.len(), transaction, entries));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            status = ImportRowStatus::Rejected { errors };
+        }
+        rows.push(ImportRowResult {
+            line: row.line,
+            idempotency_key: Some(row.idempotency_key),
+            status,
+        });
+    }
+
+    let has_errors = rows
+        .iter()
+        .any(|row| matches!(row.status, ImportRowStatus::Rejected { .. }));
+    let posted = !options.dry_run && (!has_errors || options.mode != BatchMode::AllOrNothing);
+    if !posted || postings.is_empty() {
+        return Ok(ImportReport {
+            dry_run: options.dry_run,
+            posted: false,
+            rows,
+        });
+    }
+
+    let (positions, items): (Vec<usize>, Vec<(Transaction, Vec<Entry>)>) = postings
+        .into_iter()
+        .map(|(position, transaction, entries)| (position, (transaction, entries)))
+        .unzip();
+    // The checked write repeats the overdraft check under the balance
+    // locks, in case other postings moved the balances since they were read
+    let outcomes = if options.policy.reject_overdrafts {
+        store.record_batch_checked(&items, options.mode).await?
+    } else {
+        store.record_batch(&items, options.mode).await?
+    };
+
+    // Keys recorded by another writer since they were looked up are
+    // compared the same way
+    let raced: Vec<String> = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome.status, BatchItemStatus::Duplicate { .. }))
+        .map(|outcome| outcome.idempotency_key.clone())
+        .collect();
+    let raced: HashMap<String, Transaction> = if raced.is_empty() {
+        HashMap::new()
+    } else {
+        store
+            .get_transactions_by_keys(&raced)
+            .await?
+            .into_iter()
+            .map(|transaction| (transaction.idempotency_key.clone(), transaction))
+            .collect()
+    };
+
+    for ((position, outcome), (transaction, _)) in positions.into_iter().zip(outcomes).zip(&items) {
+        rows[position].status = match outcome.status {
+            BatchItemStatus::Recorded => ImportRowStatus::Recorded {
+                transaction_id: outcome.transaction_id,
+            },
+            BatchItemStatus::Duplicate { existing_transaction_id } => {
+                match raced.get(&outcome.idempotency_key).map(|existing| check_duplicate(existing, transaction)) {
+                    Some(Err(error)) => ImportRowStatus::Rejected { errors: vec![error] },
+                    _ => ImportRowStatus::Duplicate { existing_transaction_id },
+                }
+            }
+            BatchItemStatus::Rejected { reason } => ImportRowStatus::Rejected {
+                errors: vec![RowError::row(reason)],
+            },
+        };
+    }
+
+    Ok(ImportReport {
+        dry_run: false,
+        posted: true,
+        rows,
+    })
+}
+```
@@ -3,6 +3,7 @@ ledger/src/entry.rs
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use crate::transaction::TransactionType;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Entry {
@@ -12,15 +13,28 @@ pub struct Entry {
     pub amount: Decimal,
     pub entry_type: EntryType,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    // Running balance of the balance shard this entry updated. For an
+    // unsharded account that is shard 0 and this is the account balance.
     pub balance_after: Decimal,
+    #[serde(default)]
+    pub balance_shard: i32,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EntryType {
     Debit,
     Credit,
 }
 
+impl EntryType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryType::Debit => "Debit",
+            EntryType::Credit => "Credit",
+        }
+    }
+}
+
 impl Entry {
     pub fn new(
         transaction_id: Uuid,
@@ -37,6 +51,46 @@ impl Entry {
             entry_type,
             timestamp: chrono::Utc::now(),
             balance_after,
+            balance_shard: 0,
+        }
+    }
+
+    // Signed effect on the account balance. Balances are credits minus
+    // debits: crediting a wallet raises its balance, debiting lowers it.
+    pub fn signed_amount(&self) -> Decimal {
+        match self.entry_type {
+            EntryType::Credit => self.amount,
+            EntryType::Debit => -self.amount,
+        }
+    }
+}
+
+// An entry together with the transaction it belongs to, as returned by
+// account-level history queries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountEntry {
+    pub entry: Entry,
+    pub transaction: TransactionSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionSummary {
+    pub id: Uuid,
+    pub transaction_type: TransactionType,
+    pub amount: Decimal,
+    pub source_account_id: Option<Uuid>,
+    pub destination_account_id: Option<Uuid>,
+    pub reason_code: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl TransactionSummary {
+    // The other side of the transaction from the point of view of account_id
+    pub fn counterparty_account_id(&self, account_id: Uuid) -> Option<Uuid> {
+        if self.source_account_id == Some(account_id) {
+            self.destination_account_id
+        } else {
+            self.source_account_id
         }
     }
 }
@@ -0,0 +1,198 @@
+ledger/src/events.rs
+```rust
+use std::sync::Arc;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{Account, AccountType, Entry, LedgerError, LedgerStore, Transaction, TransactionType};
+
+// Events written to the ledger_events outbox. Freezing accounts is not a
+// ledger concept yet, so there is no AccountFrozen event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum LedgerEvent {
+    AccountCreated {
+        account_id: Uuid,
+        account_type: AccountType,
+        currency: String,
+        metadata: serde_json::Value,
+    },
+    TransactionRecorded {
+        transaction_id: Uuid,
+        transaction_type: TransactionType,
+        amount: Decimal,
+        source_account_id: Option<Uuid>,
+        destination_account_id: Option<Uuid>,
+        reason_code: String,
+        idempotency_key: String,
+        timestamp: chrono::DateTime<chrono::Utc>,
+        metadata: serde_json::Value,
+        entries: Vec<Entry>,
+    },
+    // One per account touched by a transaction, carrying the balance as
+    // maintained in account_current_balances after the posting. On a
+    // sharded account, shards other than the one posted to are taken as
+    // last committed.
+    BalanceChanged {
+        account_id: Uuid,
+        transaction_id: Uuid,
+        delta: Decimal,
+        balance: Decimal,
+    },
+}
+
+impl LedgerEvent {
+    pub fn account_created(account: &Account) -> Self {
+        LedgerEvent::AccountCreated {
+            account_id: account.id,
+            account_type: account.account_type,
+            currency: account.currency.clone(),
+            metadata: account.metadata.clone(),
+        }
+    }
+
+    pub fn transaction_recorded(transaction: &Transaction, entries: &[Entry]) -> Self {
+        LedgerEvent::TransactionRecorded {
+            transaction_id: transaction.id,
+            transaction_type: transaction.transaction_type,
+            amount: transaction.amount,
+            source_account_id: transaction.source_account_id,
+            destination_account_id: transaction.destination_account_id,
+            reason_code: transaction.reason_code.clone(),
+            idempotency_key: transaction.idempotency_key.clone(),
+            timestamp: transaction.timestamp,
+            metadata: transaction.metadata.clone(),
+            entries: entries.to_vec(),
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            LedgerEvent::AccountCreated { .. } => "AccountCreated",
+            LedgerEvent::TransactionRecorded { .. } => "TransactionRecorded",
+            LedgerEvent::BalanceChanged { .. } => "BalanceChanged",
+        }
+    }
+
+    // The account or transaction the event is about
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            LedgerEvent::AccountCreated { account_id, .. } => *account_id,
+            LedgerEvent::TransactionRecorded { transaction_id, .. } => *transaction_id,
+            LedgerEvent::BalanceChanged { account_id, .. } => *account_id,
+        }
+    }
+}
+
+// An outbox row awaiting delivery
+#[derive(Debug, Clone, Serialize)]
+pub struct EventRecord {
+    pub seq: i64,
+    pub id: Uuid,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+    pub attempts: i32,
+    pub event: LedgerEvent,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Event delivery failed: {0}")]
+pub struct EventSinkError(pub String);
+
+// Destination for relayed events. Delivery is at-least-once: an event whose
+// delivery succeeded may be handed to the sink again if the relay stops
+// before recording it, so sinks should de-duplicate on EventRecord::id.
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    async fn deliver(&self, event: &EventRecord) -> Result<(), EventSinkError>;
+}
+
+const DEFAULT_BATCH_SIZE: i64 = 100;
+const DEFAULT_MAX_ATTEMPTS: i32 = 10;
+
+// Moves events from the outbox to a sink in seq order. Only one relay should
+// run against a database, otherwise ordering across relays is not preserved.
+// An event that fails max_attempts times is dead-lettered so the events
+// behind it are not held up forever; replay_dead_letter_events on the store
+// queues it again.
+pub struct EventRelay {
+    store: Arc<dyn LedgerStore>,
+    sink: Arc<dyn EventSink>,
+    batch_size: i64,
+    max_attempts: i32,
+}
+
+impl EventRelay {
+    pub fn new(store: Arc<dyn LedgerStore>, sink: Arc<dyn EventSink>) -> Self {
+        Self {
+            store,
+            sink,
+            batch_size: DEFAULT_BATCH_SIZE,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    // Attempts before an event is dead-lettered, including the first
+    pub fn with_max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    // Delivers one batch of pending events and returns how many were
+    // delivered. Stops at the first failure so later events are never
+    // delivered ahead of an earlier one; the failed event is retried first
+    // on the next run. The exception is an event on its last allowed
+    // attempt, which is dead-lettered and skipped.
+    pub async fn run_once(&self) -> Result<usize, LedgerError> {
+        let pending = self.store.get_pending_events(self.batch_size).await?;
+        let mut delivered = 0;
+
+        for record in &pending {
+            if let Err(error) = self.sink.deliver(record).await {
+                if record.attempts + 1 >= self.max_attempts {
+                    tracing::error!(seq = record.seq, %error, "event dead-lettered");
+                    self.store.dead_letter_event(record.seq, &error.to_string()).await?;
+                    continue;
+                }
+                tracing::warn!(seq = record.seq, %error, "event delivery failed");
+                self.store.record_event_failure(record.seq, &error.to_string()).await?;
+                break;
+            }
+            self.store.mark_event_delivered(record.seq).await?;
+            delivered += 1;
+        }
+
+        Ok(delivered)
+    }
+
+    // Polls the outbox every `interval`, draining it completely on each tick
+    pub fn spawn(self: Arc<Self>, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                loop {
+                    match self.run_once().await {
+                        Ok(delivered) if delivered as i64 == self.batch_size => continue,
+                        Ok(delivered) => {
+                            if delivered > 0 {
+                                tracing::debug!(delivered, "ledger events relayed");
+                            }
+                            break;
+                        }
+                        Err(error) => {
+                            tracing::warn!(%error, "event relay run failed");
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+```
@@ -0,0 +1,221 @@
+ledger/src/financial_statements.rs
+```rust
+use std::collections::{HashMap, HashSet};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+use crate::{AccountTotals, AccountType, LedgerError, LedgerStore};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceSheet {
+    pub as_of: chrono::DateTime<chrono::Utc>,
+    pub currency: String,
+    pub assets: StatementSection,
+    pub liabilities: StatementSection,
+    pub equity: StatementSection,
+    pub retained_earnings: Decimal,
+    pub total_equity: Decimal,
+    pub is_balanced: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IncomeStatement {
+    pub period_start: chrono::DateTime<chrono::Utc>,
+    pub period_end: chrono::DateTime<chrono::Utc>,
+    pub currency: String,
+    pub revenue: StatementSection,
+    pub expenses: StatementSection,
+    pub net_income: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementSection {
+    pub account_type: AccountType,
+    pub lines: Vec<ReportLine>,
+    pub total: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportLine {
+    pub account_id: Uuid,
+    pub code: Option<String>,
+    pub name: Option<String>,
+    pub depth: usize,
+    // Balance posted directly to this account
+    pub balance: Decimal,
+    // Balance including every descendant in the chart of accounts
+    pub rollup_balance: Decimal,
+}
+
+pub async fn balance_sheet(
+    store: &(impl LedgerStore + ?Sized),
+    as_of: chrono::DateTime<chrono::Utc>,
+    currency: &str,
+) -> Result<BalanceSheet, LedgerError> {
+    let totals = store.get_account_totals(None, as_of, currency).await?;
+    Ok(BalanceSheet::from_totals(as_of, currency, &totals))
+}
+
+pub async fn income_statement(
+    store: &(impl LedgerStore + ?Sized),
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+    currency: &str,
+) -> Result<IncomeStatement, LedgerError> {
+    // (period_start, period_end], like account statements: entries at
+    // exactly period_start belong to the previous period
+    let activity = store
+        .get_account_totals(Some(period_start), period_end, currency)
+        .await?;
+    Ok(IncomeStatement::from_totals(period_start, period_end, currency, &activity))
+}
+
+impl BalanceSheet {
+    pub fn from_totals(
+        as_of: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+        totals: &[AccountTotals],
+    ) -> Self {
+        let assets = build_section(AccountType::Asset, totals);
+        let liabilities = build_section(AccountType::Liability, totals);
+        let equity = build_section(AccountType::Equity, totals);
+
+        // Revenue and expense accounts are never closed out, so their net
+        // is carried into equity to keep the accounting equation intact
+        let retained_earnings = build_section(AccountType::Revenue, totals).total
+            - build_section(AccountType::Expense, totals).total;
+        let total_equity = equity.total + retained_earnings;
+        let is_balanced = assets.total == liabilities.total + total_equity;
+
+        Self {
+            as_of,
+            currency: currency.to_string(),
+            assets,
+            liabilities,
+            equity,
+            retained_earnings,
+            total_equity,
+            is_balanced,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl IncomeStatement {
+    pub fn from_totals(
+        period_start: chrono::DateTime<chrono::Utc>,
+        period_end: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+        activity: &[AccountTotals],
+    ) -> Self {
+        let revenue = build_section(AccountType::Revenue, activity);
+        let expenses = build_section(AccountType::Expense, activity);
+        let net_income = revenue.total - expenses.total;
+
+        Self {
+            period_start,
+            period_end,
+            currency: currency.to_string(),
+            revenue,
+            expenses,
+            net_income,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+fn build_section(account_type: AccountType, totals: &[AccountTotals]) -> StatementSection {
+    let rows: Vec<&AccountTotals> = totals
+        .iter()
+        .filter(|row| row.account_type == account_type)
+        .collect();
+
+    let by_code: HashMap<&str, usize> = rows
+        .iter()
+        .enumerate()
+        .filter_map(|(index, row)| row.code.as_deref().map(|code| (code, index)))
+        .collect();
+
+    // Accounts whose parent is not part of this section are treated as roots
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        match row.parent_code.as_deref().and_then(|parent| by_code.get(parent)) {
+            Some(&parent) if parent != index => children.entry(parent).or_default().push(index),
+            _ => roots.push(index),
+        }
+    }
+
+    // Coded accounts first in code order, uncoded accounts after them
+    let sort_key = |index: &usize| {
+        let row = rows[*index];
+        (row.code.is_none(), row.code.clone(), row.account_id)
+    };
+    roots.sort_by_key(sort_key);
+    for siblings in children.values_mut() {
+        siblings.sort_by_key(sort_key);
+    }
+
+    let mut lines = Vec::with_capacity(rows.len());
+    let mut visited = HashSet::new();
+    for root in roots {
+        push_lines(root, 0, &rows, &children, &mut visited, &mut lines);
+    }
+
+    // A parent_code cycle leaves accounts unreachable from any root
+    for index in 0..rows.len() {
+        if !visited.contains(&index) {
+            push_lines(index, 0, &rows, &children, &mut visited, &mut lines);
+        }
+    }
+
+    let total = lines.iter().map(|line| line.balance).sum();
+
+    StatementSection {
+        account_type,
+        lines,
+        total,
+    }
+}
+
+fn push_lines(
+    index: usize,
+    depth: usize,
+    rows: &[&AccountTotals],
+    children: &HashMap<usize, Vec<usize>>,
+    visited: &mut HashSet<usize>,
+    lines: &mut Vec<ReportLine>,
+) -> Decimal {
+    if !visited.insert(index) {
+        return Decimal::ZERO;
+    }
+
+    let row = rows[index];
+    let balance = row.account_type.normal_balance(row.debit_total, row.credit_total);
+    let position = lines.len();
+    lines.push(ReportLine {
+        account_id: row.account_id,
+        code: row.code.clone(),
+        name: row.name.clone(),
+        depth,
+        balance,
+        rollup_balance: balance,
+    });
+
+    let mut rollup_balance = balance;
+    if let Some(kids) = children.get(&index) {
+        for &child in kids {
+            rollup_balance += push_lines(child, depth + 1, rows, children, visited, lines);
+        }
+    }
+    lines[position].rollup_balance = rollup_balance;
+
+    rollup_balance
+}
+```
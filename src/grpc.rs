@@ -0,0 +1,395 @@
+ledger/src/grpc.rs
+```rust
+use std::pin::Pin;
+use std::sync::Arc;
+use rust_decimal::Decimal;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+use tonic::{Request, Response, Status};
+use uuid::Uuid;
+use crate::{
+    Account, AccountEntry, AccountType, Entry, EntryFilter, EntryType, LedgerError,
+    LedgerService, SortOrder, Transaction, TransactionFilter, TransactionSummary, TransactionType,
+};
+
+pub mod pb {
+    tonic::include_proto!("ledger.v1");
+}
+
+pub use pb::ledger_client::LedgerClient;
+pub use pb::ledger_server::LedgerServer;
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 500;
+const STREAM_PAGE_SIZE: i64 = 500;
+
+pub struct LedgerGrpcService {
+    service: Arc<LedgerService>,
+}
+
+impl LedgerGrpcService {
+    pub fn new(service: Arc<LedgerService>) -> Self {
+        Self { service }
+    }
+
+    pub fn into_server(self) -> LedgerServer<Self> {
+        LedgerServer::new(self)
+    }
+}
+
+pub async fn serve(
+    service: Arc<LedgerService>,
+    addr: std::net::SocketAddr,
+) -> Result<(), tonic::transport::Error> {
+    tracing::info!(%addr, "ledger gRPC API listening");
+    tonic::transport::Server::builder()
+        .add_service(LedgerGrpcService::new(service).into_server())
+        .serve(addr)
+        .await
+}
+
+impl From<LedgerError> for Status {
+    fn from(error: LedgerError) -> Self {
+        match &error {
+            LedgerError::AccountNotFound | LedgerError::TransactionNotFound => {
+                Status::not_found(error.to_string())
+            }
+            LedgerError::InsufficientBalance => Status::failed_precondition(error.to_string()),
+            LedgerError::TransactionError(_) | LedgerError::InvalidCursor => {
+                Status::invalid_argument(error.to_string())
+            }
+            LedgerError::IdempotencyViolation => Status::already_exists(error.to_string()),
+            _ => {
+                tracing::error!(%error, "ledger request failed");
+                Status::internal("Internal server error")
+            }
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl pb::ledger_server::Ledger for LedgerGrpcService {
+    async fn create_account(
+        &self,
+        request: Request<pb::CreateAccountRequest>,
+    ) -> Result<Response<pb::Account>, Status> {
+        let request = request.into_inner();
+        let account_type = account_type_from_pb(request.account_type)?;
+        let metadata = if request.metadata_json.is_empty() {
+            serde_json::json!({})
+        } else {
+            match serde_json::from_str(&request.metadata_json) {
+                Ok(metadata @ serde_json::Value::Object(_)) => metadata,
+                _ => return Err(Status::invalid_argument("metadata_json must be a JSON object")),
+            }
+        };
+
+        let account = self
+            .service
+            .create_account_with_metadata(account_type, &request.currency, metadata)
+            .await?;
+        Ok(Response::new(account_to_pb(&account)))
+    }
+
+    async fn get_account(
+        &self,
+        request: Request<pb::GetAccountRequest>,
+    ) -> Result<Response<pb::Account>, Status> {
+        let account_id = parse_uuid(&request.into_inner().account_id)?;
+        let account = self.service.get_account(account_id).await?;
+        Ok(Response::new(account_to_pb(&account)))
+    }
+
+    async fn get_balance(
+        &self,
+        request: Request<pb::GetBalanceRequest>,
+    ) -> Result<Response<pb::Balance>, Status> {
+        let account_id = parse_uuid(&request.into_inner().account_id)?;
+        let account = self.service.get_account(account_id).await?;
+        let balance = self.service.get_account_balance(account_id).await?;
+        Ok(Response::new(pb::Balance {
+            account_id: account_id.to_string(),
+            currency: account.currency,
+            balance: balance.to_string(),
+        }))
+    }
+
+    async fn record_transaction(
+        &self,
+        request: Request<pb::RecordTransactionRequest>,
+    ) -> Result<Response<pb::Transaction>, Status> {
+        let request = request.into_inner();
+        if request.idempotency_key.is_empty() {
+            return Err(Status::invalid_argument("idempotency_key is required"));
+        }
+        let amount = parse_decimal(&request.amount)?;
+        let source = request.source_account_id.as_deref().map(parse_uuid).transpose()?;
+        let destination = request.destination_account_id.as_deref().map(parse_uuid).transpose()?;
+        let key = &request.idempotency_key;
+        let reason = &request.reason_code;
+        let transaction_type = transaction_type_from_pb(request.transaction_type)?;
+        let replay = |existing: Transaction| {
+            if existing.matches_request(transaction_type, amount, source, destination, reason) {
+                Ok(Response::new(transaction_to_pb(&existing)))
+            } else {
+                Err(Status::already_exists(
+                    "idempotency_key was already used for a different request",
+                ))
+            }
+        };
+
+        // Looked up before posting, so a retry replays even if its balance
+        // check would now fail
+        if let Some(existing) = self.service.get_transaction_by_key(key).await? {
+            return replay(existing);
+        }
+
+        let result = match transaction_type {
+            TransactionType::Credit => {
+                let account_id = destination
+                    .ok_or_else(|| Status::invalid_argument("destination_account_id is required"))?;
+                self.service.credit_account(account_id, amount, reason, key).await
+            }
+            TransactionType::Debit => {
+                let account_id = source
+                    .ok_or_else(|| Status::invalid_argument("source_account_id is required"))?;
+                self.service.debit_account(account_id, amount, reason, key).await
+            }
+            TransactionType::Transfer => {
+                let (Some(from), Some(to)) = (source, destination) else {
+                    return Err(Status::invalid_argument(
+                        "source_account_id and destination_account_id are required",
+                    ));
+                };
+                self.service.transfer(from, to, amount, reason, key).await
+            }
+            TransactionType::Reversal | TransactionType::Adjustment => {
+                return Err(Status::unimplemented(
+                    "Only Credit, Debit and Transfer can be recorded through this RPC",
+                ));
+            }
+        };
+
+        match result {
+            Ok(transaction) => Ok(Response::new(transaction_to_pb(&transaction))),
+            // A concurrent request with the same key committed first
+            Err(LedgerError::IdempotencyViolation) => replay(
+                self.service
+                    .get_transaction_by_key(key)
+                    .await?
+                    .ok_or(LedgerError::IdempotencyViolation)?,
+            ),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    async fn list_transactions(
+        &self,
+        request: Request<pb::ListTransactionsRequest>,
+    ) -> Result<Response<pb::ListTransactionsResponse>, Status> {
+        let request = request.into_inner();
+        let account_id = parse_uuid(&request.account_id)?;
+        let limit = match request.limit {
+            limit if limit <= 0 => DEFAULT_PAGE_SIZE,
+            limit => limit.min(MAX_PAGE_SIZE),
+        };
+        let filter = TransactionFilter {
+            from: request.from.map(timestamp_from_pb).transpose()?,
+            to: request.to.map(timestamp_from_pb).transpose()?,
+            ..TransactionFilter::default()
+        };
+        let cursor = (!request.cursor.is_empty()).then_some(request.cursor.as_str());
+
+        let page = self
+            .service
+            .get_account_transactions_page(account_id, &filter, cursor, limit)
+            .await?;
+        Ok(Response::new(pb::ListTransactionsResponse {
+            transactions: page.items.iter().map(transaction_to_pb).collect(),
+            next_cursor: page.next_cursor.unwrap_or_default(),
+        }))
+    }
+
+    type StreamAccountEntriesStream =
+        Pin<Box<dyn Stream<Item = Result<pb::AccountEntry, Status>> + Send + 'static>>;
+
+    async fn stream_account_entries(
+        &self,
+        request: Request<pb::StreamAccountEntriesRequest>,
+    ) -> Result<Response<Self::StreamAccountEntriesStream>, Status> {
+        let request = request.into_inner();
+        let account_id = parse_uuid(&request.account_id)?;
+        let filter = EntryFilter {
+            from: request.from.map(timestamp_from_pb).transpose()?,
+            to: request.to.map(timestamp_from_pb).transpose()?,
+            order: if request.oldest_first {
+                SortOrder::OldestFirst
+            } else {
+                SortOrder::NewestFirst
+            },
+            ..EntryFilter::default()
+        };
+        self.service.get_account(account_id).await?;
+
+        // Pages are fetched lazily as the client consumes the stream; the
+        // bounded channel applies backpressure to the reader task
+        let (sender, receiver) = tokio::sync::mpsc::channel(STREAM_PAGE_SIZE as usize);
+        let service = self.service.clone();
+        tokio::spawn(async move {
+            let mut cursor: Option<String> = None;
+            loop {
+                let page = match service
+                    .get_account_entries(account_id, &filter, cursor.as_deref(), STREAM_PAGE_SIZE)
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(error) => {
+                        let _ = sender.send(Err(error.into())).await;
+                        return;
+                    }
+                };
+
+                for item in &page.items {
+                    if sender.send(Ok(account_entry_to_pb(item))).await.is_err() {
+                        return;
+                    }
+                }
+
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => return,
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(receiver))))
+    }
+}
+
+fn parse_uuid(value: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(value).map_err(|_| Status::invalid_argument(format!("invalid id: {}", value)))
+}
+
+fn parse_decimal(value: &str) -> Result<Decimal, Status> {
+    value
+        .parse()
+        .map_err(|_| Status::invalid_argument(format!("invalid decimal amount: {}", value)))
+}
+
+fn timestamp_to_pb(timestamp: chrono::DateTime<chrono::Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: timestamp.timestamp(),
+        nanos: timestamp.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn timestamp_from_pb(
+    timestamp: prost_types::Timestamp,
+) -> Result<chrono::DateTime<chrono::Utc>, Status> {
+    chrono::DateTime::from_timestamp(timestamp.seconds, timestamp.nanos.max(0) as u32)
+        .ok_or_else(|| Status::invalid_argument("timestamp out of range"))
+}
+
+fn account_type_from_pb(value: i32) -> Result<AccountType, Status> {
+    match pb::AccountType::try_from(value) {
+        Ok(pb::AccountType::Asset) => Ok(AccountType::Asset),
+        Ok(pb::AccountType::Liability) => Ok(AccountType::Liability),
+        Ok(pb::AccountType::Equity) => Ok(AccountType::Equity),
+        Ok(pb::AccountType::Revenue) => Ok(AccountType::Revenue),
+        Ok(pb::AccountType::Expense) => Ok(AccountType::Expense),
+        _ => Err(Status::invalid_argument("account_type is required")),
+    }
+}
+
+fn account_type_to_pb(value: AccountType) -> pb::AccountType {
+    match value {
+        AccountType::Asset => pb::AccountType::Asset,
+        AccountType::Liability => pb::AccountType::Liability,
+        AccountType::Equity => pb::AccountType::Equity,
+        AccountType::Revenue => pb::AccountType::Revenue,
+        AccountType::Expense => pb::AccountType::Expense,
+    }
+}
+
+fn transaction_type_from_pb(value: i32) -> Result<TransactionType, Status> {
+    match pb::TransactionType::try_from(value) {
+        Ok(pb::TransactionType::Credit) => Ok(TransactionType::Credit),
+        Ok(pb::TransactionType::Debit) => Ok(TransactionType::Debit),
+        Ok(pb::TransactionType::Transfer) => Ok(TransactionType::Transfer),
+        Ok(pb::TransactionType::Reversal) => Ok(TransactionType::Reversal),
+        Ok(pb::TransactionType::Adjustment) => Ok(TransactionType::Adjustment),
+        _ => Err(Status::invalid_argument("transaction_type is required")),
+    }
+}
+
+fn transaction_type_to_pb(value: TransactionType) -> pb::TransactionType {
+    match value {
+        TransactionType::Credit => pb::TransactionType::Credit,
+        TransactionType::Debit => pb::TransactionType::Debit,
+        TransactionType::Transfer => pb::TransactionType::Transfer,
+        TransactionType::Reversal => pb::TransactionType::Reversal,
+        TransactionType::Adjustment => pb::TransactionType::Adjustment,
+    }
+}
+
+fn entry_type_to_pb(value: EntryType) -> pb::EntryType {
+    match value {
+        EntryType::Debit => pb::EntryType::Debit,
+        EntryType::Credit => pb::EntryType::Credit,
+    }
+}
+
+fn account_to_pb(account: &Account) -> pb::Account {
+    pb::Account {
+        id: account.id.to_string(),
+        account_type: account_type_to_pb(account.account_type) as i32,
+        currency: account.currency.clone(),
+        created_at: Some(timestamp_to_pb(account.created_at)),
+        metadata_json: account.metadata.to_string(),
+    }
+}
+
+fn transaction_to_pb(transaction: &Transaction) -> pb::Transaction {
+    pb::Transaction {
+        id: transaction.id.to_string(),
+        transaction_type: transaction_type_to_pb(transaction.transaction_type) as i32,
+        amount: transaction.amount.to_string(),
+        source_account_id: transaction.source_account_id.map(|id| id.to_string()),
+        destination_account_id: transaction.destination_account_id.map(|id| id.to_string()),
+        timestamp: Some(timestamp_to_pb(transaction.timestamp)),
+        reason_code: transaction.reason_code.clone(),
+        metadata_json: transaction.metadata.to_string(),
+        idempotency_key: transaction.idempotency_key.clone(),
+    }
+}
+
+fn entry_to_pb(entry: &Entry) -> pb::Entry {
+    pb::Entry {
+        id: entry.id.to_string(),
+        transaction_id: entry.transaction_id.to_string(),
+        account_id: entry.account_id.to_string(),
+        amount: entry.amount.to_string(),
+        entry_type: entry_type_to_pb(entry.entry_type) as i32,
+        timestamp: Some(timestamp_to_pb(entry.timestamp)),
+        balance_after: entry.balance_after.to_string(),
+    }
+}
+
+fn transaction_summary_to_pb(summary: &TransactionSummary) -> pb::TransactionSummary {
+    pb::TransactionSummary {
+        id: summary.id.to_string(),
+        transaction_type: transaction_type_to_pb(summary.transaction_type) as i32,
+        amount: summary.amount.to_string(),
+        source_account_id: summary.source_account_id.map(|id| id.to_string()),
+        destination_account_id: summary.destination_account_id.map(|id| id.to_string()),
+        reason_code: summary.reason_code.clone(),
+        timestamp: Some(timestamp_to_pb(summary.timestamp)),
+    }
+}
+
+fn account_entry_to_pb(item: &AccountEntry) -> pb::AccountEntry {
+    pb::AccountEntry {
+        entry: Some(entry_to_pb(&item.entry)),
+        transaction: Some(transaction_summary_to_pb(&item.transaction)),
+    }
+}
+```
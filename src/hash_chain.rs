@@ -0,0 +1,218 @@
+ledger/src/hash_chain.rs
+```rust
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::{Entry, LedgerError, LedgerStore, Transaction};
+
+pub const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+const PAGE_SIZE: i64 = 1000;
+
+// One link per recorded transaction, in recording order
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainLink {
+    pub seq: i64,
+    pub transaction_id: Uuid,
+    #[serde(serialize_with = "serialize_hex")]
+    pub prev_hash: Vec<u8>,
+    #[serde(serialize_with = "serialize_hex")]
+    pub hash: Vec<u8>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+// A chain head captured for external notarization. Anyone holding an
+// exported anchor can later confirm the chain up to seq was not rewritten.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainAnchor {
+    pub id: i64,
+    pub seq: i64,
+    #[serde(serialize_with = "serialize_hex")]
+    pub hash: Vec<u8>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChainVerificationReport {
+    pub links_checked: u64,
+    pub head: Option<ChainLink>,
+    pub violations: Vec<ChainViolation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ChainViolation {
+    // A link is missing, i.e. chain rows were deleted
+    SequenceGap { expected_seq: i64, found_seq: i64 },
+    // A link does not point at its predecessor
+    PrevHashMismatch { seq: i64, transaction_id: Uuid },
+    // The transaction or its entries changed after being recorded
+    HashMismatch { seq: i64, transaction_id: Uuid },
+    MissingTransaction { seq: i64, transaction_id: Uuid },
+    // Transactions that were written without going through the chain
+    UnchainedTransaction { transaction_id: Uuid },
+    AnchorMismatch { anchor_id: i64, seq: i64 },
+}
+
+impl ChainVerificationReport {
+    pub fn is_intact(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+#[derive(Serialize)]
+struct CanonicalTransaction<'a> {
+    id: Uuid,
+    transaction_type: &'static str,
+    amount: String,
+    source_account_id: Option<Uuid>,
+    destination_account_id: Option<Uuid>,
+    timestamp: i64,
+    reason_code: &'a str,
+    metadata: &'a serde_json::Value,
+    idempotency_key: &'a str,
+    entries: Vec<CanonicalEntry>,
+}
+
+#[derive(Serialize)]
+struct CanonicalEntry {
+    id: Uuid,
+    account_id: Uuid,
+    amount: String,
+    entry_type: &'static str,
+    timestamp: i64,
+    balance_after: String,
+    balance_shard: i32,
+}
+
+// Serialization that survives a round trip through Postgres: decimals are
+// normalized (the columns pad to 8 places), timestamps are reduced to the
+// microsecond precision of TIMESTAMPTZ, entries are ordered by id and
+// metadata keys come out sorted.
+pub fn canonical_bytes(transaction: &Transaction, entries: &[Entry]) -> Vec<u8> {
+    let mut entries: Vec<CanonicalEntry> = entries
+        .iter()
+        .map(|entry| CanonicalEntry {
+            id: entry.id,
+            account_id: entry.account_id,
+            amount: entry.amount.normalize().to_string(),
+            entry_type: entry.entry_type.as_str(),
+            timestamp: entry.timestamp.timestamp_micros(),
+            balance_after: entry.balance_after.normalize().to_string(),
+            balance_shard: entry.balance_shard,
+        })
+        .collect();
+    entries.sort_by_key(|entry| entry.id);
+
+    let canonical = CanonicalTransaction {
+        id: transaction.id,
+        transaction_type: transaction.transaction_type.as_str(),
+        amount: transaction.amount.normalize().to_string(),
+        source_account_id: transaction.source_account_id,
+        destination_account_id: transaction.destination_account_id,
+        timestamp: transaction.timestamp.timestamp_micros(),
+        reason_code: &transaction.reason_code,
+        metadata: &transaction.metadata,
+        idempotency_key: &transaction.idempotency_key,
+        entries,
+    };
+
+    serde_json::to_vec(&canonical).expect("canonical transaction is always serializable")
+}
+
+pub fn chain_hash(prev_hash: &[u8], transaction: &Transaction, entries: &[Entry]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash);
+    hasher.update(canonical_bytes(transaction, entries));
+    hasher.finalize().to_vec()
+}
+
+// Walks the chain from the first link, recomputing every hash from the rows
+// as they are stored now, then checks exported anchors against it.
+pub async fn verify_chain(
+    store: &(impl LedgerStore + ?Sized),
+) -> Result<ChainVerificationReport, LedgerError> {
+    let mut violations = Vec::new();
+    let mut links_checked = 0;
+    let mut head: Option<ChainLink> = None;
+    let mut link_hashes = std::collections::HashMap::new();
+
+    loop {
+        let after_seq = head.as_ref().map(|link| link.seq).unwrap_or(0);
+        let links = store.get_chain_links(after_seq, PAGE_SIZE).await?;
+        if links.is_empty() {
+            break;
+        }
+
+        for link in links {
+            links_checked += 1;
+
+            let expected_seq = head.as_ref().map(|link| link.seq + 1).unwrap_or(1);
+            if link.seq != expected_seq {
+                violations.push(ChainViolation::SequenceGap {
+                    expected_seq,
+                    found_seq: link.seq,
+                });
+            }
+
+            let expected_prev = head
+                .as_ref()
+                .map(|link| link.hash.as_slice())
+                .unwrap_or(&GENESIS_HASH);
+            if link.prev_hash != expected_prev {
+                violations.push(ChainViolation::PrevHashMismatch {
+                    seq: link.seq,
+                    transaction_id: link.transaction_id,
+                });
+            }
+
+            match store.get_transaction(&link.transaction_id).await? {
+                Some(transaction) => {
+                    let entries = store.get_entries_for_transaction(&link.transaction_id).await?;
+                    if chain_hash(&link.prev_hash, &transaction, &entries) != link.hash {
+                        violations.push(ChainViolation::HashMismatch {
+                            seq: link.seq,
+                            transaction_id: link.transaction_id,
+                        });
+                    }
+                }
+                None => violations.push(ChainViolation::MissingTransaction {
+                    seq: link.seq,
+                    transaction_id: link.transaction_id,
+                }),
+            }
+
+            link_hashes.insert(link.seq, link.hash.clone());
+            head = Some(link);
+        }
+    }
+
+    for transaction_id in store.find_unchained_transactions().await? {
+        violations.push(ChainViolation::UnchainedTransaction { transaction_id });
+    }
+
+    for anchor in store.list_chain_anchors().await? {
+        if link_hashes.get(&anchor.seq) != Some(&anchor.hash) {
+            violations.push(ChainViolation::AnchorMismatch {
+                anchor_id: anchor.id,
+                seq: anchor.seq,
+            });
+        }
+    }
+
+    Ok(ChainVerificationReport {
+        links_checked,
+        head,
+        violations,
+    })
+}
+
+pub async fn export_anchors(store: &(impl LedgerStore + ?Sized)) -> Result<String, LedgerError> {
+    let anchors = store.list_chain_anchors().await?;
+    Ok(serde_json::to_string_pretty(&anchors).expect("anchors are always serializable"))
+}
+
+fn serialize_hex<S: serde::Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(bytes))
+}
+```
@@ -0,0 +1,316 @@
+ledger/src/http.rs
+```rust
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{
+    AccountType, Direction, LedgerError, LedgerService, Transaction, TransactionFilter, TransactionType,
+};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 500;
+
+pub fn router(service: Arc<LedgerService>) -> Router {
+    Router::new()
+        .route("/accounts", post(create_account))
+        .route("/accounts/:id/balance", get(get_balance))
+        .route("/accounts/:id/transactions", get(get_transactions))
+        .route("/transactions", post(create_transaction))
+        .route("/reconcile", post(reconcile))
+        .with_state(service)
+}
+
+pub async fn serve(service: Arc<LedgerService>, addr: std::net::SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!(%addr, "ledger HTTP API listening");
+    axum::serve(listener, router(service)).await
+}
+
+// Error body: {"error": {"code": "insufficient_balance", "message": "..."}}
+#[derive(Debug)]
+pub struct ApiError {
+    status: StatusCode,
+    code: &'static str,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: ErrorDetail<'a>,
+}
+
+#[derive(Serialize)]
+struct ErrorDetail<'a> {
+    code: &'a str,
+    message: &'a str,
+}
+
+impl ApiError {
+    fn new(status: StatusCode, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<LedgerError> for ApiError {
+    fn from(error: LedgerError) -> Self {
+        let (status, code) = match &error {
+            LedgerError::AccountNotFound => (StatusCode::NOT_FOUND, "account_not_found"),
+            LedgerError::TransactionNotFound => (StatusCode::NOT_FOUND, "transaction_not_found"),
+            LedgerError::InsufficientBalance => (StatusCode::UNPROCESSABLE_ENTITY, "insufficient_balance"),
+            LedgerError::TransactionError(_) => (StatusCode::UNPROCESSABLE_ENTITY, "invalid_transaction"),
+            LedgerError::IdempotencyViolation => (StatusCode::CONFLICT, "idempotency_violation"),
+            LedgerError::InvalidCursor => (StatusCode::BAD_REQUEST, "invalid_cursor"),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, "internal_error"),
+        };
+
+        // Database and other internal details stay in the logs
+        let message = if status == StatusCode::INTERNAL_SERVER_ERROR {
+            tracing::error!(%error, "ledger request failed");
+            "Internal server error".to_string()
+        } else {
+            error.to_string()
+        };
+
+        Self::new(status, code, message)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            error: ErrorDetail {
+                code: self.code,
+                message: &self.message,
+            },
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAccountRequest {
+    pub account_type: AccountType,
+    pub currency: String,
+    #[serde(default)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTransactionRequest {
+    pub transaction_type: TransactionType,
+    pub amount: Decimal,
+    #[serde(default)]
+    pub source_account_id: Option<Uuid>,
+    #[serde(default)]
+    pub destination_account_id: Option<Uuid>,
+    pub reason_code: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BalanceResponse {
+    pub account_id: Uuid,
+    pub currency: String,
+    pub balance: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransactionsQuery {
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    pub transaction_type: Option<TransactionType>,
+    pub reason_code: Option<String>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub direction: Option<Direction>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReconcileRequest {
+    pub account_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub expected_balances: Option<Vec<ExpectedBalance>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExpectedBalance {
+    pub account_id: Uuid,
+    pub balance: Decimal,
+}
+
+async fn create_account(
+    State(service): State<Arc<LedgerService>>,
+    Json(request): Json<CreateAccountRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let account = match request.metadata {
+        Some(metadata) => {
+            service
+                .create_account_with_metadata(request.account_type, &request.currency, metadata)
+                .await?
+        }
+        None => service.create_account(request.account_type, &request.currency).await?,
+    };
+    Ok((StatusCode::CREATED, Json(account)))
+}
+
+async fn create_transaction(
+    State(service): State<Arc<LedgerService>>,
+    headers: HeaderMap,
+    Json(request): Json<CreateTransactionRequest>,
+) -> Result<Response, ApiError> {
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::BAD_REQUEST,
+                "missing_idempotency_key",
+                "Idempotency-Key header is required",
+            )
+        })?
+        .to_string();
+
+    // Looked up before posting, so a retry of a debit that has since
+    // drained the account still replays instead of failing the balance check
+    if let Some(existing) = service.get_transaction_by_key(&idempotency_key).await? {
+        return replay(existing, &request);
+    }
+
+    let result = match request.transaction_type {
+        TransactionType::Credit => {
+            let account_id = request.destination_account_id.ok_or_else(|| {
+                ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, "invalid_transaction", "destination_account_id is required")
+            })?;
+            service
+                .credit_account(account_id, request.amount, &request.reason_code, &idempotency_key)
+                .await
+        }
+        TransactionType::Debit => {
+            let account_id = request.source_account_id.ok_or_else(|| {
+                ApiError::new(StatusCode::UNPROCESSABLE_ENTITY, "invalid_transaction", "source_account_id is required")
+            })?;
+            service
+                .debit_account(account_id, request.amount, &request.reason_code, &idempotency_key)
+                .await
+        }
+        TransactionType::Transfer => {
+            let (Some(from), Some(to)) = (request.source_account_id, request.destination_account_id) else {
+                return Err(ApiError::new(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "invalid_transaction",
+                    "source_account_id and destination_account_id are required",
+                ));
+            };
+            service
+                .transfer(from, to, request.amount, &request.reason_code, &idempotency_key)
+                .await
+        }
+        TransactionType::Reversal | TransactionType::Adjustment => {
+            return Err(ApiError::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "unsupported_transaction_type",
+                "Only Credit, Debit and Transfer can be posted through this endpoint",
+            ));
+        }
+    };
+
+    match result {
+        Ok(transaction) => Ok((StatusCode::CREATED, Json(transaction)).into_response()),
+        // A concurrent request with the same key committed first
+        Err(LedgerError::IdempotencyViolation) => {
+            let existing = service
+                .get_transaction_by_key(&idempotency_key)
+                .await?
+                .ok_or(LedgerError::IdempotencyViolation)?;
+            replay(existing, &request)
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+// A retried request gets the original result; a different request reusing
+// the key is refused rather than answered with someone else's transaction
+fn replay(existing: Transaction, request: &CreateTransactionRequest) -> Result<Response, ApiError> {
+    if !existing.matches_request(
+        request.transaction_type,
+        request.amount,
+        request.source_account_id,
+        request.destination_account_id,
+        &request.reason_code,
+    ) {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "idempotency_violation",
+            "Idempotency-Key was already used for a different request",
+        ));
+    }
+    Ok((StatusCode::OK, Json(existing)).into_response())
+}
+
+async fn get_balance(
+    State(service): State<Arc<LedgerService>>,
+    Path(account_id): Path<Uuid>,
+) -> Result<Json<BalanceResponse>, ApiError> {
+    let account = service.get_account(account_id).await?;
+    let balance = service.get_account_balance(account_id).await?;
+    Ok(Json(BalanceResponse {
+        account_id,
+        currency: account.currency,
+        balance,
+    }))
+}
+
+async fn get_transactions(
+    State(service): State<Arc<LedgerService>>,
+    Path(account_id): Path<Uuid>,
+    Query(query): Query<TransactionsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    service.get_account(account_id).await?;
+
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+    let filter = TransactionFilter {
+        from: query.from,
+        to: query.to,
+        transaction_type: query.transaction_type,
+        reason_code: query.reason_code,
+        min_amount: query.min_amount,
+        max_amount: query.max_amount,
+        direction: query.direction,
+    };
+
+    let page = service
+        .get_account_transactions_page(account_id, &filter, query.cursor.as_deref(), limit)
+        .await?;
+    Ok(Json(page))
+}
+
+async fn reconcile(
+    State(service): State<Arc<LedgerService>>,
+    Json(request): Json<ReconcileRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let expected: Option<Vec<(Uuid, Decimal)>> = request
+        .expected_balances
+        .map(|balances| balances.into_iter().map(|b| (b.account_id, b.balance)).collect());
+
+    let report = service
+        .reconcile(&request.account_ids, expected.as_deref())
+        .await?;
+    Ok(Json(report))
+}
+```
@@ -0,0 +1,217 @@
+ledger/src/integrity.rs
+```rust
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use uuid::Uuid;
+use crate::{Cursor, EntryFilter, LedgerError, LedgerStore, SortOrder, TransactionType};
+
+const PAGE_SIZE: i64 = 1000;
+
+// Per-transaction leg sums, computed by the store so the checker does not
+// have to load every entry of every transaction
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionLegTotals {
+    pub transaction_id: Uuid,
+    pub transaction_type: TransactionType,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub amount: Decimal,
+    pub entry_count: i64,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IntegrityReport {
+    pub started_at: chrono::DateTime<chrono::Utc>,
+    pub finished_at: chrono::DateTime<chrono::Utc>,
+    pub accounts_checked: u64,
+    pub entries_checked: u64,
+    pub transactions_checked: u64,
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Violation {
+    // balance_after differs from the running sum of the entries on the
+    // same balance shard
+    BalanceAfterMismatch {
+        account_id: Uuid,
+        entry_id: Uuid,
+        expected: Decimal,
+        recorded: Decimal,
+    },
+    // The maintained current balance differs from the sum of all entries
+    CurrentBalanceMismatch {
+        account_id: Uuid,
+        expected: Decimal,
+        recorded: Decimal,
+    },
+    UnbalancedTransaction {
+        transaction_id: Uuid,
+        debit_total: Decimal,
+        credit_total: Decimal,
+    },
+    AmountMismatch {
+        transaction_id: Uuid,
+        amount: Decimal,
+        debit_total: Decimal,
+        credit_total: Decimal,
+    },
+    TransactionWithoutEntries {
+        transaction_id: Uuid,
+    },
+    OrphanEntry {
+        entry_id: Uuid,
+        transaction_id: Uuid,
+        account_id: Uuid,
+    },
+}
+
+impl IntegrityReport {
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+// Scans the whole ledger. This reads every entry once, so run it against a
+// replica or off-peak on large ledgers.
+pub async fn verify_ledger(
+    store: &(impl LedgerStore + ?Sized),
+) -> Result<IntegrityReport, LedgerError> {
+    let started_at = chrono::Utc::now();
+    let mut violations = Vec::new();
+    let mut accounts_checked = 0;
+    let mut entries_checked = 0;
+    let mut transactions_checked = 0;
+
+    // 1. Running balances per account
+    let filter = EntryFilter {
+        order: SortOrder::OldestFirst,
+        ..EntryFilter::default()
+    };
+    for account in store.list_accounts().await? {
+        accounts_checked += 1;
+        // balance_after is per balance shard; the account balance is the
+        // sum over shards
+        let mut shard_balances: HashMap<i32, Decimal> = HashMap::new();
+        let mut running_balance = Decimal::ZERO;
+        let mut cursor: Option<Cursor> = None;
+
+        loop {
+            let page = store
+                .get_account_entries(&account.id, &filter, cursor.as_ref(), PAGE_SIZE)
+                .await?;
+
+            for item in &page.items {
+                entries_checked += 1;
+                running_balance += item.entry.signed_amount();
+                let shard_balance = shard_balances.entry(item.entry.balance_shard).or_default();
+                *shard_balance += item.entry.signed_amount();
+                if item.entry.balance_after != *shard_balance {
+                    violations.push(Violation::BalanceAfterMismatch {
+                        account_id: account.id,
+                        entry_id: item.entry.id,
+                        expected: *shard_balance,
+                        recorded: item.entry.balance_after,
+                    });
+                }
+            }
+
+            match page.next_cursor {
+                Some(next) => cursor = Some(Cursor::decode(&next)?),
+                None => break,
+            }
+        }
+
+        let recorded = store.get_account_balance(&account.id).await?;
+        if recorded != running_balance {
+            violations.push(Violation::CurrentBalanceMismatch {
+                account_id: account.id,
+                expected: running_balance,
+                recorded,
+            });
+        }
+    }
+
+    // 2. Double-entry and amount invariants per transaction
+    let mut cursor: Option<Cursor> = None;
+    loop {
+        let page = store
+            .get_transaction_leg_totals(cursor.as_ref(), PAGE_SIZE)
+            .await?;
+
+        for totals in &page.items {
+            transactions_checked += 1;
+            violations.extend(check_transaction(totals));
+        }
+
+        match page.next_cursor {
+            Some(next) => cursor = Some(Cursor::decode(&next)?),
+            None => break,
+        }
+    }
+
+    // 3. Entries whose transaction no longer exists
+    for entry in store.find_orphan_entries().await? {
+        violations.push(Violation::OrphanEntry {
+            entry_id: entry.id,
+            transaction_id: entry.transaction_id,
+            account_id: entry.account_id,
+        });
+    }
+
+    Ok(IntegrityReport {
+        started_at,
+        finished_at: chrono::Utc::now(),
+        accounts_checked,
+        entries_checked,
+        transactions_checked,
+        violations,
+    })
+}
+
+pub fn check_transaction(totals: &TransactionLegTotals) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if totals.entry_count == 0 {
+        violations.push(Violation::TransactionWithoutEntries {
+            transaction_id: totals.transaction_id,
+        });
+        return violations;
+    }
+
+    // Credits and debits move money into or out of the ledger with a single
+    // leg, and so do reversals of them
+    let one_sided = matches!(
+        totals.transaction_type,
+        TransactionType::Credit | TransactionType::Debit | TransactionType::Reversal
+    ) && (totals.debit_total.is_zero() || totals.credit_total.is_zero());
+    if !one_sided && totals.debit_total != totals.credit_total {
+        violations.push(Violation::UnbalancedTransaction {
+            transaction_id: totals.transaction_id,
+            debit_total: totals.debit_total,
+            credit_total: totals.credit_total,
+        });
+    }
+
+    // Each side that has legs must add up to the transaction amount
+    let debit_ok = totals.debit_total.is_zero() || totals.debit_total == totals.amount;
+    let credit_ok = totals.credit_total.is_zero() || totals.credit_total == totals.amount;
+    if !debit_ok || !credit_ok {
+        violations.push(Violation::AmountMismatch {
+            transaction_id: totals.transaction_id,
+            amount: totals.amount,
+            debit_total: totals.debit_total,
+            credit_total: totals.credit_total,
+        });
+    }
+
+    violations
+}
+```
@@ -0,0 +1,302 @@
+ledger/src/journal_export.rs
+```rust
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{Account, AccountType, Entry, LedgerError, LedgerStore, Transaction};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalFormat {
+    Ledger,
+    Hledger,
+    Beancount,
+}
+
+impl std::str::FromStr for JournalFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "ledger" | "ledger-cli" => Ok(JournalFormat::Ledger),
+            "hledger" => Ok(JournalFormat::Hledger),
+            "beancount" => Ok(JournalFormat::Beancount),
+            other => Err(format!("unknown journal format: {}", other)),
+        }
+    }
+}
+
+// Credit-only and debit-only postings have a single entry. Plain-text
+// tools insist that every transaction balances, so the remainder is posted
+// to this account and the file stays loadable.
+pub const SUSPENSE_ACCOUNT: &str = "Equity:Suspense";
+
+pub async fn export_journal(
+    store: &(impl LedgerStore + ?Sized),
+    format: JournalFormat,
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+) -> Result<String, LedgerError> {
+    let accounts = store.list_accounts().await?;
+    let transactions = store.get_transactions_with_entries(from, to).await?;
+    Ok(render_journal(format, &accounts, &transactions))
+}
+
+// Top-level names every tool accepts; beancount allows only these five
+fn root_name(account_type: AccountType) -> &'static str {
+    match account_type {
+        AccountType::Asset => "Assets",
+        AccountType::Liability => "Liabilities",
+        AccountType::Equity => "Equity",
+        AccountType::Revenue => "Income",
+        AccountType::Expense => "Expenses",
+    }
+}
+
+// Account name components may only hold letters, digits and dashes and must
+// start with an upper-case letter or a digit to satisfy beancount, which is
+// the strictest of the three
+fn name_component(value: &str) -> String {
+    let mut component = String::new();
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            component.push(c);
+        } else if !component.ends_with('-') && !component.is_empty() {
+            component.push('-');
+        }
+    }
+    let component = component.trim_end_matches('-');
+    let mut chars = component.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_digit() || first.is_ascii_uppercase() => component.to_string(),
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => "X".to_string(),
+    }
+}
+
+fn account_component(account: &Account) -> String {
+    match (account.code(), account.name()) {
+        (Some(code), Some(name)) => name_component(&format!("{} {}", code, name)),
+        (Some(code), None) => name_component(code),
+        (None, Some(name)) => name_component(name),
+        (None, None) => account.id.to_string().to_ascii_uppercase(),
+    }
+}
+
+// Maps account ids to colon-separated names: the AccountType root, then the
+// chart-of-accounts path through parent_code, e.g.
+// "Assets:1000-Current-Assets:1100-Cash". Accounts that would share a name
+// get the start of their id appended, e.g. "Assets:Primary-Wallet-1F0C2A9B",
+// so every account keeps its own postings.
+pub fn journal_account_names(accounts: &[Account]) -> HashMap<Uuid, String> {
+    let by_code: HashMap<&str, &Account> = accounts
+        .iter()
+        .filter_map(|account| account.code().map(|code| (code, account)))
+        .collect();
+
+    let mut names: HashMap<Uuid, String> = accounts
+        .iter()
+        .map(|account| {
+            let mut path = vec![account_component(account)];
+            let mut seen: HashSet<&str> = account.code().into_iter().collect();
+            let mut parent_code = account.parent_code();
+            while let Some(code) = parent_code {
+                if !seen.insert(code) {
+                    break;
+                }
+                match by_code.get(code) {
+                    Some(parent) => {
+                        path.push(account_component(parent));
+                        parent_code = parent.parent_code();
+                    }
+                    None => {
+                        path.push(name_component(code));
+                        parent_code = None;
+                    }
+                }
+            }
+            path.push(root_name(account.account_type).to_string());
+            path.reverse();
+            (account.id, path.join(":"))
+        })
+        .collect();
+
+    let mut uses: HashMap<&str, usize> = HashMap::new();
+    for name in names.values() {
+        *uses.entry(name.as_str()).or_default() += 1;
+    }
+    let shared: HashSet<String> = uses
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    for (id, name) in names.iter_mut() {
+        if shared.contains(name.as_str()) {
+            let suffix = id.simple().to_string()[..8].to_ascii_uppercase();
+            name.push('-');
+            name.push_str(&suffix);
+        }
+    }
+
+    names
+}
+
+pub fn render_journal(
+    format: JournalFormat,
+    accounts: &[Account],
+    transactions: &[(Transaction, Vec<Entry>)],
+) -> String {
+    let names = journal_account_names(accounts);
+    let currencies: HashMap<Uuid, &str> = accounts
+        .iter()
+        .map(|account| (account.id, account.currency.as_str()))
+        .collect();
+    let mut journal = String::new();
+
+    let mut declared: Vec<&Account> = accounts.iter().collect();
+    declared.sort_by(|a, b| names[&a.id].cmp(&names[&b.id]));
+    for account in &declared {
+        match format {
+            JournalFormat::Beancount => writeln!(
+                journal,
+                "{} open {} {}",
+                account.created_at.format("%Y-%m-%d"),
+                names[&account.id],
+                account.currency
+            ),
+            JournalFormat::Ledger | JournalFormat::Hledger => {
+                writeln!(journal, "account {}", names[&account.id])
+            }
+        }
+        .expect("writing to a String cannot fail");
+    }
+    if format == JournalFormat::Beancount {
+        let opened = accounts
+            .iter()
+            .map(|account| account.created_at)
+            .chain(transactions.iter().map(|(transaction, _)| transaction.timestamp))
+            .min();
+        if let Some(opened) = opened {
+            writeln!(journal, "{} open {}", opened.format("%Y-%m-%d"), SUSPENSE_ACCOUNT)
+                .expect("writing to a String cannot fail");
+        }
+    } else {
+        writeln!(journal, "account {}", SUSPENSE_ACCOUNT).expect("writing to a String cannot fail");
+    }
+
+    let mut ordered: Vec<&(Transaction, Vec<Entry>)> = transactions.iter().collect();
+    ordered.sort_by_key(|(transaction, _)| (transaction.timestamp, transaction.id));
+    for (transaction, entries) in ordered {
+        journal.push('\n');
+        render_transaction(&mut journal, format, transaction, entries, &names, &currencies);
+    }
+
+    journal
+}
+
+fn render_transaction(
+    journal: &mut String,
+    format: JournalFormat,
+    transaction: &Transaction,
+    entries: &[Entry],
+    names: &HashMap<Uuid, String>,
+    currencies: &HashMap<Uuid, &str>,
+) {
+    let date = transaction.timestamp.format("%Y-%m-%d");
+    let mut tags = vec![
+        ("transaction_id".to_string(), transaction.id.to_string()),
+        ("transaction_type".to_string(), transaction.transaction_type.as_str().to_string()),
+        ("reason_code".to_string(), transaction.reason_code.clone()),
+        ("idempotency_key".to_string(), transaction.idempotency_key.clone()),
+    ];
+    if let Some(metadata) = transaction.metadata.as_object() {
+        for (key, value) in metadata {
+            let value = match value {
+                serde_json::Value::String(value) => value.clone(),
+                other => other.to_string(),
+            };
+            tags.push((key.clone(), value));
+        }
+    }
+    // Comments and metadata end at the line break
+    for (_, value) in tags.iter_mut() {
+        if value.contains(['\n', '\r']) {
+            *value = value.replace(['\n', '\r'], " ");
+        }
+    }
+
+    let result = match format {
+        JournalFormat::Ledger | JournalFormat::Hledger => {
+            writeln!(journal, "{} * ({}) {}", date, transaction.id, transaction.reason_code).and_then(|_| {
+                tags.iter().try_for_each(|(key, value)| {
+                    // hledger ends a tag value at the first comma
+                    let value = if format == JournalFormat::Hledger {
+                        value.replace(',', ";")
+                    } else {
+                        value.clone()
+                    };
+                    writeln!(journal, "    ; {}: {}", tag_key(key), value)
+                })
+            })
+        }
+        JournalFormat::Beancount => writeln!(
+            journal,
+            "{} * \"{}\" \"\"",
+            date,
+            beancount_string(&transaction.reason_code)
+        )
+        .and_then(|_| {
+            tags.iter().try_for_each(|(key, value)| {
+                writeln!(journal, "  {}: \"{}\"", beancount_meta_key(key), beancount_string(value))
+            })
+        }),
+    };
+    result.expect("writing to a String cannot fail");
+
+    let indent = if format == JournalFormat::Beancount { "  " } else { "    " };
+    let mut residual: BTreeMap<&str, Decimal> = BTreeMap::new();
+    for entry in entries {
+        let currency = currencies.get(&entry.account_id).copied().unwrap_or("XXX");
+        let name = names
+            .get(&entry.account_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Equity:Unknown:{}", entry.account_id.to_string().to_ascii_uppercase()));
+        // Plain-text journals count debits as positive
+        let amount = (-entry.signed_amount()).normalize();
+        *residual.entry(currency).or_default() += amount;
+        writeln!(journal, "{}{}  {} {}", indent, name, amount, currency)
+            .expect("writing to a String cannot fail");
+    }
+    for (currency, amount) in residual {
+        if !amount.is_zero() {
+            writeln!(journal, "{}{}  {} {}", indent, SUSPENSE_ACCOUNT, -amount, currency)
+                .expect("writing to a String cannot fail");
+        }
+    }
+}
+
+// Tag names end at whitespace or a colon
+fn tag_key(key: &str) -> String {
+    key.chars()
+        .map(|c| if c.is_whitespace() || c == ':' || c == ',' { '_' } else { c })
+        .collect()
+}
+
+fn beancount_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Metadata keys must start with a lower-case letter and hold only
+// letters, digits, dashes and underscores
+fn beancount_meta_key(key: &str) -> String {
+    let key: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    match key.chars().next() {
+        Some(first) if first.is_ascii_lowercase() => key,
+        _ => format!("m{}", key),
+    }
+}
+```
@@ -0,0 +1,686 @@
+ledger/src/journal_import.rs
+```rust
+use std::collections::{hash_map, BTreeMap, HashMap, HashSet};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::{
+    journal_account_names, Account, AccountType, BatchItemStatus, BatchMode, Entry, EntryType,
+    ImportRowResult, ImportRowStatus, JournalFormat, LedgerError, LedgerStore, RowError,
+    Transaction, TransactionType,
+};
+
+// Reason code for imported transactions that do not carry one in their
+// metadata, as journals written by export_journal do
+pub const JOURNAL_IMPORT_REASON_CODE: &str = "journal_import";
+
+// Longest reason code the transactions table accepts
+const MAX_REASON_CODE_LEN: usize = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalPosting {
+    pub line: u64,
+    pub account: String,
+    // None when the amount is elided and inferred from the other postings
+    pub amount: Option<Decimal>,
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct JournalTransaction {
+    // Line of the transaction header
+    pub line: u64,
+    pub date: chrono::NaiveDate,
+    pub description: String,
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+    pub postings: Vec<JournalPosting>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ParsedJournal {
+    // Accounts declared by open (beancount) or account (ledger-cli)
+    // directives, with the currency given there if any
+    pub accounts: BTreeMap<String, Option<String>>,
+    pub transactions: Vec<Result<JournalTransaction, (u64, Vec<RowError>)>>,
+}
+
+// What the parser is in the middle of
+enum Block {
+    None,
+    Transaction(JournalTransaction, Vec<RowError>),
+    // Automated or periodic ledger-cli transactions, which are not imported
+    Skipped,
+}
+
+// Reads transactions and account declarations. Directives that do not
+// affect postings (prices, balance assertions, options, includes) are
+// skipped; postings that need them, such as lot costs, are rejected.
+pub fn parse_journal(format: JournalFormat, text: &str) -> ParsedJournal {
+    let mut journal = ParsedJournal::default();
+    let mut block = Block::None;
+
+    for (index, raw_line) in text.lines().enumerate() {
+        let line = index as u64 + 1;
+        let is_indented = raw_line.starts_with([' ', '\t']);
+        let content = raw_line.trim();
+
+        if !is_indented || content.is_empty() {
+            finish_block(&mut journal, std::mem::replace(&mut block, Block::None));
+            if content.is_empty() || content.starts_with([';', '#', '%', '|', '*']) {
+                continue;
+            }
+            block = parse_header(format, line, content, &mut journal);
+            continue;
+        }
+
+        let Block::Transaction(transaction, errors) = &mut block else {
+            continue;
+        };
+        if let Some(comment) = content.strip_prefix(';') {
+            // ledger-cli metadata lives in comments as "key: value"
+            if let Some((key, value)) = metadata_pair(comment.trim()) {
+                transaction.metadata.insert(key, value.into());
+            }
+            continue;
+        }
+        if format == JournalFormat::Beancount && content.starts_with(|c: char| c.is_ascii_lowercase()) {
+            if let Some((key, value)) = metadata_pair(content) {
+                transaction.metadata.insert(key, unquote(&value).into());
+            }
+            continue;
+        }
+        match parse_posting(format, line, content) {
+            Ok(posting) => transaction.postings.push(posting),
+            Err(error) => errors.push(error),
+        }
+    }
+    finish_block(&mut journal, block);
+
+    journal
+}
+
+fn parse_header(format: JournalFormat, line: u64, content: &str, journal: &mut ParsedJournal) -> Block {
+    if format != JournalFormat::Beancount {
+        if let Some(account) = content.strip_prefix("account ") {
+            let account = strip_comment(account).trim();
+            journal.accounts.entry(account.to_string()).or_insert(None);
+            return Block::None;
+        }
+        if content.starts_with(['=', '~']) {
+            return Block::Skipped;
+        }
+    }
+    if !content.starts_with(|c: char| c.is_ascii_digit()) {
+        return Block::None;
+    }
+
+    let (date_text, rest) = content.split_once(char::is_whitespace).unwrap_or((content, ""));
+    // ledger-cli allows an auxiliary date after "="
+    let date_text = date_text.split('=').next().unwrap_or(date_text);
+    let Some(date) = parse_date(date_text) else {
+        let error = RowError::row(format!("invalid date: {}", date_text));
+        return Block::Transaction(empty_transaction(line), vec![error]);
+    };
+    let rest = rest.trim();
+
+    if format == JournalFormat::Beancount {
+        let mut tokens = rest.split_whitespace();
+        match tokens.next() {
+            Some("*" | "!" | "txn") => {}
+            Some("open") => {
+                if let Some(account) = tokens.next() {
+                    let currency = tokens.next().and_then(|c| c.split(',').next()).map(str::to_string);
+                    journal.accounts.insert(account.to_string(), currency);
+                }
+                return Block::None;
+            }
+            // close, balance, pad, price, note, document, event, ...
+            _ => return Block::None,
+        }
+        let strings: Vec<String> = quoted_strings(rest);
+        let description = strings.iter().filter(|s| !s.is_empty()).cloned().collect::<Vec<_>>().join(" ");
+        let mut transaction = empty_transaction(line);
+        transaction.date = date;
+        transaction.description = description;
+        return Block::Transaction(transaction, Vec::new());
+    }
+
+    let mut description = strip_comment(rest).trim();
+    description = description.trim_start_matches(['*', '!']).trim_start();
+    if description.starts_with('(') {
+        if let Some(end) = description.find(')') {
+            description = description[end + 1..].trim_start();
+        }
+    }
+    let mut transaction = empty_transaction(line);
+    transaction.date = date;
+    transaction.description = description.to_string();
+    if let Some((_, comment)) = rest.split_once(';') {
+        if let Some((key, value)) = metadata_pair(comment.trim()) {
+            transaction.metadata.insert(key, value.into());
+        }
+    }
+    Block::Transaction(transaction, Vec::new())
+}
+
+fn finish_block(journal: &mut ParsedJournal, block: Block) {
+    let Block::Transaction(mut transaction, mut errors) = block else {
+        return;
+    };
+    if errors.is_empty() {
+        if let Err(error) = balance_postings(&mut transaction.postings) {
+            errors.push(error);
+        }
+    }
+    journal.transactions.push(if errors.is_empty() {
+        Ok(transaction)
+    } else {
+        Err((transaction.line, errors))
+    });
+}
+
+fn empty_transaction(line: u64) -> JournalTransaction {
+    JournalTransaction {
+        line,
+        date: chrono::NaiveDate::default(),
+        description: String::new(),
+        metadata: serde_json::Map::new(),
+        postings: Vec::new(),
+    }
+}
+
+// Fills in an elided amount and checks that every currency nets to zero
+fn balance_postings(postings: &mut [JournalPosting]) -> Result<(), RowError> {
+    if postings.is_empty() {
+        return Err(RowError::row("transaction has no postings"));
+    }
+
+    let mut residual: BTreeMap<Option<String>, Decimal> = BTreeMap::new();
+    let mut elided = None;
+    for (index, posting) in postings.iter().enumerate() {
+        match posting.amount {
+            Some(amount) => *residual.entry(posting.currency.clone()).or_default() += amount,
+            None if elided.is_none() => elided = Some(index),
+            None => return Err(RowError::row("only one posting may omit its amount")),
+        }
+    }
+    residual.retain(|_, amount| !amount.is_zero());
+
+    if let Some(index) = elided {
+        let mut open = residual.into_iter();
+        match (open.next(), open.next()) {
+            (Some((currency, amount)), None) => {
+                postings[index].amount = Some(-amount);
+                postings[index].currency = currency;
+            }
+            (None, _) => postings[index].amount = Some(Decimal::ZERO),
+            (Some(_), Some(_)) => {
+                return Err(RowError::row("cannot infer an elided amount across several currencies"));
+            }
+        }
+        return Ok(());
+    }
+
+    match residual.into_iter().next() {
+        None => Ok(()),
+        Some((currency, amount)) => Err(RowError::row(format!(
+            "transaction does not balance: off by {} {}",
+            amount,
+            currency.unwrap_or_default()
+        ))),
+    }
+}
+
+fn parse_posting(format: JournalFormat, line: u64, content: &str) -> Result<JournalPosting, RowError> {
+    let content = strip_comment(content).trim();
+    let content = content.trim_start_matches(['*', '!']).trim_start();
+    if content.starts_with(['(', '[']) {
+        return Err(RowError::field("account", "virtual postings are not supported"));
+    }
+
+    let (account, amount_text) = if format == JournalFormat::Beancount {
+        content.split_once(char::is_whitespace).unwrap_or((content, ""))
+    } else {
+        // Account names may contain single spaces; two spaces or a tab
+        // separate the amount
+        match (content.find("  "), content.find('\t')) {
+            (Some(a), Some(b)) => content.split_at(a.min(b)),
+            (Some(a), None) | (None, Some(a)) => content.split_at(a),
+            (None, None) => (content, ""),
+        }
+    };
+
+    let mut amount_text = amount_text.trim();
+    if amount_text.contains(['@', '{']) {
+        return Err(RowError::field("amount", "prices and lot costs are not supported"));
+    }
+    // A trailing ledger-cli balance assertion does not change the posting
+    if let Some((amount, _)) = amount_text.split_once('=') {
+        amount_text = amount.trim();
+    }
+
+    let (amount, currency) = if amount_text.is_empty() {
+        (None, None)
+    } else {
+        let (amount, currency) = parse_amount(amount_text)
+            .ok_or_else(|| RowError::field("amount", format!("invalid amount: {}", amount_text)))?;
+        (Some(amount), currency)
+    };
+
+    Ok(JournalPosting {
+        line,
+        account: account.trim().to_string(),
+        amount,
+        currency,
+    })
+}
+
+// "100.00 USD", "USD 100.00", "$1,000.00", "-$5" and "$-5"
+fn parse_amount(text: &str) -> Option<(Decimal, Option<String>)> {
+    let number = |value: &str| value.replace(',', "").parse::<Decimal>().ok();
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        [value, commodity] | [commodity, value] if number(value).is_some() => {
+            Some((number(value)?, Some(currency_code(commodity))))
+        }
+        [value] => {
+            if let Some(amount) = number(value) {
+                return Some((amount, None));
+            }
+            let (negative, rest) = match value.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, *value),
+            };
+            let split = rest.find(|c: char| c.is_ascii_digit() || c == '-')?;
+            let (symbol, digits) = rest.split_at(split);
+            if symbol.is_empty() {
+                let split = digits.find(|c: char| c.is_alphabetic())?;
+                let (digits, commodity) = digits.split_at(split);
+                let amount = number(digits)?;
+                return Some((if negative { -amount } else { amount }, Some(currency_code(commodity))));
+            }
+            let amount = number(digits)?;
+            Some((if negative { -amount } else { amount }, Some(currency_code(symbol))))
+        }
+        _ => None,
+    }
+}
+
+fn currency_code(commodity: &str) -> String {
+    match commodity.trim_matches('"') {
+        "$" => "USD".to_string(),
+        "€" => "EUR".to_string(),
+        "£" => "GBP".to_string(),
+        "¥" => "JPY".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_date(text: &str) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d")
+        .or_else(|_| chrono::NaiveDate::parse_from_str(text, "%Y/%m/%d"))
+        .ok()
+}
+
+fn strip_comment(text: &str) -> &str {
+    text.split_once(';').map(|(before, _)| before).unwrap_or(text)
+}
+
+// "key: value" with a key that is a single word
+fn metadata_pair(text: &str) -> Option<(String, String)> {
+    let (key, value) = text.split_once(':')?;
+    let key = key.trim();
+    if key.is_empty() || key.contains(char::is_whitespace) || value.trim().is_empty() {
+        return None;
+    }
+    Some((key.to_string(), value.trim().to_string()))
+}
+
+fn unquote(value: &str) -> String {
+    match value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner) => inner.replace("\\\"", "\"").replace("\\\\", "\\"),
+        None => value.to_string(),
+    }
+}
+
+fn quoted_strings(text: &str) -> Vec<String> {
+    let mut strings = Vec::new();
+    let mut current: Option<String> = None;
+    let mut escaped = false;
+    for c in text.chars() {
+        match (&mut current, c) {
+            (Some(string), _) if escaped => {
+                string.push(c);
+                escaped = false;
+            }
+            (Some(_), '\\') => escaped = true,
+            (Some(_), '"') => strings.push(current.take().unwrap_or_default()),
+            (Some(string), _) => string.push(c),
+            (None, '"') => current = Some(String::new()),
+            (None, _) => {}
+        }
+    }
+    strings
+}
+
+// Idempotency key of the transaction starting at `line` of `source`.
+// Appending to a journal keeps the keys of earlier transactions, so the
+// same file can be imported again as it grows.
+pub fn journal_idempotency_key(source: &str, line: u64) -> String {
+    let digest = Sha256::digest(format!("{}:{}", source, line).as_bytes());
+    format!("journal:{}", hex::encode(digest))
+}
+
+fn account_type_for(name: &str) -> Option<AccountType> {
+    match name.split(':').next()? {
+        "Assets" | "Asset" => Some(AccountType::Asset),
+        "Liabilities" | "Liability" => Some(AccountType::Liability),
+        "Equity" => Some(AccountType::Equity),
+        "Income" | "Revenue" | "Revenues" => Some(AccountType::Revenue),
+        "Expenses" | "Expense" => Some(AccountType::Expense),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JournalImportOptions {
+    // Identifies the file in idempotency keys, e.g. its name. Use the same
+    // value every time the same journal is imported.
+    pub source: String,
+    // Validate and report only; no accounts or transactions are created
+    pub dry_run: bool,
+    pub mode: BatchMode,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JournalImportReport {
+    pub dry_run: bool,
+    // False when no transaction was written
+    pub posted: bool,
+    // Accounts the journal needed that did not exist; on a dry run, the
+    // accounts that would be created
+    pub accounts_created: Vec<Account>,
+    pub transactions: Vec<ImportRowResult>,
+}
+
+impl JournalImportReport {
+    pub fn has_errors(&self) -> bool {
+        self.transactions
+            .iter()
+            .any(|row| matches!(row.status, ImportRowStatus::Rejected { .. }))
+    }
+}
+
+// Journal account names resolved to ledger accounts, creating entries for
+// the ones that do not exist yet
+struct AccountResolver {
+    by_name: HashMap<String, Account>,
+    // Names more than one existing account answers to
+    ambiguous: HashSet<String>,
+    declared_currencies: BTreeMap<String, Option<String>>,
+    created: Vec<Uuid>,
+}
+
+impl AccountResolver {
+    fn new(existing: Vec<Account>, declared_currencies: BTreeMap<String, Option<String>>) -> Self {
+        // Accounts previously imported are found by the name they were
+        // imported under, everything else by the name export_journal gives it
+        let exported = journal_account_names(&existing);
+        let mut by_name = HashMap::new();
+        let mut ambiguous = HashSet::new();
+        for account in existing {
+            let name = account
+                .metadata
+                .get("journal_account")
+                .and_then(|name| name.as_str())
+                .map(str::to_string)
+                .unwrap_or_else(|| exported[&account.id].clone());
+            match by_name.entry(name) {
+                hash_map::Entry::Occupied(occupied) => {
+                    ambiguous.insert(occupied.key().clone());
+                }
+                hash_map::Entry::Vacant(vacant) => {
+                    vacant.insert(account);
+                }
+            }
+        }
+        Self {
+            by_name,
+            ambiguous,
+            declared_currencies,
+            created: Vec::new(),
+        }
+    }
+
+    fn resolve(&mut self, name: &str, posting_currency: Option<&str>) -> Result<&Account, RowError> {
+        if self.ambiguous.contains(name) {
+            return Err(RowError::field(
+                "account",
+                format!("{}: more than one existing account has this name", name),
+            ));
+        }
+        if !self.by_name.contains_key(name) {
+            let account_type = account_type_for(name).ok_or_else(|| {
+                RowError::field(
+                    "account",
+                    format!("{}: top-level account must be Assets, Liabilities, Equity, Income or Expenses", name),
+                )
+            })?;
+            let currency = self
+                .declared_currencies
+                .get(name)
+                .cloned()
+                .flatten()
+                .or_else(|| posting_currency.map(str::to_string))
+                .ok_or_else(|| RowError::field("account", format!("{}: currency is unknown", name)))?;
+            if currency.len() != 3 {
+                return Err(RowError::field(
+                    "account",
+                    format!("{}: commodity {} is not a three-letter currency code", name, currency),
+                ));
+            }
+
+            let mut account = Account::new(account_type, &currency);
+            account.metadata = serde_json::json!({
+                "journal_account": name,
+                "name": name.rsplit(':').next().unwrap_or(name),
+            });
+            self.created.push(account.id);
+            self.by_name.insert(name.to_string(), account);
+        }
+        Ok(&self.by_name[name])
+    }
+
+    fn created(&self) -> Vec<Account> {
+        self.by_name
+            .values()
+            .filter(|account| self.created.contains(&account.id))
+            .cloned()
+            .collect()
+    }
+}
+
+// Parses a journal, creates the accounts it uses that do not exist yet and
+// posts every transaction as one balanced multi-leg Adjustment through
+// record_batch. Each transaction's idempotency key is derived from the
+// source and its line, so importing the same file again posts nothing twice.
+pub async fn import_journal(
+    store: &(impl LedgerStore + ?Sized),
+    format: JournalFormat,
+    text: &str,
+    options: &JournalImportOptions,
+) -> Result<JournalImportReport, LedgerError> {
+    let parsed = parse_journal(format, text);
+    let mut accounts = AccountResolver::new(store.list_accounts().await?, parsed.accounts.clone());
+    for (name, currency) in &parsed.accounts {
+        // Declared accounts are created even when unused; ones that cannot
+        // be are reported by the transactions that post to them
+        let _ = accounts.resolve(name, currency.as_deref());
+    }
+
+    let mut rows = Vec::with_capacity(parsed.transactions.len());
+    let mut items = Vec::new();
+
+    for parsed_transaction in parsed.transactions {
+        let journal_transaction = match parsed_transaction {
+            Ok(journal_transaction) => journal_transaction,
+            Err((line, errors)) => {
+                rows.push(ImportRowResult {
+                    line,
+                    idempotency_key: Some(journal_idempotency_key(&options.source, line)),
+                    status: ImportRowStatus::Rejected { errors },
+                });
+                continue;
+            }
+        };
+        let key = journal_idempotency_key(&options.source, journal_transaction.line);
+
+        let mut errors = Vec::new();
+        let mut legs = Vec::new();
+        for posting in &journal_transaction.postings {
+            let amount = posting.amount.unwrap_or_default();
+            if amount.is_zero() {
+                continue;
+            }
+            match accounts.resolve(&posting.account, posting.currency.as_deref()) {
+                Ok(account) if posting.currency.as_deref().is_some_and(|c| c != account.currency) => {
+                    errors.push(RowError::field(
+                        "amount",
+                        format!(
+                            "line {}: {} is held in {}, not {}",
+                            posting.line,
+                            posting.account,
+                            account.currency,
+                            posting.currency.as_deref().unwrap_or_default()
+                        ),
+                    ));
+                }
+                Ok(account) => legs.push((account.id, amount)),
+                Err(error) => errors.push(error),
+            }
+        }
+
+        let status = if !errors.is_empty() {
+            ImportRowStatus::Rejected { errors }
+        } else {
+            match build_posting(&journal_transaction, &legs, &key, &options.source) {
+                Err(error) => ImportRowStatus::Rejected { errors: vec![error] },
+                Ok((transaction, entries)) => match store.get_transaction_by_key(&key).await? {
+                    Some(existing) => ImportRowStatus::Duplicate {
+                        existing_transaction_id: existing.id,
+                    },
+                    None => {
+                        items.push((rows.len(), transaction, entries));
+                        ImportRowStatus::Valid
+                    }
+                },
+            }
+        };
+        rows.push(ImportRowResult {
+            line: journal_transaction.line,
+            idempotency_key: Some(key),
+            status,
+        });
+    }
+
+    let accounts_created = accounts.created();
+    let has_errors = rows
+        .iter()
+        .any(|row| matches!(row.status, ImportRowStatus::Rejected { .. }));
+    if options.dry_run || (has_errors && options.mode == BatchMode::AllOrNothing) {
+        return Ok(JournalImportReport {
+            dry_run: options.dry_run,
+            posted: false,
+            accounts_created: if options.dry_run { accounts_created } else { Vec::new() },
+            transactions: rows,
+        });
+    }
+
+    if items.is_empty() && accounts_created.is_empty() {
+        return Ok(JournalImportReport {
+            dry_run: false,
+            posted: false,
+            accounts_created,
+            transactions: rows,
+        });
+    }
+
+    // New accounts are created with the postings, so an all-or-nothing
+    // import that fails leaves no accounts behind
+    let (positions, batch): (Vec<usize>, Vec<(Transaction, Vec<Entry>)>) = items
+        .into_iter()
+        .map(|(position, transaction, entries)| (position, (transaction, entries)))
+        .unzip();
+    let outcomes = store
+        .record_batch_with_accounts(&accounts_created, &batch, options.mode)
+        .await?;
+    for (position, outcome) in positions.into_iter().zip(outcomes) {
+        rows[position].status = match outcome.status {
+            BatchItemStatus::Recorded => ImportRowStatus::Recorded {
+                transaction_id: outcome.transaction_id,
+            },
+            BatchItemStatus::Duplicate { existing_transaction_id } => {
+                ImportRowStatus::Duplicate { existing_transaction_id }
+            }
+            BatchItemStatus::Rejected { reason } => ImportRowStatus::Rejected {
+                errors: vec![RowError::row(reason)],
+            },
+        };
+    }
+
+    Ok(JournalImportReport {
+        dry_run: false,
+        posted: !batch.is_empty(),
+        accounts_created,
+        transactions: rows,
+    })
+}
+
+fn build_posting(
+    journal_transaction: &JournalTransaction,
+    legs: &[(Uuid, Decimal)],
+    key: &str,
+    source: &str,
+) -> Result<(Transaction, Vec<Entry>), RowError> {
+    let total: Decimal = legs.iter().map(|(_, amount)| *amount).filter(|a| a.is_sign_positive()).sum();
+    if total.is_zero() {
+        return Err(RowError::row("transaction moves no money"));
+    }
+
+    let mut metadata = journal_transaction.metadata.clone();
+    let reason_code = metadata
+        .remove("reason_code")
+        .and_then(|code| code.as_str().map(str::to_string))
+        .filter(|code| !code.is_empty() && code.len() <= MAX_REASON_CODE_LEN)
+        .unwrap_or_else(|| JOURNAL_IMPORT_REASON_CODE.to_string());
+    if !journal_transaction.description.is_empty() {
+        metadata.insert("description".to_string(), journal_transaction.description.clone().into());
+    }
+    metadata.insert("journal_source".to_string(), source.into());
+    metadata.insert("journal_line".to_string(), journal_transaction.line.into());
+    // Posted now rather than on the journal date, so balances, checkpoints
+    // and published roots of earlier periods are left as they were. A
+    // journal written by export_journal already carries the original date.
+    metadata
+        .entry("effective_date")
+        .or_insert_with(|| journal_transaction.date.to_string().into());
+
+    let first_credit = legs.iter().find(|(_, amount)| amount.is_sign_negative()).map(|(id, _)| *id);
+    let first_debit = legs.iter().find(|(_, amount)| amount.is_sign_positive()).map(|(id, _)| *id);
+    let mut transaction = Transaction::new(TransactionType::Adjustment, total, first_credit, first_debit, &reason_code, key);
+    transaction.metadata = serde_json::Value::Object(metadata);
+
+    // balance_after is filled in by the store when the batch is written
+    let mut entries = Vec::with_capacity(legs.len());
+    for (account_id, amount) in legs {
+        let entry_type = if amount.is_sign_positive() { EntryType::Debit } else { EntryType::Credit };
+        let mut entry = Entry::new(transaction.id, *account_id, amount.abs(), entry_type, Decimal::ZERO);
+        entry.timestamp = transaction.timestamp;
+        entries.push(entry);
+    }
+
+    Ok((transaction, entries))
+}
+```
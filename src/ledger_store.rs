@@ -1,33 +1,218 @@
 ledger/src/ledger_store.rs
 ```rust
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 use async_trait::async_trait;
 use rust_decimal::Decimal;
 use sqlx::{PgPool, Postgres, Transaction};
 use uuid::Uuid;
-use crate::{account::Account, transaction::{Transaction, TransactionError}, entry::Entry};
+use crate::{
+    account::Account,
+    transaction::{Transaction, TransactionError, TransactionType},
+    entry::{AccountEntry, Entry, EntryType, TransactionSummary},
+    reconciliation::AccountTotals,
+    integrity::TransactionLegTotals,
+    hash_chain::{self, ChainAnchor, ChainLink},
+    merkle::PublishedRoot,
+    signing::{SigningError, TransactionSignature, TransactionSigner},
+    events::{EventRecord, LedgerEvent},
+    webhooks::{DeliveryStatus, WebhookAttempt, WebhookDelivery, WebhookSubscription},
+    subscriptions::{AccountUpdate, AccountUpdateBroadcaster, AccountUpdateStream},
+    batch::{validate_batch_item, BatchItemOutcome, BatchItemStatus, BatchMode},
+    pagination::{Cursor, EntryFilter, Page, SortOrder, TransactionFilter},
+};
 
 #[async_trait]
 pub trait LedgerStore: Send + Sync {
     async fn create_account(&self, account: &Account) -> Result<(), LedgerError>;
     async fn get_account(&self, account_id: &Uuid) -> Result<Option<Account>, LedgerError>;
+    async fn list_accounts(&self) -> Result<Vec<Account>, LedgerError>;
     async fn get_account_balance(&self, account_id: &Uuid) -> Result<Decimal, LedgerError>;
+    // Current balances of several accounts in one query. Accounts with no
+    // postings are omitted.
+    async fn get_account_balances(&self, account_ids: &[Uuid]) -> Result<HashMap<Uuid, Decimal>, LedgerError>;
+    async fn get_account_balance_shards(&self, account_id: &Uuid) -> Result<i32, LedgerError>;
+    // Spreads the account's future postings over `shards` balance rows; 1
+    // turns sharding off. Rows of shards beyond the new count keep their
+    // balance, so each shard's entries keep a continuous running balance.
+    // The account id and its balance are unaffected.
+    async fn set_account_balance_shards(&self, account_id: &Uuid, shards: i32) -> Result<(), LedgerError>;
+    async fn get_account_balance_at(
+        &self,
+        account_id: &Uuid,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Decimal, LedgerError>;
+    // Balance of each balance shard the account has posted to, as of
+    // `as_of`. Summed, they give get_account_balance_at.
+    async fn get_account_shard_balances_at(
+        &self,
+        account_id: &Uuid,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<HashMap<i32, Decimal>, LedgerError>;
+    async fn create_balance_checkpoints(
+        &self,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, LedgerError>;
+    // Entries' balance_after and balance_shard are computed under the
+    // balance row locks and replace whatever the caller set. An entry's
+    // timestamp may move forward by microseconds so that it sorts after the
+    // entries already applied to its balance shard.
     async fn record_transaction(
         &self,
         transaction: &Transaction,
         entries: &[Entry],
     ) -> Result<(), LedgerError>;
+    // Like record_transaction, but fails with InsufficientBalance and writes
+    // nothing if an account the transaction takes money from would end up
+    // below zero. The check is made under the balance row locks, so
+    // concurrent postings cannot overdraw the account between check and write.
+    async fn record_transaction_checked(
+        &self,
+        transaction: &Transaction,
+        entries: &[Entry],
+    ) -> Result<(), LedgerError>;
+    // Writes many transactions with multi-row statements. Outcomes are
+    // returned in input order, one per item.
+    async fn record_batch(
+        &self,
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError>;
+    // Like record_batch, but rejects every item that would take an account
+    // below zero, checked in batch order as record_transaction_checked does
+    async fn record_batch_checked(
+        &self,
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError>;
+    // Like record_batch, but first creates `accounts`, which the items may
+    // post to. With AllOrNothing the accounts are created in the same
+    // database transaction, so a rejected batch leaves none of them behind.
+    async fn record_batch_with_accounts(
+        &self,
+        accounts: &[Account],
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError>;
     async fn get_transaction(&self, transaction_id: &Uuid) -> Result<Option<Transaction>, LedgerError>;
     async fn get_transaction_by_key(&self, idempotency_key: &str) -> Result<Option<Transaction>, LedgerError>;
+    // The transactions already recorded under any of `idempotency_keys`
+    async fn get_transactions_by_keys(&self, idempotency_keys: &[String]) -> Result<Vec<Transaction>, LedgerError>;
+    // The Reversal posted against `transaction_id`, if there is one
+    async fn find_reversal(&self, transaction_id: &Uuid) -> Result<Option<Transaction>, LedgerError>;
     async fn get_account_transactions(
         &self,
         account_id: &Uuid,
         limit: i64,
         offset: i64,
     ) -> Result<Vec<Transaction>, LedgerError>;
+    async fn get_account_transactions_page(
+        &self,
+        account_id: &Uuid,
+        filter: &TransactionFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<Transaction>, LedgerError>;
     async fn get_entries_for_transaction(
         &self,
         transaction_id: &Uuid,
     ) -> Result<Vec<Entry>, LedgerError>;
+    // Debit and credit totals of every account in `currency`, over entries
+    // in (from, as_of], or up to and including `as_of` without `from`.
+    // Accounts with no entries in range are listed with zero totals.
+    async fn get_account_totals(
+        &self,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        as_of: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+    ) -> Result<Vec<AccountTotals>, LedgerError>;
+    // Entries in (from, to], oldest first
+    async fn get_account_entries_between(
+        &self,
+        account_id: &Uuid,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<AccountEntry>, LedgerError>;
+    async fn get_account_entries(
+        &self,
+        account_id: &Uuid,
+        filter: &EntryFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<AccountEntry>, LedgerError>;
+    async fn get_transaction_leg_totals(
+        &self,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<TransactionLegTotals>, LedgerError>;
+    async fn find_orphan_entries(&self) -> Result<Vec<Entry>, LedgerError>;
+    async fn get_chain_links(&self, after_seq: i64, limit: i64) -> Result<Vec<ChainLink>, LedgerError>;
+    // Transactions neither linked into the chain nor queued to be
+    async fn find_unchained_transactions(&self) -> Result<Vec<Uuid>, LedgerError>;
+    async fn create_chain_anchor(&self) -> Result<Option<ChainAnchor>, LedgerError>;
+    async fn list_chain_anchors(&self) -> Result<Vec<ChainAnchor>, LedgerError>;
+    // Transactions in (from, to], oldest first
+    async fn get_transactions_with_entries(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<(Transaction, Vec<Entry>)>, LedgerError>;
+    async fn save_merkle_root(&self, root: &PublishedRoot) -> Result<(), LedgerError>;
+    async fn find_merkle_root(
+        &self,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<PublishedRoot>, LedgerError>;
+    async fn get_transaction_signature(
+        &self,
+        transaction_id: &Uuid,
+    ) -> Result<Option<TransactionSignature>, LedgerError>;
+    // Undelivered outbox events, oldest first
+    async fn get_pending_events(&self, limit: i64) -> Result<Vec<EventRecord>, LedgerError>;
+    async fn mark_event_delivered(&self, seq: i64) -> Result<(), LedgerError>;
+    async fn record_event_failure(&self, seq: i64, error: &str) -> Result<(), LedgerError>;
+    // Records the failure and sets the event aside, so get_pending_events
+    // no longer returns it
+    async fn dead_letter_event(&self, seq: i64, error: &str) -> Result<(), LedgerError>;
+    // Returns every dead-lettered event to the pending queue with its
+    // attempts reset; returns how many were replayed
+    async fn replay_dead_letter_events(&self) -> Result<u64, LedgerError>;
+    async fn create_webhook_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), LedgerError>;
+    async fn list_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, LedgerError>;
+    // Queues the event for every active subscriber whose filter accepts it;
+    // returns how many deliveries were newly queued
+    async fn enqueue_webhook_deliveries(&self, event: &EventRecord) -> Result<u64, LedgerError>;
+    // Returns due deliveries and pushes their next attempt out by `lease`
+    async fn claim_due_webhook_deliveries(
+        &self,
+        limit: i64,
+        lease: std::time::Duration,
+    ) -> Result<Vec<WebhookDelivery>, LedgerError>;
+    async fn record_webhook_attempt(
+        &self,
+        delivery_id: &Uuid,
+        attempt: &WebhookAttempt,
+    ) -> Result<(), LedgerError>;
+    async fn list_webhook_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        status: Option<DeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, LedgerError>;
+    // Resets one delivery, or every dead-lettered delivery of the
+    // subscription when delivery_id is None, to be sent again immediately
+    async fn replay_webhook_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        delivery_id: Option<&Uuid>,
+    ) -> Result<u64, LedgerError>;
+    // Updates committed after the call for the given account
+    async fn subscribe_account_updates(
+        &self,
+        account_id: &Uuid,
+    ) -> Result<AccountUpdateStream, LedgerError>;
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -42,175 +227,1406 @@ pub enum LedgerError {
     TransactionError(#[from] TransactionError),
     #[error("Idempotency violation")]
     IdempotencyViolation,
+    #[error("Invalid pagination cursor")]
+    InvalidCursor,
+    #[error("Transaction not found")]
+    TransactionNotFound,
+    #[error("Transaction was already reversed by {reversal_id}")]
+    AlreadyReversed { reversal_id: Uuid },
+    #[error("A reversal cannot itself be reversed")]
+    CannotReverseReversal,
+    #[error("No Merkle root has been published for this period")]
+    MerkleRootNotPublished,
+    #[error("Rebuilt Merkle root does not match the published root")]
+    MerkleRootMismatch,
+    #[error("Period ending {period_end} is not settled; only periods ending by {settled_by} can be published")]
+    PeriodNotSettled {
+        period_end: chrono::DateTime<chrono::Utc>,
+        settled_by: chrono::DateTime<chrono::Utc>,
+    },
+    #[error("Signing error: {0}")]
+    SigningError(#[from] SigningError),
+    #[error("Database schema is at version {found}, this library requires version {expected}")]
+    SchemaVersionMismatch { expected: i64, found: i64 },
+    #[error("Applied migration {version} does not match the embedded migration")]
+    MigrationChecksumMismatch { version: i64 },
+    #[error("Database has ledger tables but no migration history; run migrate::baseline first")]
+    UntrackedSchema,
+    #[error("Stored event {seq} has an unreadable payload")]
+    InvalidEventPayload { seq: i64 },
+    #[error("Subscriber fell behind and missed {missed} updates")]
+    SubscriptionLagged { missed: u64 },
+    #[error("Batch item {index} rejected: {reason}")]
+    BatchRejected { index: usize, reason: String },
+    #[error("Balance shard count must be between 1 and {max}, got {shards}")]
+    InvalidShardCount { shards: i32, max: i32 },
+    #[error("Import file is unreadable: {0}")]
+    InvalidImportFile(String),
 }
 
 pub struct PostgresLedgerStore {
     pool: PgPool,
+    signer: Option<TransactionSigner>,
+    // Started by the first subscriber; shares one LISTEN connection
+    updates: tokio::sync::OnceCell<AccountUpdateBroadcaster>,
+}
+
+// Advisory lock serializing appends to the transaction hash chain and to
+// the outbox. Only append_chain_links and append_outbox_events take it,
+// never a posting.
+const CHAIN_LOCK_KEY: i64 = 0x4c45_4447_4552;
+
+// Queued postings linked per append_chain_links round
+const CHAIN_APPEND_BATCH: i64 = 1000;
+
+// Queued events moved per append_outbox_events round
+const OUTBOX_APPEND_BATCH: i64 = 1000;
+
+// NOTIFY channel carrying an AccountUpdate per account touched by a commit
+const ACCOUNT_UPDATES_CHANNEL: &str = "ledger_account_updates";
+
+// Postgres refuses NOTIFY payloads of 8000 bytes or more, failing the
+// whole transaction
+const MAX_NOTIFY_PAYLOAD: usize = 7999;
+
+// Items per multi-row statement when writing batches
+const BATCH_CHUNK_SIZE: usize = 1000;
+
+// Upper bound on balance shards per account, matching the CHECK on
+// accounts.balance_shards
+pub const MAX_BALANCE_SHARDS: i32 = 64;
+
+// Shard of a sharded account that a transaction's entries update. Derived
+// from the random transaction id, so postings spread evenly and retries of
+// the same transaction land on the same shard.
+fn balance_shard(transaction_id: &Uuid, shards: i32) -> i32 {
+    if shards <= 1 {
+        return 0;
+    }
+    (transaction_id.as_u128() % shards as u128) as i32
+}
+
+// Inserts accounts, their current-balance rows and AccountCreated events as
+// part of the caller's database transaction
+async fn insert_account(connection: &mut sqlx::PgConnection, account: &Account) -> Result<(), LedgerError> {
+    sqlx::query!(
+        r#"
+        INSERT INTO accounts (id, account_type, currency, created_at, metadata)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        account.id,
+        account.account_type as _,
+        &account.currency,
+        account.created_at,
+        &account.metadata
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO account_current_balances (account_id) VALUES ($1)",
+        account.id
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    insert_events(&mut *connection, &[LedgerEvent::account_created(account)]).await
+}
+
+// Queues events for the outbox as part of the caller's database
+// transaction. append_outbox_events moves them into ledger_events once
+// committed, which is where they get their seq.
+async fn insert_events(
+    connection: &mut sqlx::PgConnection,
+    events: &[LedgerEvent],
+) -> Result<(), LedgerError> {
+    if events.is_empty() {
+        return Ok(());
+    }
+
+    let ids: Vec<Uuid> = events.iter().map(|_| Uuid::new_v4()).collect();
+    let event_types: Vec<&str> = events.iter().map(|event| event.event_type()).collect();
+    let aggregate_ids: Vec<Uuid> = events.iter().map(|event| event.aggregate_id()).collect();
+    let payloads: Vec<serde_json::Value> = events
+        .iter()
+        .map(|event| serde_json::to_value(event).expect("ledger events always serialize"))
+        .collect();
+
+    // WITH ORDINALITY keeps the queue in the order the events were given
+    sqlx::query!(
+        r#"
+        INSERT INTO pending_ledger_events (id, event_type, aggregate_id, payload)
+        SELECT id, event_type, aggregate_id, payload
+        FROM UNNEST($1::UUID[], $2::VARCHAR[], $3::UUID[], $4::JSONB[])
+            WITH ORDINALITY AS e(id, event_type, aggregate_id, payload, position)
+        ORDER BY position
+        "#,
+        &ids,
+        &event_types as &[&str],
+        &aggregate_ids,
+        &payloads
+    )
+    .execute(&mut *connection)
+    .await?;
+
+    Ok(())
 }
 
 impl PostgresLedgerStore {
+    // Does not check the schema version; prefer connect() outside of tests
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            signer: None,
+            updates: tokio::sync::OnceCell::new(),
+        }
     }
-}
 
-#[async_trait]
-impl LedgerStore for PostgresLedgerStore {
-    async fn create_account(&self, account: &Account) -> Result<(), LedgerError> {
+    // Refuses to start against a schema older or newer than this library
+    pub async fn connect(pool: PgPool) -> Result<Self, LedgerError> {
+        crate::migrate::check_schema(&pool).await?;
+        Ok(Self::new(pool))
+    }
+
+    // Sign every transaction this instance records, so investigators can
+    // later tell which node wrote it
+    pub fn with_signer(mut self, signer: TransactionSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    // Opens the LISTEN connection and forwards every notification to the
+    // broadcaster. sqlx re-establishes the connection if it drops; updates
+    // committed while it is down are not replayed.
+    async fn start_update_listener(&self) -> Result<AccountUpdateBroadcaster, LedgerError> {
+        let mut listener = sqlx::postgres::PgListener::connect_with(&self.pool).await?;
+        listener.listen(ACCOUNT_UPDATES_CHANNEL).await?;
+
+        let broadcaster = AccountUpdateBroadcaster::default();
+        let publisher = broadcaster.clone();
+        tokio::spawn(async move {
+            loop {
+                match listener.recv().await {
+                    Ok(notification) => match serde_json::from_str::<AccountUpdate>(notification.payload()) {
+                        Ok(update) => publisher.publish(update),
+                        Err(error) => tracing::warn!(%error, "unreadable account update notification"),
+                    },
+                    Err(error) => {
+                        tracing::warn!(%error, "account update listener failed");
+                        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                    }
+                }
+            }
+        });
+
+        Ok(broadcaster)
+    }
+
+    // Writes transactions together with their entries, chain links,
+    // signatures, balance updates, outbox events and notifications, using
+    // one multi-row statement per table. Entries are written with
+    // balance_after, balance_shard and a shard-ordered timestamp computed
+    // here under the balance row locks. Items whose idempotency key is
+    // already taken are skipped and reported as duplicates. With
+    // no_overdraft, the first item that takes an account below zero fails
+    // the call with BatchRejected at its position, and the caller rolls
+    // back; every other check is the caller's.
+    async fn write_batch(
+        &self,
+        connection: &mut sqlx::PgConnection,
+        items: &[(&Transaction, &[Entry])],
+        no_overdraft: bool,
+    ) -> Result<Vec<BatchItemStatus>, LedgerError> {
+        let transaction_ids: Vec<Uuid> = items.iter().map(|(t, _)| t.id).collect();
+        let transaction_types: Vec<&str> = items.iter().map(|(t, _)| t.transaction_type.as_str()).collect();
+        let amounts: Vec<Decimal> = items.iter().map(|(t, _)| t.amount).collect();
+        let sources: Vec<Option<Uuid>> = items.iter().map(|(t, _)| t.source_account_id).collect();
+        let destinations: Vec<Option<Uuid>> = items.iter().map(|(t, _)| t.destination_account_id).collect();
+        let timestamps: Vec<chrono::DateTime<chrono::Utc>> = items.iter().map(|(t, _)| t.timestamp).collect();
+        let reason_codes: Vec<&str> = items.iter().map(|(t, _)| t.reason_code.as_str()).collect();
+        let metadata: Vec<serde_json::Value> = items.iter().map(|(t, _)| t.metadata.clone()).collect();
+        let keys: Vec<&str> = items.iter().map(|(t, _)| t.idempotency_key.as_str()).collect();
+
+        // ON CONFLICT rather than a prior SELECT, so a concurrent writer
+        // using the same key cannot slip in between check and insert
+        let inserted: HashSet<Uuid> = sqlx::query_scalar!(
+            r#"
+            INSERT INTO transactions (
+                id, transaction_type, amount, source_account_id,
+                destination_account_id, timestamp, reason_code,
+                metadata, idempotency_key
+            )
+            SELECT * FROM UNNEST(
+                $1::UUID[], $2::VARCHAR[], $3::NUMERIC[], $4::UUID[], $5::UUID[],
+                $6::TIMESTAMPTZ[], $7::VARCHAR[], $8::JSONB[], $9::VARCHAR[]
+            )
+            ON CONFLICT (idempotency_key) DO NOTHING
+            RETURNING id
+            "#,
+            &transaction_ids,
+            &transaction_types as &[&str],
+            &amounts,
+            &sources as &[Option<Uuid>],
+            &destinations as &[Option<Uuid>],
+            &timestamps,
+            &reason_codes as &[&str],
+            &metadata,
+            &keys as &[&str]
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .collect();
+
+        let mut existing: HashMap<String, Uuid> = HashMap::new();
+        if inserted.len() < items.len() {
+            let duplicate_keys: Vec<&str> = items
+                .iter()
+                .filter(|(t, _)| !inserted.contains(&t.id))
+                .map(|(t, _)| t.idempotency_key.as_str())
+                .collect();
+            for row in sqlx::query!(
+                "SELECT id, idempotency_key FROM transactions WHERE idempotency_key = ANY($1)",
+                &duplicate_keys as &[&str]
+            )
+            .fetch_all(&mut *connection)
+            .await?
+            {
+                existing.insert(row.idempotency_key, row.id);
+            }
+        }
+
+        let (positions, written): (Vec<usize>, Vec<(&Transaction, &[Entry])>) = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (t, _))| inserted.contains(&t.id))
+            .map(|(position, item)| (position, *item))
+            .unzip();
+        let statuses = items
+            .iter()
+            .map(|(t, _)| {
+                if inserted.contains(&t.id) {
+                    BatchItemStatus::Recorded
+                } else {
+                    BatchItemStatus::Duplicate {
+                        existing_transaction_id: existing.get(&t.idempotency_key).copied().unwrap_or(t.id),
+                    }
+                }
+            })
+            .collect();
+        if written.is_empty() {
+            return Ok(statuses);
+        }
+
+        let touched_accounts: Vec<Uuid> = written
+            .iter()
+            .flat_map(|(_, entries)| entries.iter().map(|entry| entry.account_id))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let shard_counts: HashMap<Uuid, i32> = sqlx::query!(
+            "SELECT id, balance_shards FROM accounts WHERE id = ANY($1)",
+            &touched_accounts
+        )
+        .fetch_all(&mut *connection)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.balance_shards))
+        .collect();
+
+        let shard_of = |transaction: &Transaction, entry: &Entry| {
+            let shards = shard_counts.get(&entry.account_id).copied().unwrap_or(1);
+            (entry.account_id, balance_shard(&transaction.id, shards))
+        };
+        let target_shards: BTreeSet<(Uuid, i32)> = written
+            .iter()
+            .flat_map(|(transaction, entries)| entries.iter().map(move |entry| shard_of(transaction, entry)))
+            .collect();
+
+        // Accounts some item takes money from; only these are checked for
+        // overdrafts
+        let mut guarded: BTreeSet<Uuid> = BTreeSet::new();
+        if no_overdraft {
+            for (_, entries) in &written {
+                let mut deltas: HashMap<Uuid, Decimal> = HashMap::new();
+                for entry in entries.iter() {
+                    *deltas.entry(entry.account_id).or_default() += entry.signed_amount();
+                }
+                guarded.extend(deltas.into_iter().filter(|(_, delta)| *delta < Decimal::ZERO).map(|(id, _)| id));
+            }
+        }
+
+        // Create missing shard rows, then lock every row this batch depends
+        // on in (account id, shard) order so concurrent postings cannot
+        // deadlock on each other. Guarded accounts have all their shards
+        // locked, so their total cannot move before commit.
         sqlx::query!(
             r#"
-            INSERT INTO accounts (id, account_type, currency, created_at, metadata)
-            VALUES ($1, $2, $3, $4, $5)
+            INSERT INTO account_current_balances (account_id, shard)
+            SELECT * FROM UNNEST($1::UUID[], $2::INTEGER[])
+            ON CONFLICT (account_id, shard) DO NOTHING
             "#,
-            account.id,
-            account.account_type as _,
-            &account.currency,
-            account.created_at,
-            &account.metadata
+            &target_shards.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+            &target_shards.iter().map(|(_, shard)| *shard).collect::<Vec<_>>()
         )
-        .execute(&self.pool)
+        .execute(&mut *connection)
         .await?;
-        
-        Ok(())
-    }
-
-    async fn get_account(&self, account_id: &Uuid) -> Result<Option<Account>, LedgerError> {
-        let account = sqlx::query_as!(
-            Account,
+        sqlx::query!(
             r#"
-            SELECT id, account_type as "account_type: _", currency, created_at, metadata
-            FROM accounts WHERE id = $1
+            SELECT account_id, shard
+            FROM account_current_balances
+            WHERE account_id = ANY($1)
+               OR (account_id, shard) IN (SELECT * FROM UNNEST($2::UUID[], $3::INTEGER[]))
+            ORDER BY account_id, shard
+            FOR UPDATE
             "#,
-            account_id
+            &guarded.iter().copied().collect::<Vec<_>>(),
+            &target_shards.iter().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+            &target_shards.iter().map(|(_, shard)| *shard).collect::<Vec<_>>()
         )
-        .fetch_optional(&self.pool)
+        .fetch_all(&mut *connection)
         .await?;
-        
-        Ok(account)
-    }
 
-    async fn get_account_balance(&self, account_id: &Uuid) -> Result<Decimal, LedgerError> {
-        let result = sqlx::query!(
+        // Balances before this batch, per shard and summed per account. The
+        // shards this batch writes are locked, so their balances are exact.
+        // So are the totals of unsharded and guarded accounts; for other
+        // sharded accounts, shards being written by concurrent postings are
+        // read as last committed.
+        let shard_rows = sqlx::query!(
             r#"
-            SELECT COALESCE(SUM(
-                CASE 
-                    WHEN entry_type = 'Debit' THEN amount
-                    ELSE -amount
-                END
-            ), 0) as balance
-            FROM entries
-            WHERE account_id = $1
+            SELECT account_id, shard, balance, last_entry_at
+            FROM account_current_balances
+            WHERE account_id = ANY($1)
             "#,
-            account_id
+            &touched_accounts
         )
-        .fetch_one(&self.pool)
+        .fetch_all(&mut *connection)
         .await?;
-        
-        Ok(result.balance.unwrap_or(Decimal::ZERO))
-    }
+        let mut shard_balances: HashMap<(Uuid, i32), Decimal> = HashMap::new();
+        let mut shard_last_entry: HashMap<(Uuid, i32), chrono::DateTime<chrono::Utc>> = HashMap::new();
+        let mut balances: HashMap<Uuid, Decimal> = HashMap::new();
+        for row in shard_rows {
+            shard_balances.insert((row.account_id, row.shard), row.balance);
+            if let Some(last_entry_at) = row.last_entry_at {
+                shard_last_entry.insert((row.account_id, row.shard), last_entry_at);
+            }
+            *balances.entry(row.account_id).or_default() += row.balance;
+        }
 
-    async fn record_transaction(
-        &self,
-        transaction: &Transaction,
-        entries: &[Entry],
-    ) -> Result<(), LedgerError> {
-        let mut db_transaction = self.pool.begin().await?;
+        // Replay the batch in order. Each entry's balance_after is the
+        // running balance of the locked shard it updates, replacing whatever
+        // the caller computed from an earlier read. Alongside come the
+        // per-shard deltas for the balance rows and each transaction's
+        // effect on each account for the checks, events and notifications.
+        let mut deltas: BTreeMap<(Uuid, i32), (Decimal, i64, chrono::DateTime<chrono::Utc>)> = BTreeMap::new();
+        let mut recorded: Vec<(&Transaction, Vec<Entry>)> = Vec::with_capacity(written.len());
+        let mut events = Vec::new();
+        let mut notifications = Vec::new();
+        for (&position, (transaction, entries)) in positions.iter().zip(&written) {
+            let mut entries = entries.to_vec();
+            let mut touched: BTreeMap<Uuid, (Decimal, Vec<Uuid>)> = BTreeMap::new();
+            for entry in entries.iter_mut() {
+                let shard = shard_of(transaction, entry);
+                // A shard's entries must sort in the order they were applied,
+                // so an entry stamped at or before the shard's latest one (it
+                // waited on the lock, or another instance's clock is ahead)
+                // moves to just after it. Stored timestamps are microseconds.
+                let mut timestamp = chrono::DateTime::from_timestamp_micros(entry.timestamp.timestamp_micros())
+                    .unwrap_or(entry.timestamp);
+                if let Some(last_entry_at) = shard_last_entry.get(&shard) {
+                    if timestamp <= *last_entry_at {
+                        timestamp = *last_entry_at + chrono::Duration::microseconds(1);
+                    }
+                }
+                shard_last_entry.insert(shard, timestamp);
+                entry.timestamp = timestamp;
+
+                let shard_balance = shard_balances.entry(shard).or_default();
+                *shard_balance += entry.signed_amount();
+                entry.balance_after = *shard_balance;
+                entry.balance_shard = shard.1;
+                *balances.entry(entry.account_id).or_default() += entry.signed_amount();
 
-        // Check idempotency
-        let existing = sqlx::query!(
-            "SELECT id FROM transactions WHERE idempotency_key = $1",
-            &transaction.idempotency_key
+                let delta = deltas
+                    .entry(shard)
+                    .or_insert((Decimal::ZERO, 0, entry.timestamp));
+                delta.0 += entry.signed_amount();
+                delta.1 += 1;
+                delta.2 = delta.2.max(entry.timestamp);
+
+                let account = touched.entry(entry.account_id).or_default();
+                account.0 += entry.signed_amount();
+                account.1.push(entry.id);
+            }
+
+            events.push(LedgerEvent::transaction_recorded(transaction, &entries));
+            for (account_id, (delta, entry_ids)) in touched {
+                let balance = balances[&account_id];
+                if guarded.contains(&account_id) && delta < Decimal::ZERO && balance < Decimal::ZERO {
+                    return Err(LedgerError::BatchRejected {
+                        index: position,
+                        reason: format!("Insufficient balance on account {}", account_id),
+                    });
+                }
+
+                events.push(LedgerEvent::BalanceChanged {
+                    account_id,
+                    transaction_id: transaction.id,
+                    delta,
+                    balance,
+                });
+                // Delivered to listeners only if and when the transaction commits
+                let update = AccountUpdate {
+                    account_id,
+                    transaction_id: transaction.id,
+                    entry_ids,
+                    balance,
+                };
+                let payload = serde_json::to_string(&update).expect("account updates always serialize");
+                // Live updates are best effort; a posting must never fail
+                // because its notification is too large
+                if payload.len() > MAX_NOTIFY_PAYLOAD {
+                    tracing::warn!(%account_id, transaction_id = %transaction.id, "account update too large to notify");
+                } else {
+                    notifications.push(payload);
+                }
+            }
+            recorded.push((*transaction, entries));
+        }
+
+        let entries: Vec<&Entry> = recorded.iter().flat_map(|(_, entries)| entries.iter()).collect();
+        sqlx::query!(
+            r#"
+            INSERT INTO entries (
+                id, transaction_id, account_id, amount,
+                entry_type, timestamp, balance_after, balance_shard
+            )
+            SELECT * FROM UNNEST(
+                $1::UUID[], $2::UUID[], $3::UUID[], $4::NUMERIC[],
+                $5::VARCHAR[], $6::TIMESTAMPTZ[], $7::NUMERIC[], $8::INTEGER[]
+            )
+            "#,
+            &entries.iter().map(|e| e.id).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.transaction_id).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.account_id).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.amount).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.entry_type.as_str()).collect::<Vec<_>>() as &[&str],
+            &entries.iter().map(|e| e.timestamp).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.balance_after).collect::<Vec<_>>(),
+            &entries.iter().map(|e| e.balance_shard).collect::<Vec<_>>()
         )
-        .fetch_optional(&mut *db_transaction)
+        .execute(&mut *connection)
         .await?;
 
-        if existing.is_some() {
-            return Err(LedgerError::IdempotencyViolation);
+        // Queue for the hash chain. append_chain_links links them once they
+        // have committed, so postings do not serialize on the chain head.
+        sqlx::query!(
+            r#"
+            INSERT INTO pending_chain_links (transaction_id)
+            SELECT transaction_id FROM UNNEST($1::UUID[]) WITH ORDINALITY AS q(transaction_id, position)
+            ORDER BY position
+            "#,
+            &recorded.iter().map(|(t, _)| t.id).collect::<Vec<_>>()
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        if let Some(signer) = &self.signer {
+            let signatures: Vec<TransactionSignature> = recorded
+                .iter()
+                .map(|(transaction, entries)| signer.sign(transaction, entries))
+                .collect();
+            sqlx::query!(
+                r#"
+                INSERT INTO transaction_signatures (transaction_id, key_id, signature)
+                SELECT * FROM UNNEST($1::UUID[], $2::VARCHAR[], $3::BYTEA[])
+                "#,
+                &recorded.iter().map(|(t, _)| t.id).collect::<Vec<_>>(),
+                &signatures.iter().map(|s| s.key_id.as_str()).collect::<Vec<_>>() as &[&str],
+                &signatures.iter().map(|s| s.signature.clone()).collect::<Vec<_>>()
+            )
+            .execute(&mut *connection)
+            .await?;
         }
 
-        // Insert transaction
         sqlx::query!(
             r#"
-            INSERT INTO transactions (
-                id, transaction_type, amount, source_account_id,
-                destination_account_id, timestamp, reason_code,
-                metadata, idempotency_key
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            UPDATE account_current_balances b SET
+                balance = b.balance + d.balance,
+                entry_count = b.entry_count + d.entry_count,
+                last_entry_at = GREATEST(b.last_entry_at, d.last_entry_at),
+                updated_at = NOW()
+            FROM UNNEST($1::UUID[], $2::INTEGER[], $3::NUMERIC[], $4::BIGINT[], $5::TIMESTAMPTZ[])
+                AS d(account_id, shard, balance, entry_count, last_entry_at)
+            WHERE b.account_id = d.account_id AND b.shard = d.shard
             "#,
-            transaction.id,
-            transaction.transaction_type as _,
-            transaction.amount,
-            transaction.source_account_id,
-            transaction.destination_account_id,
-            transaction.timestamp,
-            &transaction.reason_code,
-            &transaction.metadata,
-            &transaction.idempotency_key
+            &deltas.keys().map(|(account_id, _)| *account_id).collect::<Vec<_>>(),
+            &deltas.keys().map(|(_, shard)| *shard).collect::<Vec<_>>(),
+            &deltas.values().map(|d| d.0).collect::<Vec<_>>(),
+            &deltas.values().map(|d| d.1).collect::<Vec<_>>(),
+            &deltas.values().map(|d| d.2).collect::<Vec<_>>()
         )
-        .execute(&mut *db_transaction)
+        .execute(&mut *connection)
         .await?;
 
-        // Insert entries
-        for entry in entries {
+        insert_events(&mut *connection, &events).await?;
+
+        sqlx::query!(
+            "SELECT pg_notify($1, payload) FROM UNNEST($2::TEXT[]) AS payload",
+            ACCOUNT_UPDATES_CHANNEL,
+            &notifications
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(statuses)
+    }
+
+    async fn write_transaction(
+        &self,
+        transaction: &Transaction,
+        entries: &[Entry],
+        no_overdraft: bool,
+    ) -> Result<(), LedgerError> {
+        let mut db_transaction = self.pool.begin().await?;
+
+        let statuses = match self
+            .write_batch(&mut db_transaction, &[(transaction, entries)], no_overdraft)
+            .await
+        {
+            Ok(statuses) => statuses,
+            Err(LedgerError::BatchRejected { .. }) => return Err(LedgerError::InsufficientBalance),
+            Err(error) => return Err(error),
+        };
+        if let Some(BatchItemStatus::Duplicate { .. }) = statuses.first() {
+            return Err(LedgerError::IdempotencyViolation);
+        }
+
+        db_transaction.commit().await?;
+        self.link_committed_postings().await;
+        Ok(())
+    }
+
+    // Creates `accounts` along with the postings: in the same database
+    // transaction for AllOrNothing, and in one of their own before the first
+    // chunk for BestEffort, whose chunks commit independently
+    async fn write_items(
+        &self,
+        accounts: &[Account],
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+        no_overdraft: bool,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError> {
+        let mut statuses: Vec<Option<BatchItemStatus>> = vec![None; items.len()];
+        let reject = |statuses: &mut Vec<Option<BatchItemStatus>>, index: usize, reason: String| {
+            match mode {
+                BatchMode::AllOrNothing => Err(LedgerError::BatchRejected { index, reason }),
+                BatchMode::BestEffort => {
+                    statuses[index] = Some(BatchItemStatus::Rejected { reason });
+                    Ok(())
+                }
+            }
+        };
+
+        for (index, (transaction, entries)) in items.iter().enumerate() {
+            if let Err(reason) = validate_batch_item(transaction, entries) {
+                reject(&mut statuses, index, reason)?;
+            }
+        }
+
+        // Accounts are checked up front so a single unknown account does not
+        // fail a whole multi-row insert
+        let referenced = |(transaction, entries): &(Transaction, Vec<Entry>)| -> Vec<Uuid> {
+            entries
+                .iter()
+                .map(|entry| entry.account_id)
+                .chain(transaction.source_account_id)
+                .chain(transaction.destination_account_id)
+                .collect()
+        };
+        let mut account_ids: Vec<Uuid> = items.iter().flat_map(referenced).collect();
+        account_ids.sort();
+        account_ids.dedup();
+        let known: HashSet<Uuid> =
+            sqlx::query_scalar!("SELECT id FROM accounts WHERE id = ANY($1)", &account_ids)
+                .fetch_all(&self.pool)
+                .await?
+                .into_iter()
+                .chain(accounts.iter().map(|account| account.id))
+                .collect();
+        for (index, item) in items.iter().enumerate() {
+            if statuses[index].is_some() {
+                continue;
+            }
+            if let Some(account_id) = referenced(item).into_iter().find(|id| !known.contains(id)) {
+                reject(&mut statuses, index, format!("Account {} not found", account_id))?;
+            }
+        }
+
+        // Repeated keys within the batch: the first occurrence is written
+        // and later ones are reported as its duplicates
+        let mut first_with_key: HashMap<&str, usize> = HashMap::new();
+        let mut repeats = Vec::new();
+        let mut pending = Vec::new();
+        for (index, (transaction, _)) in items.iter().enumerate() {
+            if statuses[index].is_some() {
+                continue;
+            }
+            match first_with_key.get(transaction.idempotency_key.as_str()) {
+                Some(&first) => repeats.push((index, first)),
+                None => {
+                    first_with_key.insert(&transaction.idempotency_key, index);
+                    pending.push(index);
+                }
+            }
+        }
+
+        match mode {
+            BatchMode::AllOrNothing => {
+                let mut db_transaction = self.pool.begin().await?;
+                for account in accounts {
+                    insert_account(&mut db_transaction, account).await?;
+                }
+                for chunk in pending.chunks(BATCH_CHUNK_SIZE) {
+                    let chunk_items: Vec<(&Transaction, &[Entry])> =
+                        chunk.iter().map(|&i| (&items[i].0, items[i].1.as_slice())).collect();
+                    let written = match self.write_batch(&mut db_transaction, &chunk_items, no_overdraft).await {
+                        Ok(written) => written,
+                        // Report the position in the submitted batch, not in the chunk
+                        Err(LedgerError::BatchRejected { index, reason }) => {
+                            return Err(LedgerError::BatchRejected { index: chunk[index], reason })
+                        }
+                        Err(error) => return Err(error),
+                    };
+                    for (&index, status) in chunk.iter().zip(written) {
+                        statuses[index] = Some(status);
+                    }
+                }
+                db_transaction.commit().await?;
+            }
+            BatchMode::BestEffort => {
+                if !accounts.is_empty() {
+                    let mut db_transaction = self.pool.begin().await?;
+                    for account in accounts {
+                        insert_account(&mut db_transaction, account).await?;
+                    }
+                    db_transaction.commit().await?;
+                }
+                for chunk in pending.chunks(BATCH_CHUNK_SIZE) {
+                    let chunk_items: Vec<(&Transaction, &[Entry])> =
+                        chunk.iter().map(|&i| (&items[i].0, items[i].1.as_slice())).collect();
+                    let mut db_transaction = self.pool.begin().await?;
+                    match self.write_batch(&mut db_transaction, &chunk_items, no_overdraft).await {
+                        Ok(written) => {
+                            db_transaction.commit().await?;
+                            for (&index, status) in chunk.iter().zip(written) {
+                                statuses[index] = Some(status);
+                            }
+                        }
+                        // A constraint or the overdraft check rejected some
+                        // item of the chunk; retry item by item to find out which
+                        Err(LedgerError::DatabaseError(sqlx::Error::Database(_)))
+                        | Err(LedgerError::BatchRejected { .. }) => {
+                            db_transaction.rollback().await?;
+                            for (&index, item) in chunk.iter().zip(&chunk_items) {
+                                let mut db_transaction = self.pool.begin().await?;
+                                match self.write_batch(&mut db_transaction, &[*item], no_overdraft).await {
+                                    Ok(written) => {
+                                        db_transaction.commit().await?;
+                                        statuses[index] = written.into_iter().next();
+                                    }
+                                    Err(LedgerError::DatabaseError(sqlx::Error::Database(error))) => {
+                                        db_transaction.rollback().await?;
+                                        statuses[index] =
+                                            Some(BatchItemStatus::Rejected { reason: error.to_string() });
+                                    }
+                                    Err(LedgerError::BatchRejected { reason, .. }) => {
+                                        db_transaction.rollback().await?;
+                                        statuses[index] = Some(BatchItemStatus::Rejected { reason });
+                                    }
+                                    Err(error) => return Err(error),
+                                }
+                            }
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+            }
+        }
+
+        self.link_committed_postings().await;
+
+        for (index, first) in repeats {
+            statuses[index] = Some(match &statuses[first] {
+                Some(BatchItemStatus::Duplicate { existing_transaction_id }) => BatchItemStatus::Duplicate {
+                    existing_transaction_id: *existing_transaction_id,
+                },
+                Some(BatchItemStatus::Recorded) => BatchItemStatus::Duplicate {
+                    existing_transaction_id: items[first].0.id,
+                },
+                _ => BatchItemStatus::Rejected {
+                    reason: format!("Repeats the idempotency key of rejected item {}", first),
+                },
+            });
+        }
+
+        Ok(items
+            .iter()
+            .zip(statuses)
+            .enumerate()
+            .map(|(index, ((transaction, _), status))| BatchItemOutcome {
+                index,
+                transaction_id: transaction.id,
+                idempotency_key: transaction.idempotency_key.clone(),
+                status: status.expect("every batch item is resolved"),
+            })
+            .collect())
+    }
+
+    // Appends queued postings to the hash chain in queue order, hashing
+    // them as stored. Safe to run from several processes at once; returns
+    // how many links were appended.
+    pub async fn append_chain_links(&self) -> Result<u64, LedgerError> {
+        let mut appended = 0;
+        loop {
+            let mut db_transaction = self.pool.begin().await?;
+            sqlx::query!("SELECT pg_advisory_xact_lock($1)", CHAIN_LOCK_KEY)
+                .execute(&mut *db_transaction)
+                .await?;
+
+            let queued: Vec<Uuid> = sqlx::query_scalar!(
+                "SELECT transaction_id FROM pending_chain_links ORDER BY queue_seq LIMIT $1",
+                CHAIN_APPEND_BATCH
+            )
+            .fetch_all(&mut *db_transaction)
+            .await?;
+            if queued.is_empty() {
+                db_transaction.commit().await?;
+                return Ok(appended);
+            }
+
+            let transactions: HashMap<Uuid, Transaction> = sqlx::query_as!(
+                Transaction,
+                r#"
+                SELECT id, transaction_type as "transaction_type: _", amount,
+                       source_account_id, destination_account_id, timestamp,
+                       reason_code, metadata, idempotency_key
+                FROM transactions WHERE id = ANY($1)
+                "#,
+                &queued
+            )
+            .fetch_all(&mut *db_transaction)
+            .await?
+            .into_iter()
+            .map(|transaction| (transaction.id, transaction))
+            .collect();
+            let mut entries: HashMap<Uuid, Vec<Entry>> = HashMap::new();
+            for entry in sqlx::query_as!(
+                Entry,
+                r#"
+                SELECT id, transaction_id, account_id, amount,
+                       entry_type as "entry_type: _", timestamp, balance_after, balance_shard
+                FROM entries WHERE transaction_id = ANY($1)
+                "#,
+                &queued
+            )
+            .fetch_all(&mut *db_transaction)
+            .await?
+            {
+                entries.entry(entry.transaction_id).or_default().push(entry);
+            }
+
+            let head = sqlx::query!("SELECT seq, hash FROM transaction_chain ORDER BY seq DESC LIMIT 1")
+                .fetch_optional(&mut *db_transaction)
+                .await?;
+            let (mut seq, mut prev_hash) = head
+                .map(|head| (head.seq, head.hash))
+                .unwrap_or((0, hash_chain::GENESIS_HASH.to_vec()));
+
+            let mut link_seqs = Vec::with_capacity(queued.len());
+            let mut link_prev_hashes = Vec::with_capacity(queued.len());
+            let mut link_hashes = Vec::with_capacity(queued.len());
+            for transaction_id in &queued {
+                // The foreign key keeps queued transactions from disappearing
+                let transaction = &transactions[transaction_id];
+                let transaction_entries = entries.get(transaction_id).map(Vec::as_slice).unwrap_or_default();
+                let hash = hash_chain::chain_hash(&prev_hash, transaction, transaction_entries);
+                seq += 1;
+                link_seqs.push(seq);
+                link_prev_hashes.push(std::mem::replace(&mut prev_hash, hash.clone()));
+                link_hashes.push(hash);
+            }
+
             sqlx::query!(
                 r#"
-                INSERT INTO entries (
-                    id, transaction_id, account_id, amount,
-                    entry_type, timestamp, balance_after
-                ) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                INSERT INTO transaction_chain (seq, transaction_id, prev_hash, hash)
+                SELECT * FROM UNNEST($1::BIGINT[], $2::UUID[], $3::BYTEA[], $4::BYTEA[])
                 "#,
-                entry.id,
-                entry.transaction_id,
-                entry.account_id,
-                entry.amount,
-                entry.entry_type as _,
-                entry.timestamp,
-                entry.balance_after
+                &link_seqs,
+                &queued,
+                &link_prev_hashes,
+                &link_hashes
             )
             .execute(&mut *db_transaction)
             .await?;
+            sqlx::query!("DELETE FROM pending_chain_links WHERE transaction_id = ANY($1)", &queued)
+                .execute(&mut *db_transaction)
+                .await?;
+
+            db_transaction.commit().await?;
+            appended += queued.len() as u64;
+            if (queued.len() as i64) < CHAIN_APPEND_BATCH {
+                return Ok(appended);
+            }
         }
+    }
 
-        db_transaction.commit().await?;
-        Ok(())
+    // Moves committed events from the queue into the outbox in queue
+    // order. seq is allocated here, under the chain lock, so the outbox
+    // becomes visible in seq order: an event queued by a posting that
+    // commits later is never given a smaller seq than one already there.
+    // Safe to run from several processes at once; returns how many events
+    // were moved.
+    pub async fn append_outbox_events(&self) -> Result<u64, LedgerError> {
+        let mut appended = 0;
+        loop {
+            let mut db_transaction = self.pool.begin().await?;
+            sqlx::query!("SELECT pg_advisory_xact_lock($1)", CHAIN_LOCK_KEY)
+                .execute(&mut *db_transaction)
+                .await?;
+
+            let moved = sqlx::query!(
+                r#"
+                WITH queued AS (
+                    DELETE FROM pending_ledger_events
+                    WHERE queue_seq IN (
+                        SELECT queue_seq FROM pending_ledger_events ORDER BY queue_seq LIMIT $1
+                    )
+                    RETURNING queue_seq, id, event_type, aggregate_id, payload, occurred_at
+                )
+                INSERT INTO ledger_events (id, event_type, aggregate_id, payload, occurred_at)
+                SELECT id, event_type, aggregate_id, payload, occurred_at
+                FROM queued
+                ORDER BY queue_seq
+                "#,
+                OUTBOX_APPEND_BATCH
+            )
+            .execute(&mut *db_transaction)
+            .await?
+            .rows_affected();
+
+            db_transaction.commit().await?;
+            appended += moved;
+            if (moved as i64) < OUTBOX_APPEND_BATCH {
+                return Ok(appended);
+            }
+        }
     }
 
-    async fn get_transaction(&self, transaction_id: &Uuid) -> Result<Option<Transaction>, LedgerError> {
-        let transaction = sqlx::query_as!(
-            Transaction,
+    // Links and publishes what the caller just committed. The writes are
+    // already durable, so a failure here only delays their links until the
+    // next write or chain anchor run, and their events until then or the
+    // relay's next poll.
+    async fn link_committed_postings(&self) {
+        if let Err(error) = self.append_chain_links().await {
+            tracing::warn!(%error, "appending to the hash chain failed");
+        }
+        if let Err(error) = self.append_outbox_events().await {
+            tracing::warn!(%error, "appending to the outbox failed");
+        }
+    }
+
+    // Periodically checkpoints every active account. Checkpoints are taken
+    // `settle` in the past so that entries still in flight when the task runs
+    // cannot land behind a checkpoint that has already been written.
+    pub fn spawn_checkpoint_task(
+        self: Arc<Self>,
+        interval: std::time::Duration,
+        settle: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                let as_of = chrono::Utc::now()
+                    - chrono::Duration::from_std(settle).unwrap_or_else(|_| chrono::Duration::zero());
+                match self.create_balance_checkpoints(as_of).await {
+                    Ok(created) => tracing::debug!(created, %as_of, "balance checkpoints written"),
+                    Err(error) => tracing::warn!(%error, "balance checkpoint run failed"),
+                }
+            }
+        })
+    }
+
+    // Rebuilds the account_balances materialized view. A concurrent refresh
+    // keeps the view readable while it runs but needs the unique index on
+    // account_id and is slower than a plain refresh.
+    pub async fn refresh_account_balances(
+        &self,
+        concurrently: bool,
+    ) -> Result<chrono::DateTime<chrono::Utc>, LedgerError> {
+        let started = std::time::Instant::now();
+        // The view reflects entries committed before the refresh began, so
+        // that is the moment its staleness is measured from
+        let refreshed_at = chrono::Utc::now();
+
+        // REFRESH cannot be parameterised or run inside a transaction block
+        if concurrently {
+            sqlx::query("REFRESH MATERIALIZED VIEW CONCURRENTLY account_balances")
+                .execute(&self.pool)
+                .await?;
+        } else {
+            sqlx::query("REFRESH MATERIALIZED VIEW account_balances")
+                .execute(&self.pool)
+                .await?;
+        }
+
+        let refreshed = sqlx::query!(
             r#"
-            SELECT id, transaction_type as "transaction_type: _", amount,
-                   source_account_id, destination_account_id, timestamp,
-                   reason_code, metadata, idempotency_key
-            FROM transactions WHERE id = $1
+            INSERT INTO materialized_view_refreshes (view_name, refreshed_at, duration_ms)
+            VALUES ('account_balances', $1, $2)
+            ON CONFLICT (view_name) DO UPDATE SET
+                refreshed_at = EXCLUDED.refreshed_at,
+                duration_ms = EXCLUDED.duration_ms
+            RETURNING refreshed_at
             "#,
-            transaction_id
+            refreshed_at,
+            started.elapsed().as_millis() as i64
         )
-        .fetch_optional(&self.pool)
+        .fetch_one(&self.pool)
         .await?;
-        
-        Ok(transaction)
+
+        Ok(refreshed.refreshed_at)
     }
 
-    async fn get_transaction_by_key(&self, idempotency_key: &str) -> Result<Option<Transaction>, LedgerError> {
-        let transaction = sqlx::query_as!(
-            Transaction,
+    // Balances for every account from the materialized view. When max_age is
+    // given and the view is older than that, it is refreshed first; otherwise
+    // the caller gets whatever the last refresh produced.
+    pub async fn list_balances(
+        &self,
+        max_age: Option<chrono::Duration>,
+    ) -> Result<BalanceListing, LedgerError> {
+        if let Some(max_age) = max_age {
+            let refreshed_at = self.account_balances_refreshed_at().await?;
+            let is_stale = refreshed_at
+                .map(|at| chrono::Utc::now() - at > max_age)
+                .unwrap_or(true);
+            if is_stale {
+                self.refresh_account_balances(true).await?;
+            }
+        }
+
+        let refreshed_at = self.account_balances_refreshed_at().await?;
+        let balances = sqlx::query_as!(
+            CachedBalance,
             r#"
-            SELECT id, transaction_type as "transaction_type: _", amount,
-                   source_account_id, destination_account_id, timestamp,
-                   reason_code, metadata, idempotency_key
-            FROM transactions WHERE idempotency_key = $1
-            "#,
-            idempotency_key
+            SELECT account_id as "account_id!", currency as "currency!",
+                   balance as "balance!", last_activity
+            FROM account_balances
+            ORDER BY account_id
+            "#
         )
-        .fetch_optional(&self.pool)
+        .fetch_all(&self.pool)
         .await?;
-        
-        Ok(transaction)
+
+        Ok(BalanceListing {
+            refreshed_at,
+            balances,
+        })
     }
 
-    async fn get_account_transactions(
-        &self,
+    pub fn spawn_chain_anchor_task(
+        self: Arc<Self>,
+        interval: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                // Anchor the head including anything left queued
+                self.link_committed_postings().await;
+                match self.create_chain_anchor().await {
+                    Ok(Some(anchor)) => tracing::info!(seq = anchor.seq, hash = %hex::encode(&anchor.hash), "chain anchor created"),
+                    Ok(None) => {}
+                    Err(error) => tracing::warn!(%error, "chain anchor creation failed"),
+                }
+            }
+        })
+    }
+
+    pub fn spawn_balance_view_refresh_task(
+        self: Arc<Self>,
+        interval: std::time::Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self.refresh_account_balances(true).await {
+                    Ok(refreshed_at) => tracing::debug!(%refreshed_at, "account_balances refreshed"),
+                    Err(error) => tracing::warn!(%error, "account_balances refresh failed"),
+                }
+            }
+        })
+    }
+
+    async fn account_balances_refreshed_at(
+        &self,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>, LedgerError> {
+        let row = sqlx::query!(
+            "SELECT refreshed_at FROM materialized_view_refreshes WHERE view_name = 'account_balances'"
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(|row| row.refreshed_at))
+    }
+}
+
+// Snapshot of the account_balances materialized view, for readers such as
+// dashboards that can tolerate slightly stale data
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BalanceListing {
+    pub refreshed_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub balances: Vec<CachedBalance>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CachedBalance {
+    pub account_id: Uuid,
+    pub currency: String,
+    pub balance: Decimal,
+    pub last_activity: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl BalanceListing {
+    pub fn staleness(&self) -> Option<chrono::Duration> {
+        self.refreshed_at.map(|at| chrono::Utc::now() - at)
+    }
+
+    pub fn is_stale(&self, max_age: chrono::Duration) -> bool {
+        self.staleness().map(|age| age > max_age).unwrap_or(true)
+    }
+}
+
+#[async_trait]
+impl LedgerStore for PostgresLedgerStore {
+    async fn create_account(&self, account: &Account) -> Result<(), LedgerError> {
+        let mut db_transaction = self.pool.begin().await?;
+        insert_account(&mut db_transaction, account).await?;
+        db_transaction.commit().await?;
+        self.link_committed_postings().await;
+        Ok(())
+    }
+
+    async fn get_account(&self, account_id: &Uuid) -> Result<Option<Account>, LedgerError> {
+        let account = sqlx::query_as!(
+            Account,
+            r#"
+            SELECT id, account_type as "account_type: _", currency, created_at, metadata
+            FROM accounts WHERE id = $1
+            "#,
+            account_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(account)
+    }
+
+    async fn list_accounts(&self) -> Result<Vec<Account>, LedgerError> {
+        let accounts = sqlx::query_as!(
+            Account,
+            r#"
+            SELECT id, account_type as "account_type: _", currency, created_at, metadata
+            FROM accounts
+            ORDER BY created_at, id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(accounts)
+    }
+
+    async fn get_account_balance(&self, account_id: &Uuid) -> Result<Decimal, LedgerError> {
+        // Sum over shards; an unsharded account has only shard 0
+        let result = sqlx::query!(
+            "SELECT SUM(balance) as balance FROM account_current_balances WHERE account_id = $1",
+            account_id
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        
+        Ok(result.balance.unwrap_or(Decimal::ZERO))
+    }
+
+    async fn get_account_balances(&self, account_ids: &[Uuid]) -> Result<HashMap<Uuid, Decimal>, LedgerError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT account_id, SUM(balance) as "balance!"
+            FROM account_current_balances
+            WHERE account_id = ANY($1)
+            GROUP BY account_id
+            "#,
+            account_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| (row.account_id, row.balance)).collect())
+    }
+
+    async fn get_account_balance_shards(&self, account_id: &Uuid) -> Result<i32, LedgerError> {
+        sqlx::query_scalar!("SELECT balance_shards FROM accounts WHERE id = $1", account_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or(LedgerError::AccountNotFound)
+    }
+
+    async fn set_account_balance_shards(&self, account_id: &Uuid, shards: i32) -> Result<(), LedgerError> {
+        if !(1..=MAX_BALANCE_SHARDS).contains(&shards) {
+            return Err(LedgerError::InvalidShardCount { shards, max: MAX_BALANCE_SHARDS });
+        }
+
+        let updated = sqlx::query!(
+            "UPDATE accounts SET balance_shards = $2 WHERE id = $1",
+            account_id,
+            shards
+        )
+        .execute(&self.pool)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Err(LedgerError::AccountNotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn get_account_balance_at(
+        &self,
+        account_id: &Uuid,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Decimal, LedgerError> {
+        // Latest checkpoint at or before as_of, plus whatever was posted
+        // between that checkpoint and as_of
+        let result = sqlx::query!(
+            r#"
+            WITH checkpoint AS (
+                SELECT checkpoint_at, balance
+                FROM balance_checkpoints
+                WHERE account_id = $1 AND checkpoint_at <= $2
+                ORDER BY checkpoint_at DESC
+                LIMIT 1
+            )
+            SELECT COALESCE((SELECT balance FROM checkpoint), 0) + COALESCE((
+                SELECT SUM(
+                    CASE 
+                        WHEN entry_type = 'Credit' THEN amount
+                        ELSE -amount
+                    END
+                )
+                FROM entries
+                WHERE account_id = $1
+                  AND timestamp > COALESCE((SELECT checkpoint_at FROM checkpoint), '-infinity'::timestamptz)
+                  AND timestamp <= $2
+            ), 0) as "balance!"
+            "#,
+            account_id,
+            as_of
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        
+        Ok(result.balance)
+    }
+
+    async fn get_account_shard_balances_at(
+        &self,
+        account_id: &Uuid,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<HashMap<i32, Decimal>, LedgerError> {
+        // Shards other than 0 are summed from their entries, which the
+        // partial index keeps cheap; shard 0 is the remainder of the
+        // checkpointed account balance
+        let mut balances: HashMap<i32, Decimal> = sqlx::query!(
+            r#"
+            SELECT balance_shard, SUM(
+                CASE
+                    WHEN entry_type = 'Credit' THEN amount
+                    ELSE -amount
+                END
+            ) as "balance!"
+            FROM entries
+            WHERE account_id = $1 AND balance_shard <> 0 AND timestamp <= $2
+            GROUP BY balance_shard
+            "#,
+            account_id,
+            as_of
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.balance_shard, row.balance))
+        .collect();
+
+        let total = self.get_account_balance_at(account_id, as_of).await?;
+        let sharded: Decimal = balances.values().copied().sum();
+        balances.insert(0, total - sharded);
+        Ok(balances)
+    }
+
+    async fn create_balance_checkpoints(
+        &self,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<u64, LedgerError> {
+        // Roll each account forward from its previous checkpoint; accounts
+        // with no activity since then keep their existing checkpoint
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO balance_checkpoints (account_id, checkpoint_at, balance, entry_count)
+            SELECT a.id, $1,
+                   COALESCE(cp.balance, 0) + COALESCE(SUM(
+                       CASE 
+                           WHEN e.entry_type = 'Credit' THEN e.amount
+                           ELSE -e.amount
+                       END
+                   ), 0),
+                   COALESCE(cp.entry_count, 0) + COUNT(e.id)
+            FROM accounts a
+            LEFT JOIN LATERAL (
+                SELECT checkpoint_at, balance, entry_count
+                FROM balance_checkpoints c
+                WHERE c.account_id = a.id AND c.checkpoint_at <= $1
+                ORDER BY c.checkpoint_at DESC
+                LIMIT 1
+            ) cp ON TRUE
+            JOIN entries e ON e.account_id = a.id
+                AND e.timestamp > COALESCE(cp.checkpoint_at, '-infinity'::timestamptz)
+                AND e.timestamp <= $1
+            GROUP BY a.id, cp.balance, cp.entry_count
+            ON CONFLICT (account_id, checkpoint_at) DO NOTHING
+            "#,
+            as_of
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn record_transaction(
+        &self,
+        transaction: &Transaction,
+        entries: &[Entry],
+    ) -> Result<(), LedgerError> {
+        self.write_transaction(transaction, entries, false).await
+    }
+
+    async fn record_transaction_checked(
+        &self,
+        transaction: &Transaction,
+        entries: &[Entry],
+    ) -> Result<(), LedgerError> {
+        self.write_transaction(transaction, entries, true).await
+    }
+
+    async fn record_batch(
+        &self,
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError> {
+        self.write_items(&[], items, mode, false).await
+    }
+
+    async fn record_batch_checked(
+        &self,
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError> {
+        self.write_items(&[], items, mode, true).await
+    }
+
+    async fn record_batch_with_accounts(
+        &self,
+        accounts: &[Account],
+        items: &[(Transaction, Vec<Entry>)],
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError> {
+        self.write_items(accounts, items, mode, false).await
+    }
+
+    async fn get_transaction(&self, transaction_id: &Uuid) -> Result<Option<Transaction>, LedgerError> {
+        let transaction = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT id, transaction_type as "transaction_type: _", amount,
+                   source_account_id, destination_account_id, timestamp,
+                   reason_code, metadata, idempotency_key
+            FROM transactions WHERE id = $1
+            "#,
+            transaction_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(transaction)
+    }
+
+    async fn get_transaction_by_key(&self, idempotency_key: &str) -> Result<Option<Transaction>, LedgerError> {
+        let transaction = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT id, transaction_type as "transaction_type: _", amount,
+                   source_account_id, destination_account_id, timestamp,
+                   reason_code, metadata, idempotency_key
+            FROM transactions WHERE idempotency_key = $1
+            "#,
+            idempotency_key
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        
+        Ok(transaction)
+    }
+
+    async fn get_transactions_by_keys(&self, idempotency_keys: &[String]) -> Result<Vec<Transaction>, LedgerError> {
+        let transactions = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT id, transaction_type as "transaction_type: _", amount,
+                   source_account_id, destination_account_id, timestamp,
+                   reason_code, metadata, idempotency_key
+            FROM transactions WHERE idempotency_key = ANY($1)
+            "#,
+            idempotency_keys
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(transactions)
+    }
+
+    async fn find_reversal(&self, transaction_id: &Uuid) -> Result<Option<Transaction>, LedgerError> {
+        let transaction = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT id, transaction_type as "transaction_type: _", amount,
+                   source_account_id, destination_account_id, timestamp,
+                   reason_code, metadata, idempotency_key
+            FROM transactions
+            WHERE metadata ? 'reverses' AND metadata->>'reverses' = $1
+            "#,
+            transaction_id.to_string()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(transaction)
+    }
+
+    async fn get_account_transactions(
+        &self,
         account_id: &Uuid,
         limit: i64,
         offset: i64,
@@ -237,6 +1653,55 @@ impl LedgerStore for PostgresLedgerStore {
         Ok(transactions)
     }
 
+    async fn get_account_transactions_page(
+        &self,
+        account_id: &Uuid,
+        filter: &TransactionFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<Transaction>, LedgerError> {
+        let transactions = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT t.id, t.transaction_type as "transaction_type: _", t.amount,
+                   t.source_account_id, t.destination_account_id, t.timestamp,
+                   t.reason_code, t.metadata, t.idempotency_key
+            FROM transactions t
+            WHERE EXISTS (
+                    SELECT 1 FROM entries e
+                    WHERE e.transaction_id = t.id AND e.account_id = $1
+                )
+              AND ($2::timestamptz IS NULL OR t.timestamp > $2)
+              AND ($3::timestamptz IS NULL OR t.timestamp <= $3)
+              AND ($4::varchar IS NULL OR t.transaction_type = $4)
+              AND ($5::varchar IS NULL OR t.reason_code = $5)
+              AND ($6::numeric IS NULL OR t.amount >= $6)
+              AND ($7::numeric IS NULL OR t.amount <= $7)
+              AND ($8::varchar IS NULL
+                   OR ($8 = 'incoming' AND t.destination_account_id = $1)
+                   OR ($8 = 'outgoing' AND t.source_account_id = $1))
+              AND ($9::timestamptz IS NULL OR (t.timestamp, t.id) < ($9, $10::uuid))
+            ORDER BY t.timestamp DESC, t.id DESC
+            LIMIT $11
+            "#,
+            account_id,
+            filter.from,
+            filter.to,
+            filter.transaction_type.map(|t| t.as_str()),
+            filter.reason_code.as_deref(),
+            filter.min_amount,
+            filter.max_amount,
+            filter.direction.map(|d| d.as_str()),
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id),
+            limit + 1
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Page::from_rows(transactions, limit, |t| Cursor::new(t.timestamp, t.id)))
+    }
+
     async fn get_entries_for_transaction(
         &self,
         transaction_id: &Uuid,
@@ -245,7 +1710,7 @@ impl LedgerStore for PostgresLedgerStore {
             Entry,
             r#"
             SELECT id, transaction_id, account_id, amount,
-                   entry_type as "entry_type: _", timestamp, balance_after
+                   entry_type as "entry_type: _", timestamp, balance_after, balance_shard
             FROM entries WHERE transaction_id = $1
             ORDER BY timestamp
             "#,
@@ -256,5 +1721,754 @@ impl LedgerStore for PostgresLedgerStore {
         
         Ok(entries)
     }
+
+    async fn get_account_totals(
+        &self,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        as_of: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+    ) -> Result<Vec<AccountTotals>, LedgerError> {
+        let totals = sqlx::query_as!(
+            AccountTotals,
+            r#"
+            SELECT a.id as account_id, a.account_type as "account_type: _",
+                   a.metadata->>'code' as code,
+                   a.metadata->>'parent_code' as parent_code,
+                   a.metadata->>'name' as name,
+                   COALESCE(SUM(CASE WHEN e.entry_type = 'Debit' THEN e.amount END), 0) as "debit_total!",
+                   COALESCE(SUM(CASE WHEN e.entry_type = 'Credit' THEN e.amount END), 0) as "credit_total!"
+            FROM accounts a
+            LEFT JOIN entries e ON e.account_id = a.id
+                AND e.timestamp <= $1
+                AND ($3::TIMESTAMPTZ IS NULL OR e.timestamp > $3)
+            WHERE a.currency = $2
+            GROUP BY a.id, a.account_type, a.metadata
+            ORDER BY a.account_type, a.id
+            "#,
+            as_of,
+            currency,
+            from
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(totals)
+    }
+
+    async fn get_account_entries_between(
+        &self,
+        account_id: &Uuid,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<AccountEntry>, LedgerError> {
+        let rows = sqlx::query_as!(
+            AccountEntryRow,
+            r#"
+            SELECT e.id, e.transaction_id, e.account_id, e.amount,
+                   e.entry_type as "entry_type: _", e.timestamp, e.balance_after, e.balance_shard,
+                   t.transaction_type as "transaction_type: _",
+                   t.amount as transaction_amount, t.source_account_id,
+                   t.destination_account_id, t.reason_code,
+                   t.timestamp as transaction_timestamp
+            FROM entries e
+            JOIN transactions t ON t.id = e.transaction_id
+            WHERE e.account_id = $1 AND e.timestamp > $2 AND e.timestamp <= $3
+            ORDER BY e.timestamp, e.id
+            "#,
+            account_id,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(AccountEntry::from).collect())
+    }
+
+    async fn get_account_entries(
+        &self,
+        account_id: &Uuid,
+        filter: &EntryFilter,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<AccountEntry>, LedgerError> {
+        // The keyset comparison and sort direction flip together, so each
+        // order gets its own statement rather than a CASE in ORDER BY that
+        // would defeat the (account_id, timestamp, id) index
+        let rows = match filter.order {
+            SortOrder::NewestFirst => {
+                sqlx::query_as!(
+                    AccountEntryRow,
+                    r#"
+                    SELECT e.id, e.transaction_id, e.account_id, e.amount,
+                           e.entry_type as "entry_type: _", e.timestamp, e.balance_after, e.balance_shard,
+                           t.transaction_type as "transaction_type: _",
+                           t.amount as transaction_amount, t.source_account_id,
+                           t.destination_account_id, t.reason_code,
+                           t.timestamp as transaction_timestamp
+                    FROM entries e
+                    JOIN transactions t ON t.id = e.transaction_id
+                    WHERE e.account_id = $1
+                      AND ($2::timestamptz IS NULL OR e.timestamp > $2)
+                      AND ($3::timestamptz IS NULL OR e.timestamp <= $3)
+                      AND ($4::varchar IS NULL OR e.entry_type = $4)
+                      AND ($5::varchar IS NULL OR t.transaction_type = $5)
+                      AND ($6::varchar IS NULL OR t.reason_code = $6)
+                      AND ($7::numeric IS NULL OR e.amount >= $7)
+                      AND ($8::numeric IS NULL OR e.amount <= $8)
+                      AND ($9::timestamptz IS NULL OR (e.timestamp, e.id) < ($9, $10::uuid))
+                    ORDER BY e.timestamp DESC, e.id DESC
+                    LIMIT $11
+                    "#,
+                    account_id,
+                    filter.from,
+                    filter.to,
+                    filter.entry_type.map(|t| t.as_str()),
+                    filter.transaction_type.map(|t| t.as_str()),
+                    filter.reason_code.as_deref(),
+                    filter.min_amount,
+                    filter.max_amount,
+                    cursor.map(|c| c.timestamp),
+                    cursor.map(|c| c.id),
+                    limit + 1
+                )
+                .fetch_all(&self.pool)
+                .await?
+            }
+            SortOrder::OldestFirst => {
+                sqlx::query_as!(
+                    AccountEntryRow,
+                    r#"
+                    SELECT e.id, e.transaction_id, e.account_id, e.amount,
+                           e.entry_type as "entry_type: _", e.timestamp, e.balance_after, e.balance_shard,
+                           t.transaction_type as "transaction_type: _",
+                           t.amount as transaction_amount, t.source_account_id,
+                           t.destination_account_id, t.reason_code,
+                           t.timestamp as transaction_timestamp
+                    FROM entries e
+                    JOIN transactions t ON t.id = e.transaction_id
+                    WHERE e.account_id = $1
+                      AND ($2::timestamptz IS NULL OR e.timestamp > $2)
+                      AND ($3::timestamptz IS NULL OR e.timestamp <= $3)
+                      AND ($4::varchar IS NULL OR e.entry_type = $4)
+                      AND ($5::varchar IS NULL OR t.transaction_type = $5)
+                      AND ($6::varchar IS NULL OR t.reason_code = $6)
+                      AND ($7::numeric IS NULL OR e.amount >= $7)
+                      AND ($8::numeric IS NULL OR e.amount <= $8)
+                      AND ($9::timestamptz IS NULL OR (e.timestamp, e.id) > ($9, $10::uuid))
+                    ORDER BY e.timestamp ASC, e.id ASC
+                    LIMIT $11
+                    "#,
+                    account_id,
+                    filter.from,
+                    filter.to,
+                    filter.entry_type.map(|t| t.as_str()),
+                    filter.transaction_type.map(|t| t.as_str()),
+                    filter.reason_code.as_deref(),
+                    filter.min_amount,
+                    filter.max_amount,
+                    cursor.map(|c| c.timestamp),
+                    cursor.map(|c| c.id),
+                    limit + 1
+                )
+                .fetch_all(&self.pool)
+                .await?
+            }
+        };
+
+        let entries = rows.into_iter().map(AccountEntry::from).collect();
+        Ok(Page::from_rows(entries, limit, |e: &AccountEntry| {
+            Cursor::new(e.entry.timestamp, e.entry.id)
+        }))
+    }
+
+    async fn get_transaction_leg_totals(
+        &self,
+        cursor: Option<&Cursor>,
+        limit: i64,
+    ) -> Result<Page<TransactionLegTotals>, LedgerError> {
+        let totals = sqlx::query_as!(
+            TransactionLegTotals,
+            r#"
+            SELECT t.id as transaction_id, t.transaction_type as "transaction_type: _",
+                   t.timestamp, t.amount,
+                   COUNT(e.id) as "entry_count!",
+                   COALESCE(SUM(CASE WHEN e.entry_type = 'Debit' THEN e.amount END), 0) as "debit_total!",
+                   COALESCE(SUM(CASE WHEN e.entry_type = 'Credit' THEN e.amount END), 0) as "credit_total!"
+            FROM transactions t
+            LEFT JOIN entries e ON e.transaction_id = t.id
+            WHERE ($1::timestamptz IS NULL OR (t.timestamp, t.id) > ($1, $2::uuid))
+            GROUP BY t.id, t.transaction_type, t.timestamp, t.amount
+            ORDER BY t.timestamp, t.id
+            LIMIT $3
+            "#,
+            cursor.map(|c| c.timestamp),
+            cursor.map(|c| c.id),
+            limit + 1
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Page::from_rows(totals, limit, |t: &TransactionLegTotals| {
+            Cursor::new(t.timestamp, t.transaction_id)
+        }))
+    }
+
+    async fn find_orphan_entries(&self) -> Result<Vec<Entry>, LedgerError> {
+        let entries = sqlx::query_as!(
+            Entry,
+            r#"
+            SELECT e.id, e.transaction_id, e.account_id, e.amount,
+                   e.entry_type as "entry_type: _", e.timestamp, e.balance_after, e.balance_shard
+            FROM entries e
+            LEFT JOIN transactions t ON t.id = e.transaction_id
+            WHERE t.id IS NULL
+            ORDER BY e.timestamp, e.id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    async fn get_chain_links(&self, after_seq: i64, limit: i64) -> Result<Vec<ChainLink>, LedgerError> {
+        let links = sqlx::query_as!(
+            ChainLink,
+            r#"
+            SELECT seq, transaction_id, prev_hash, hash, created_at
+            FROM transaction_chain
+            WHERE seq > $1
+            ORDER BY seq
+            LIMIT $2
+            "#,
+            after_seq,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(links)
+    }
+
+    async fn find_unchained_transactions(&self) -> Result<Vec<Uuid>, LedgerError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT t.id
+            FROM transactions t
+            LEFT JOIN transaction_chain c ON c.transaction_id = t.id
+            LEFT JOIN pending_chain_links p ON p.transaction_id = t.id
+            WHERE c.seq IS NULL AND p.transaction_id IS NULL
+            ORDER BY t.timestamp, t.id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.id).collect())
+    }
+
+    async fn create_chain_anchor(&self) -> Result<Option<ChainAnchor>, LedgerError> {
+        // Anchors the current head; a no-op while the chain is empty
+        let anchor = sqlx::query_as!(
+            ChainAnchor,
+            r#"
+            INSERT INTO chain_anchors (seq, hash)
+            SELECT seq, hash FROM transaction_chain
+            ORDER BY seq DESC
+            LIMIT 1
+            RETURNING id, seq, hash, created_at
+            "#
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(anchor)
+    }
+
+    async fn list_chain_anchors(&self) -> Result<Vec<ChainAnchor>, LedgerError> {
+        let anchors = sqlx::query_as!(
+            ChainAnchor,
+            "SELECT id, seq, hash, created_at FROM chain_anchors ORDER BY id"
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(anchors)
+    }
+
+    async fn get_transactions_with_entries(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<(Transaction, Vec<Entry>)>, LedgerError> {
+        let transactions = sqlx::query_as!(
+            Transaction,
+            r#"
+            SELECT id, transaction_type as "transaction_type: _", amount,
+                   source_account_id, destination_account_id, timestamp,
+                   reason_code, metadata, idempotency_key
+            FROM transactions
+            WHERE timestamp > $1 AND timestamp <= $2
+            ORDER BY timestamp, id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let ids: Vec<Uuid> = transactions.iter().map(|t| t.id).collect();
+        let entries = sqlx::query_as!(
+            Entry,
+            r#"
+            SELECT id, transaction_id, account_id, amount,
+                   entry_type as "entry_type: _", timestamp, balance_after, balance_shard
+            FROM entries WHERE transaction_id = ANY($1)
+            ORDER BY timestamp, id
+            "#,
+            &ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut by_transaction: HashMap<Uuid, Vec<Entry>> = HashMap::new();
+        for entry in entries {
+            by_transaction.entry(entry.transaction_id).or_default().push(entry);
+        }
+
+        Ok(transactions
+            .into_iter()
+            .map(|transaction| {
+                let entries = by_transaction.remove(&transaction.id).unwrap_or_default();
+                (transaction, entries)
+            })
+            .collect())
+    }
+
+    async fn save_merkle_root(&self, root: &PublishedRoot) -> Result<(), LedgerError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO merkle_roots (period_start, period_end, tree_size, root, published_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            root.period_start,
+            root.period_end,
+            root.tree_size,
+            &root.root,
+            root.published_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_merkle_root(
+        &self,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Option<PublishedRoot>, LedgerError> {
+        let root = sqlx::query_as!(
+            PublishedRoot,
+            r#"
+            SELECT period_start, period_end, tree_size, root, published_at
+            FROM merkle_roots
+            WHERE period_start < $1 AND period_end >= $1
+            ORDER BY published_at DESC
+            LIMIT 1
+            "#,
+            timestamp
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(root)
+    }
+
+    async fn get_transaction_signature(
+        &self,
+        transaction_id: &Uuid,
+    ) -> Result<Option<TransactionSignature>, LedgerError> {
+        let signature = sqlx::query_as!(
+            TransactionSignature,
+            "SELECT key_id, signature FROM transaction_signatures WHERE transaction_id = $1",
+            transaction_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(signature)
+    }
+
+    async fn get_pending_events(&self, limit: i64) -> Result<Vec<EventRecord>, LedgerError> {
+        // Pick up events whose writer committed but failed to move them
+        self.append_outbox_events().await?;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT seq, id, occurred_at, attempts, payload
+            FROM ledger_events
+            WHERE delivered_at IS NULL AND dead_lettered_at IS NULL
+            ORDER BY seq
+            LIMIT $1
+            "#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let event = serde_json::from_value(row.payload)
+                    .map_err(|_| LedgerError::InvalidEventPayload { seq: row.seq })?;
+                Ok(EventRecord {
+                    seq: row.seq,
+                    id: row.id,
+                    occurred_at: row.occurred_at,
+                    attempts: row.attempts,
+                    event,
+                })
+            })
+            .collect()
+    }
+
+    async fn mark_event_delivered(&self, seq: i64) -> Result<(), LedgerError> {
+        sqlx::query!(
+            r#"
+            UPDATE ledger_events
+            SET delivered_at = NOW(), attempts = attempts + 1, last_error = NULL
+            WHERE seq = $1
+            "#,
+            seq
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn record_event_failure(&self, seq: i64, error: &str) -> Result<(), LedgerError> {
+        sqlx::query!(
+            "UPDATE ledger_events SET attempts = attempts + 1, last_error = $2 WHERE seq = $1",
+            seq,
+            error
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn dead_letter_event(&self, seq: i64, error: &str) -> Result<(), LedgerError> {
+        sqlx::query!(
+            r#"
+            UPDATE ledger_events
+            SET attempts = attempts + 1, last_error = $2, dead_lettered_at = NOW()
+            WHERE seq = $1
+            "#,
+            seq,
+            error
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn replay_dead_letter_events(&self) -> Result<u64, LedgerError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE ledger_events
+            SET dead_lettered_at = NULL, attempts = 0
+            WHERE dead_lettered_at IS NOT NULL AND delivered_at IS NULL
+            "#
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn create_webhook_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), LedgerError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_subscriptions (id, url, secret, event_types, active, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            subscription.id,
+            &subscription.url,
+            &subscription.secret,
+            &subscription.event_types,
+            subscription.active,
+            subscription.created_at
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, LedgerError> {
+        let subscriptions = sqlx::query_as!(
+            WebhookSubscription,
+            r#"
+            SELECT id, url, secret, event_types, active, created_at
+            FROM webhook_subscriptions
+            ORDER BY created_at, id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(subscriptions)
+    }
+
+    async fn enqueue_webhook_deliveries(&self, event: &EventRecord) -> Result<u64, LedgerError> {
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (id, subscription_id, event_seq)
+            SELECT gen_random_uuid(), s.id, $1
+            FROM webhook_subscriptions s
+            WHERE s.active
+              AND (cardinality(s.event_types) = 0 OR $2 = ANY(s.event_types))
+            ON CONFLICT (subscription_id, event_seq) DO NOTHING
+            "#,
+            event.seq,
+            event.event.event_type()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        limit: i64,
+        lease: std::time::Duration,
+    ) -> Result<Vec<WebhookDelivery>, LedgerError> {
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            WITH claimed AS (
+                UPDATE webhook_deliveries
+                SET next_attempt_at = NOW() + make_interval(secs => $2)
+                WHERE id IN (
+                    SELECT id FROM webhook_deliveries
+                    WHERE status = 'Pending' AND next_attempt_at <= NOW()
+                    ORDER BY event_seq
+                    LIMIT $1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING *
+            )
+            SELECT c.id AS "id!", c.subscription_id AS "subscription_id!", s.url, s.secret,
+                   c.status AS "status!", c.attempts AS "attempts!",
+                   c.next_attempt_at AS "next_attempt_at!", c.last_status_code, c.last_error,
+                   e.seq, e.id AS event_id, e.occurred_at, e.attempts AS event_attempts, e.payload
+            FROM claimed c
+            JOIN webhook_subscriptions s ON s.id = c.subscription_id
+            JOIN ledger_events e ON e.seq = c.event_seq
+            ORDER BY e.seq
+            "#,
+            limit,
+            lease.as_secs_f64()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    async fn record_webhook_attempt(
+        &self,
+        delivery_id: &Uuid,
+        attempt: &WebhookAttempt,
+    ) -> Result<(), LedgerError> {
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2,
+                attempts = attempts + 1,
+                next_attempt_at = $3,
+                last_status_code = $4,
+                last_error = $5,
+                delivered_at = CASE WHEN $2::VARCHAR = 'Delivered' THEN NOW() ELSE delivered_at END
+            WHERE id = $1
+            "#,
+            delivery_id,
+            attempt.status.as_str(),
+            attempt.next_attempt_at,
+            attempt.status_code,
+            attempt.error.as_deref()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_webhook_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        status: Option<DeliveryStatus>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, LedgerError> {
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            SELECT d.id, d.subscription_id, s.url, s.secret, d.status, d.attempts,
+                   d.next_attempt_at, d.last_status_code, d.last_error,
+                   e.seq, e.id AS event_id, e.occurred_at, e.attempts AS event_attempts, e.payload
+            FROM webhook_deliveries d
+            JOIN webhook_subscriptions s ON s.id = d.subscription_id
+            JOIN ledger_events e ON e.seq = d.event_seq
+            WHERE d.subscription_id = $1
+              AND ($2::VARCHAR IS NULL OR d.status = $2)
+            ORDER BY e.seq
+            LIMIT $3
+            "#,
+            subscription_id,
+            status.map(|status| status.as_str()),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    async fn replay_webhook_deliveries(
+        &self,
+        subscription_id: &Uuid,
+        delivery_id: Option<&Uuid>,
+    ) -> Result<u64, LedgerError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = 'Pending', attempts = 0, next_attempt_at = NOW(),
+                last_status_code = NULL, last_error = NULL
+            WHERE subscription_id = $1
+              AND CASE WHEN $2::UUID IS NULL THEN status = 'DeadLetter' ELSE id = $2 END
+            "#,
+            subscription_id,
+            delivery_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn subscribe_account_updates(
+        &self,
+        account_id: &Uuid,
+    ) -> Result<AccountUpdateStream, LedgerError> {
+        let broadcaster = self
+            .updates
+            .get_or_try_init(|| self.start_update_listener())
+            .await?;
+        Ok(broadcaster.subscribe(*account_id))
+    }
+}
+
+// Flat row shape shared by the entry history queries
+struct AccountEntryRow {
+    id: Uuid,
+    transaction_id: Uuid,
+    account_id: Uuid,
+    amount: Decimal,
+    entry_type: EntryType,
+    timestamp: chrono::DateTime<chrono::Utc>,
+    balance_after: Decimal,
+    balance_shard: i32,
+    transaction_type: TransactionType,
+    transaction_amount: Decimal,
+    source_account_id: Option<Uuid>,
+    destination_account_id: Option<Uuid>,
+    reason_code: String,
+    transaction_timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<AccountEntryRow> for AccountEntry {
+    fn from(row: AccountEntryRow) -> Self {
+        AccountEntry {
+            entry: Entry {
+                id: row.id,
+                transaction_id: row.transaction_id,
+                account_id: row.account_id,
+                amount: row.amount,
+                entry_type: row.entry_type,
+                timestamp: row.timestamp,
+                balance_after: row.balance_after,
+                balance_shard: row.balance_shard,
+            },
+            transaction: TransactionSummary {
+                id: row.transaction_id,
+                transaction_type: row.transaction_type,
+                amount: row.transaction_amount,
+                source_account_id: row.source_account_id,
+                destination_account_id: row.destination_account_id,
+                reason_code: row.reason_code,
+                timestamp: row.transaction_timestamp,
+            },
+        }
+    }
+}
+
+// Flat row shape shared by the webhook delivery queries
+struct WebhookDeliveryRow {
+    id: Uuid,
+    subscription_id: Uuid,
+    url: String,
+    secret: String,
+    status: String,
+    attempts: i32,
+    next_attempt_at: chrono::DateTime<chrono::Utc>,
+    last_status_code: Option<i32>,
+    last_error: Option<String>,
+    seq: i64,
+    event_id: Uuid,
+    occurred_at: chrono::DateTime<chrono::Utc>,
+    event_attempts: i32,
+    payload: serde_json::Value,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = LedgerError;
+
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        let event = serde_json::from_value(row.payload)
+            .map_err(|_| LedgerError::InvalidEventPayload { seq: row.seq })?;
+        // The CHECK constraint on webhook_deliveries.status rules out
+        // anything else
+        let status = match row.status.as_str() {
+            "Delivered" => DeliveryStatus::Delivered,
+            "DeadLetter" => DeliveryStatus::DeadLetter,
+            _ => DeliveryStatus::Pending,
+        };
+
+        Ok(WebhookDelivery {
+            id: row.id,
+            subscription_id: row.subscription_id,
+            url: row.url,
+            secret: row.secret,
+            status,
+            attempts: row.attempts,
+            next_attempt_at: row.next_attempt_at,
+            last_status_code: row.last_status_code,
+            last_error: row.last_error,
+            event: EventRecord {
+                seq: row.seq,
+                id: row.event_id,
+                occurred_at: row.occurred_at,
+                attempts: row.event_attempts,
+                event,
+            },
+        })
+    }
 }
 ```
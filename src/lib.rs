@@ -7,12 +7,48 @@ pub mod transaction;
 pub mod entry;
 pub mod ledger_store;
 pub mod reconciliation;
+pub mod financial_statements;
+pub mod account_statement;
+pub mod pagination;
+pub mod integrity;
+pub mod hash_chain;
+pub mod merkle;
+pub mod signing;
+pub mod events;
+pub mod webhooks;
+pub mod subscriptions;
+pub mod batch;
+pub mod csv_import;
+pub mod journal_export;
+pub mod journal_import;
+pub mod bank_statement;
+pub mod migrate;
+#[cfg(feature = "http")]
+pub mod http;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 
 pub use account::*;
 pub use transaction::*;
 pub use entry::*;
 pub use ledger_store::*;
 pub use reconciliation::*;
+pub use financial_statements::*;
+pub use account_statement::*;
+pub use pagination::*;
+pub use integrity::*;
+pub use hash_chain::*;
+pub use merkle::*;
+pub use signing::*;
+pub use events::*;
+pub use webhooks::*;
+pub use subscriptions::*;
+pub use batch::*;
+pub use csv_import::*;
+pub use journal_export::*;
+pub use journal_import::*;
+pub use bank_statement::*;
+pub use migrate::{migrate, check_schema};
 
 pub struct LedgerService {
     store: Box<dyn LedgerStore>,
@@ -33,6 +69,25 @@ impl LedgerService {
         Ok(account)
     }
 
+    pub async fn create_account_with_metadata(
+        &self,
+        account_type: AccountType,
+        currency: &str,
+        metadata: serde_json::Value,
+    ) -> Result<Account, LedgerError> {
+        let mut account = Account::new(account_type, currency);
+        account.metadata = metadata;
+        self.store.create_account(&account).await?;
+        Ok(account)
+    }
+
+    pub async fn get_account(&self, account_id: Uuid) -> Result<Account, LedgerError> {
+        self.store
+            .get_account(&account_id)
+            .await?
+            .ok_or(LedgerError::AccountNotFound)
+    }
+
     pub async fn credit_account(
         &self,
         account_id: Uuid,
@@ -52,7 +107,7 @@ impl LedgerService {
         transaction.validate()?;
 
         // Create entries
-        let entries = self.create_credit_entries(&transaction).await?;
+        let entries = self.create_credit_entries(&transaction);
         
         // Record transaction
         self.store.record_transaction(&transaction, &entries).await?;
@@ -60,6 +115,39 @@ impl LedgerService {
         Ok(transaction)
     }
 
+    pub async fn debit_account(
+        &self,
+        account_id: Uuid,
+        amount: rust_decimal::Decimal,
+        reason_code: &str,
+        idempotency_key: &str,
+    ) -> Result<Transaction, LedgerError> {
+        let transaction = Transaction::new(
+            TransactionType::Debit,
+            amount,
+            Some(account_id),
+            None,
+            reason_code,
+            idempotency_key,
+        );
+
+        transaction.validate()?;
+
+        // Create entries; the store fills in balance_after
+        let entries = vec![Entry::new(
+            transaction.id,
+            account_id,
+            transaction.amount,
+            EntryType::Debit,
+            rust_decimal::Decimal::ZERO,
+        )];
+
+        // Record transaction; the store checks the balance under its row lock
+        self.store.record_transaction_checked(&transaction, &entries).await?;
+
+        Ok(transaction)
+    }
+
     pub async fn transfer(
         &self,
         from_account_id: Uuid,
@@ -68,12 +156,6 @@ impl LedgerService {
         reason_code: &str,
         idempotency_key: &str,
     ) -> Result<Transaction, LedgerError> {
-        // Check source balance
-        let source_balance = self.store.get_account_balance(&from_account_id).await?;
-        if source_balance < amount {
-            return Err(LedgerError::InsufficientBalance);
-        }
-
         let transaction = Transaction::new(
             TransactionType::Transfer,
             amount,
@@ -86,69 +168,155 @@ impl LedgerService {
         transaction.validate()?;
 
         // Create entries
-        let entries = self.create_transfer_entries(&transaction).await?;
+        let entries = self.create_transfer_entries(&transaction);
         
-        // Record transaction
-        self.store.record_transaction(&transaction, &entries).await?;
+        // Record transaction; the store checks the source balance under its
+        // row lock
+        self.store.record_transaction_checked(&transaction, &entries).await?;
         
         Ok(transaction)
     }
 
-    async fn create_credit_entries(
+    // Posts the mirror image of an earlier transaction: every entry is
+    // replayed on the opposite side, and the original is left untouched
+    pub async fn reverse_transaction(
         &self,
-        transaction: &Transaction,
-    ) -> Result<Vec<Entry>, LedgerError> {
+        transaction_id: Uuid,
+        reason_code: &str,
+        idempotency_key: &str,
+    ) -> Result<Transaction, LedgerError> {
+        let original = self
+            .store
+            .get_transaction(&transaction_id)
+            .await?
+            .ok_or(LedgerError::TransactionNotFound)?;
+        // Undoing a reversal would re-post the original; post it anew instead
+        if original.transaction_type == TransactionType::Reversal {
+            return Err(LedgerError::CannotReverseReversal);
+        }
+        // Also enforced by a unique index, for reversals racing this check
+        if let Some(reversal) = self.store.find_reversal(&transaction_id).await? {
+            return Err(LedgerError::AlreadyReversed { reversal_id: reversal.id });
+        }
+        let original_entries = self.store.get_entries_for_transaction(&transaction_id).await?;
+
+        let mut transaction = Transaction::new(
+            TransactionType::Reversal,
+            original.amount,
+            original.destination_account_id,
+            original.source_account_id,
+            reason_code,
+            idempotency_key,
+        );
+        transaction.metadata = serde_json::json!({ "reverses": original.id });
+
+        transaction.validate()?;
+
+        let entries: Vec<Entry> = original_entries
+            .iter()
+            .map(|entry| {
+                let entry_type = match entry.entry_type {
+                    EntryType::Debit => EntryType::Credit,
+                    EntryType::Credit => EntryType::Debit,
+                };
+                Entry::new(
+                    transaction.id,
+                    entry.account_id,
+                    entry.amount,
+                    entry_type,
+                    rust_decimal::Decimal::ZERO,
+                )
+            })
+            .collect();
+
+        self.store.record_transaction(&transaction, &entries).await?;
+
+        Ok(transaction)
+    }
+
+    // Bulk ingestion of prepared postings, e.g. nightly settlement files.
+    // Entries are written as given; no balance checks are made.
+    pub async fn record_batch(
+        &self,
+        items: Vec<(Transaction, Vec<Entry>)>,
+        mode: BatchMode,
+    ) -> Result<Vec<BatchItemOutcome>, LedgerError> {
+        self.store.record_batch(&items, mode).await
+    }
+
+    // Validates a CSV file of postings and, unless options.dry_run is set,
+    // posts the valid rows through record_batch
+    pub async fn import_csv<R: std::io::Read>(
+        &self,
+        reader: R,
+        options: &ImportOptions,
+    ) -> Result<ImportReport, LedgerError> {
+        csv_import::import_csv(self.store.as_ref(), reader, options).await
+    }
+
+    // Transactions in [from, to) as a ledger-cli, hledger or beancount journal
+    pub async fn export_journal(
+        &self,
+        format: JournalFormat,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+    ) -> Result<String, LedgerError> {
+        journal_export::export_journal(self.store.as_ref(), format, from, to).await
+    }
+
+    // Migrates a ledger-cli, hledger or beancount journal, creating the
+    // accounts it uses that do not exist yet
+    pub async fn import_journal(
+        &self,
+        format: JournalFormat,
+        text: &str,
+        options: &JournalImportOptions,
+    ) -> Result<JournalImportReport, LedgerError> {
+        journal_import::import_journal(self.store.as_ref(), format, text, options).await
+    }
+
+    // Entries are built without reading balances: the store computes each
+    // entry's balance_after under the balance row locks when it writes them
+    fn create_credit_entries(&self, transaction: &Transaction) -> Vec<Entry> {
         let mut entries = Vec::new();
         
         if let Some(dest_account_id) = transaction.destination_account_id {
-            let current_balance = self.store.get_account_balance(&dest_account_id).await?;
-            let new_balance = current_balance + transaction.amount;
-            
             entries.push(Entry::new(
                 transaction.id,
                 dest_account_id,
                 transaction.amount,
                 EntryType::Credit,
-                new_balance,
+                rust_decimal::Decimal::ZERO,
             ));
         }
         
-        Ok(entries)
+        entries
     }
 
-    async fn create_transfer_entries(
-        &self,
-        transaction: &Transaction,
-    ) -> Result<Vec<Entry>, LedgerError> {
+    fn create_transfer_entries(&self, transaction: &Transaction) -> Vec<Entry> {
         let mut entries = Vec::new();
         
         if let Some(source_account_id) = transaction.source_account_id {
-            let source_balance = self.store.get_account_balance(&source_account_id).await?;
-            let new_source_balance = source_balance - transaction.amount;
-            
             entries.push(Entry::new(
                 transaction.id,
                 source_account_id,
                 transaction.amount,
                 EntryType::Debit,
-                new_source_balance,
+                rust_decimal::Decimal::ZERO,
             ));
         }
         
         if let Some(dest_account_id) = transaction.destination_account_id {
-            let dest_balance = self.store.get_account_balance(&dest_account_id).await?;
-            let new_dest_balance = dest_balance + transaction.amount;
-            
             entries.push(Entry::new(
                 transaction.id,
                 dest_account_id,
                 transaction.amount,
                 EntryType::Credit,
-                new_dest_balance,
+                rust_decimal::Decimal::ZERO,
             ));
         }
         
-        Ok(entries)
+        entries
     }
 
     pub async fn get_account_balance(
@@ -158,6 +326,13 @@ impl LedgerService {
         self.store.get_account_balance(&account_id).await
     }
 
+    // For fee, settlement and other accounts that take part in most
+    // postings: spreads the balance row over `shards` rows so concurrent
+    // postings stop serializing on it. Reads still return one balance.
+    pub async fn set_balance_shards(&self, account_id: Uuid, shards: i32) -> Result<(), LedgerError> {
+        self.store.set_account_balance_shards(&account_id, shards).await
+    }
+
     pub async fn get_account_transactions(
         &self,
         account_id: Uuid,
@@ -166,6 +341,66 @@ impl LedgerService {
     ) -> Result<Vec<Transaction>, LedgerError> {
         self.store.get_account_transactions(&account_id, limit, offset).await
     }
+
+    // Keyset-paginated history, newest first. Pass the next_cursor of the
+    // previous page to continue; None starts from the most recent transaction.
+    pub async fn get_account_transactions_page(
+        &self,
+        account_id: Uuid,
+        filter: &TransactionFilter,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<Page<Transaction>, LedgerError> {
+        let cursor = cursor.map(Cursor::decode).transpose()?;
+        self.store
+            .get_account_transactions_page(&account_id, filter, cursor.as_ref(), limit)
+            .await
+    }
+
+    // Entry-level history: shows which side of each transaction the account
+    // was on without a per-transaction lookup
+    pub async fn get_account_entries(
+        &self,
+        account_id: Uuid,
+        filter: &EntryFilter,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<Page<AccountEntry>, LedgerError> {
+        let cursor = cursor.map(Cursor::decode).transpose()?;
+        self.store
+            .get_account_entries(&account_id, filter, cursor.as_ref(), limit)
+            .await
+    }
+
+    // Live stream of entries and balance changes for the account, starting
+    // with the first transaction committed after the call
+    pub async fn subscribe(&self, account_id: Uuid) -> Result<AccountUpdateStream, LedgerError> {
+        self.get_account(account_id).await?;
+        self.store.subscribe_account_updates(&account_id).await
+    }
+
+    pub async fn get_transaction_by_key(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<Transaction>, LedgerError> {
+        self.store.get_transaction_by_key(idempotency_key).await
+    }
+
+    pub async fn reconcile(
+        &self,
+        account_ids: &[Uuid],
+        expected_balances: Option<&[(Uuid, rust_decimal::Decimal)]>,
+    ) -> Result<ReconciliationReport, LedgerError> {
+        reconcile_accounts(self.store.as_ref(), account_ids, expected_balances).await
+    }
+
+    pub async fn trial_balance(
+        &self,
+        as_of: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+    ) -> Result<TrialBalance, LedgerError> {
+        trial_balance(self.store.as_ref(), as_of, currency).await
+    }
 }
 ```
 ```rust
@@ -0,0 +1,269 @@
+ledger/src/merkle.rs
+```rust
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+use crate::{hash_chain, Entry, LedgerError, LedgerStore, Transaction};
+
+// Tree construction follows RFC 6962: leaves and interior nodes are hashed
+// with distinct prefixes, and an n-leaf tree splits at the largest power of
+// two below n, so proofs can be checked with any standard verifier.
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+pub type MerkleHash = [u8; 32];
+
+pub fn leaf_hash(transaction: &Transaction, entries: &[Entry]) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update(hash_chain::canonical_bytes(transaction, entries));
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+pub struct MerkleTree {
+    leaves: Vec<MerkleHash>,
+}
+
+impl MerkleTree {
+    pub fn new(leaves: Vec<MerkleHash>) -> Self {
+        Self { leaves }
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    // Root of the empty tree is the hash of the empty string
+    pub fn root(&self) -> MerkleHash {
+        if self.leaves.is_empty() {
+            return Sha256::digest([]).into();
+        }
+        subtree_root(&self.leaves)
+    }
+
+    // Audit path for the leaf at index, ordered from the leaf up
+    pub fn proof(&self, index: usize) -> Option<Vec<MerkleHash>> {
+        if index >= self.leaves.len() {
+            return None;
+        }
+        let mut path = Vec::new();
+        audit_path(&self.leaves, index, &mut path);
+        Some(path)
+    }
+}
+
+fn split_point(n: usize) -> usize {
+    let mut k = 1;
+    while k << 1 < n {
+        k <<= 1;
+    }
+    k
+}
+
+fn subtree_root(leaves: &[MerkleHash]) -> MerkleHash {
+    if leaves.len() == 1 {
+        return leaves[0];
+    }
+    let k = split_point(leaves.len());
+    node_hash(&subtree_root(&leaves[..k]), &subtree_root(&leaves[k..]))
+}
+
+fn audit_path(leaves: &[MerkleHash], index: usize, path: &mut Vec<MerkleHash>) {
+    if leaves.len() <= 1 {
+        return;
+    }
+    let k = split_point(leaves.len());
+    if index < k {
+        audit_path(&leaves[..k], index, path);
+        path.push(subtree_root(&leaves[k..]));
+    } else {
+        audit_path(&leaves[k..], index - k, path);
+        path.push(subtree_root(&leaves[..k]));
+    }
+}
+
+// RFC 9162 section 2.1.3.2 inclusion verification
+pub fn verify_inclusion(
+    leaf: &MerkleHash,
+    leaf_index: u64,
+    tree_size: u64,
+    path: &[MerkleHash],
+    root: &MerkleHash,
+) -> bool {
+    if leaf_index >= tree_size {
+        return false;
+    }
+
+    let mut fn_ = leaf_index;
+    let mut sn = tree_size - 1;
+    let mut r = *leaf;
+
+    for p in path {
+        if sn == 0 {
+            return false;
+        }
+        if fn_ & 1 == 1 || fn_ == sn {
+            r = node_hash(p, &r);
+            if fn_ & 1 == 0 {
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            }
+        } else {
+            r = node_hash(&r, p);
+        }
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+
+    sn == 0 && r == *root
+}
+
+// A period's root, published so that proofs can be checked against it
+// without access to the rest of the ledger
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishedRoot {
+    pub period_start: chrono::DateTime<chrono::Utc>,
+    pub period_end: chrono::DateTime<chrono::Utc>,
+    pub tree_size: i64,
+    pub root: String,
+    pub published_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub transaction_id: Uuid,
+    pub period_start: chrono::DateTime<chrono::Utc>,
+    pub period_end: chrono::DateTime<chrono::Utc>,
+    pub leaf_index: u64,
+    pub tree_size: u64,
+    pub leaf_hash: String,
+    pub path: Vec<String>,
+    pub root: String,
+}
+
+impl InclusionProof {
+    // Checks the proof against the transaction as the third party holds it
+    // and the root they obtained from the published list. The proof's own
+    // root field is not trusted.
+    pub fn verify(&self, transaction: &Transaction, entries: &[Entry], published_root: &str) -> bool {
+        if transaction.id != self.transaction_id {
+            return false;
+        }
+
+        let Some(root) = decode_hash(published_root) else {
+            return false;
+        };
+        let path: Option<Vec<MerkleHash>> = self.path.iter().map(|h| decode_hash(h)).collect();
+        let Some(path) = path else {
+            return false;
+        };
+
+        let leaf = leaf_hash(transaction, entries);
+        verify_inclusion(&leaf, self.leaf_index, self.tree_size, &path, &root)
+    }
+}
+
+// Builds the tree over every transaction in (period_start, period_end],
+// ordered by (timestamp, id), and stores its root. The period must have
+// ended at least `settle` ago: a transaction landing in it after publication
+// would change the rebuilt root and leave every proof for it unobtainable.
+pub async fn publish_period_root(
+    store: &(impl LedgerStore + ?Sized),
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+    settle: chrono::Duration,
+) -> Result<PublishedRoot, LedgerError> {
+    let settled_by = chrono::Utc::now() - settle;
+    if period_end > settled_by {
+        return Err(LedgerError::PeriodNotSettled { period_end, settled_by });
+    }
+
+    let tree = period_tree(store, period_start, period_end).await?.0;
+
+    let published = PublishedRoot {
+        period_start,
+        period_end,
+        tree_size: tree.len() as i64,
+        root: hex::encode(tree.root()),
+        published_at: chrono::Utc::now(),
+    };
+    store.save_merkle_root(&published).await?;
+
+    Ok(published)
+}
+
+pub async fn inclusion_proof(
+    store: &(impl LedgerStore + ?Sized),
+    transaction_id: Uuid,
+) -> Result<InclusionProof, LedgerError> {
+    let transaction = store
+        .get_transaction(&transaction_id)
+        .await?
+        .ok_or(LedgerError::TransactionNotFound)?;
+    let published = store
+        .find_merkle_root(transaction.timestamp)
+        .await?
+        .ok_or(LedgerError::MerkleRootNotPublished)?;
+
+    let (tree, ids) = period_tree(store, published.period_start, published.period_end).await?;
+
+    // A rebuilt root that differs from the published one means the period's
+    // history changed after publication; a proof against it would be useless
+    if hex::encode(tree.root()) != published.root {
+        return Err(LedgerError::MerkleRootMismatch);
+    }
+
+    let leaf_index = ids
+        .iter()
+        .position(|id| *id == transaction_id)
+        .ok_or(LedgerError::TransactionNotFound)?;
+    let path = tree.proof(leaf_index).ok_or(LedgerError::TransactionNotFound)?;
+
+    Ok(InclusionProof {
+        transaction_id,
+        period_start: published.period_start,
+        period_end: published.period_end,
+        leaf_index: leaf_index as u64,
+        tree_size: tree.len() as u64,
+        leaf_hash: hex::encode(tree.leaves[leaf_index]),
+        path: path.iter().map(hex::encode).collect(),
+        root: published.root,
+    })
+}
+
+async fn period_tree(
+    store: &(impl LedgerStore + ?Sized),
+    period_start: chrono::DateTime<chrono::Utc>,
+    period_end: chrono::DateTime<chrono::Utc>,
+) -> Result<(MerkleTree, Vec<Uuid>), LedgerError> {
+    let transactions = store
+        .get_transactions_with_entries(period_start, period_end)
+        .await?;
+
+    let ids = transactions.iter().map(|(transaction, _)| transaction.id).collect();
+    let leaves = transactions
+        .iter()
+        .map(|(transaction, entries)| leaf_hash(transaction, entries))
+        .collect();
+
+    Ok((MerkleTree::new(leaves), ids))
+}
+
+fn decode_hash(encoded: &str) -> Option<MerkleHash> {
+    hex::decode(encoded).ok()?.try_into().ok()
+}
+```
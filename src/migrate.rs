@@ -0,0 +1,182 @@
+ledger/src/migrate.rs
+```rust
+use sha2::{Digest, Sha256};
+use sqlx::{Executor, PgPool};
+use crate::LedgerError;
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+// Every migration the library knows about, in order. Append new files here;
+// never edit or renumber one that has shipped, the runner will refuse to
+// start against a database where an applied migration's checksum changed.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, name: "initial_schema", sql: include_str!("../migrations/001_initial_schema.sql") },
+    Migration { version: 2, name: "transaction_pagination", sql: include_str!("../migrations/002_transaction_pagination.sql") },
+    Migration { version: 3, name: "entry_history", sql: include_str!("../migrations/003_entry_history.sql") },
+    Migration { version: 4, name: "balance_snapshots", sql: include_str!("../migrations/004_balance_snapshots.sql") },
+    Migration { version: 5, name: "balance_view_refreshes", sql: include_str!("../migrations/005_balance_view_refreshes.sql") },
+    Migration { version: 6, name: "transaction_hash_chain", sql: include_str!("../migrations/006_transaction_hash_chain.sql") },
+    Migration { version: 7, name: "append_only_history", sql: include_str!("../migrations/007_append_only_history.sql") },
+    Migration { version: 8, name: "merkle_roots", sql: include_str!("../migrations/008_merkle_roots.sql") },
+    Migration { version: 9, name: "transaction_signatures", sql: include_str!("../migrations/009_transaction_signatures.sql") },
+    Migration { version: 10, name: "ledger_events", sql: include_str!("../migrations/010_ledger_events.sql") },
+    Migration { version: 11, name: "webhooks", sql: include_str!("../migrations/011_webhooks.sql") },
+    Migration { version: 12, name: "balance_shards", sql: include_str!("../migrations/012_balance_shards.sql") },
+    Migration { version: 13, name: "single_reversal", sql: include_str!("../migrations/013_single_reversal.sql") },
+    Migration { version: 14, name: "chain_sequencer", sql: include_str!("../migrations/014_chain_sequencer.sql") },
+];
+
+// Schema version this build of the library reads and writes
+pub fn schema_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+// Serializes concurrent migrate() calls from instances starting together
+const MIGRATION_LOCK_KEY: i64 = 0x4c45_4447_4d49;
+
+// Applies every pending migration in a single database transaction and
+// returns the resulting schema version
+pub async fn migrate(pool: &PgPool) -> Result<i64, LedgerError> {
+    let mut db_transaction = pool.begin().await?;
+
+    sqlx::query!("SELECT pg_advisory_xact_lock($1)", MIGRATION_LOCK_KEY)
+        .execute(&mut *db_transaction)
+        .await?;
+
+    db_transaction
+        .execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS ledger_schema_migrations (
+                version BIGINT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL,
+                checksum BYTEA NOT NULL,
+                applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+            )
+            "#,
+        )
+        .await?;
+
+    let applied = sqlx::query!("SELECT version, checksum FROM ledger_schema_migrations ORDER BY version")
+        .fetch_all(&mut *db_transaction)
+        .await?;
+
+    let expected = schema_version();
+    if let Some(newest) = applied.last() {
+        if newest.version > expected {
+            return Err(LedgerError::SchemaVersionMismatch {
+                expected,
+                found: newest.version,
+            });
+        }
+    }
+
+    for row in &applied {
+        let migration = MIGRATIONS
+            .iter()
+            .find(|m| m.version == row.version)
+            .ok_or(LedgerError::MigrationChecksumMismatch { version: row.version })?;
+        if checksum(migration.sql) != row.checksum {
+            return Err(LedgerError::MigrationChecksumMismatch { version: row.version });
+        }
+    }
+
+    if applied.is_empty() {
+        // A schema created by running the SQL files by hand has no history;
+        // re-running 001 on it would fail halfway, so ask for a baseline
+        let existing = sqlx::query!("SELECT to_regclass('public.accounts') IS NOT NULL as \"exists!\"")
+            .fetch_one(&mut *db_transaction)
+            .await?;
+        if existing.exists {
+            return Err(LedgerError::UntrackedSchema);
+        }
+    }
+
+    let current = applied.last().map(|row| row.version).unwrap_or(0);
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        tracing::info!(version = migration.version, name = migration.name, "applying migration");
+
+        db_transaction.execute(migration.sql).await?;
+
+        sqlx::query!(
+            "INSERT INTO ledger_schema_migrations (version, name, checksum) VALUES ($1, $2, $3)",
+            migration.version,
+            migration.name,
+            checksum(migration.sql)
+        )
+        .execute(&mut *db_transaction)
+        .await?;
+    }
+
+    db_transaction.commit().await?;
+    Ok(expected)
+}
+
+// Records migrations up to and including version as applied without running
+// them, for databases that were set up by applying the SQL files manually
+pub async fn baseline(pool: &PgPool, version: i64) -> Result<(), LedgerError> {
+    let mut db_transaction = pool.begin().await?;
+
+    db_transaction
+        .execute(
+            r#"
+            CREATE TABLE IF NOT EXISTS ledger_schema_migrations (
+                version BIGINT PRIMARY KEY,
+                name VARCHAR(255) NOT NULL,
+                checksum BYTEA NOT NULL,
+                applied_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+            )
+            "#,
+        )
+        .await?;
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version <= version) {
+        sqlx::query!(
+            r#"
+            INSERT INTO ledger_schema_migrations (version, name, checksum)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (version) DO NOTHING
+            "#,
+            migration.version,
+            migration.name,
+            checksum(migration.sql)
+        )
+        .execute(&mut *db_transaction)
+        .await?;
+    }
+
+    db_transaction.commit().await?;
+    Ok(())
+}
+
+pub async fn applied_schema_version(pool: &PgPool) -> Result<Option<i64>, LedgerError> {
+    let exists = sqlx::query!("SELECT to_regclass('public.ledger_schema_migrations') IS NOT NULL as \"exists!\"")
+        .fetch_one(pool)
+        .await?;
+    if !exists.exists {
+        return Ok(None);
+    }
+
+    let row = sqlx::query!("SELECT MAX(version) as version FROM ledger_schema_migrations")
+        .fetch_one(pool)
+        .await?;
+    Ok(row.version)
+}
+
+// Fails unless the database is at exactly the version this library expects
+pub async fn check_schema(pool: &PgPool) -> Result<(), LedgerError> {
+    let expected = schema_version();
+    let found = applied_schema_version(pool).await?.unwrap_or(0);
+    if found != expected {
+        return Err(LedgerError::SchemaVersionMismatch { expected, found });
+    }
+    Ok(())
+}
+
+fn checksum(sql: &str) -> Vec<u8> {
+    Sha256::digest(sql.as_bytes()).to_vec()
+}
+```
@@ -0,0 +1,119 @@
+ledger/src/pagination.rs
+```rust
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{EntryType, LedgerError, TransactionType};
+
+// Keyset position: the (timestamp, id) of the last row on the previous page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn new(timestamp: chrono::DateTime<chrono::Utc>, id: Uuid) -> Self {
+        Self { timestamp, id }
+    }
+
+    // Cursors are handed to clients as opaque strings; the hex wrapping keeps
+    // them from being mistaken for something meaningful to construct by hand
+    pub fn encode(&self) -> String {
+        let raw = format!("{}:{}", self.timestamp.timestamp_micros(), self.id);
+        hex::encode(raw)
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, LedgerError> {
+        let bytes = hex::decode(encoded).map_err(|_| LedgerError::InvalidCursor)?;
+        let raw = String::from_utf8(bytes).map_err(|_| LedgerError::InvalidCursor)?;
+
+        let (micros, id) = raw.split_once(':').ok_or(LedgerError::InvalidCursor)?;
+        let micros: i64 = micros.parse().map_err(|_| LedgerError::InvalidCursor)?;
+        let timestamp = chrono::DateTime::from_timestamp_micros(micros)
+            .ok_or(LedgerError::InvalidCursor)?;
+        let id = Uuid::parse_str(id).map_err(|_| LedgerError::InvalidCursor)?;
+
+        Ok(Self { timestamp, id })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    // Builds a page from up to limit + 1 rows; the extra row only signals
+    // that another page exists and is dropped
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        limit: i64,
+        cursor_of: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let limit = limit.max(0) as usize;
+        let has_more = rows.len() > limit;
+        rows.truncate(limit);
+
+        let next_cursor = if has_more {
+            rows.last().map(|row| cursor_of(row).encode())
+        } else {
+            None
+        };
+
+        Self { items: rows, next_cursor }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Incoming,
+    Outgoing,
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Incoming => "incoming",
+            Direction::Outgoing => "outgoing",
+        }
+    }
+}
+
+// All bounds are optional. Like every period in the ledger, from is
+// exclusive and to is inclusive, so `to` of one range is `from` of the next.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransactionFilter {
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    pub transaction_type: Option<TransactionType>,
+    pub reason_code: Option<String>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    pub direction: Option<Direction>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+// Same bound semantics as TransactionFilter; amount bounds apply to the entry
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntryFilter {
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    pub entry_type: Option<EntryType>,
+    pub transaction_type: Option<TransactionType>,
+    pub reason_code: Option<String>,
+    pub min_amount: Option<Decimal>,
+    pub max_amount: Option<Decimal>,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+```
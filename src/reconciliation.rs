@@ -1,9 +1,11 @@
 ledger/src/reconciliation.rs
 ```rust
 use rust_decimal::Decimal;
+use serde::Serialize;
 use uuid::Uuid;
-use crate::{LedgerStore, LedgerError};
+use crate::{AccountType, LedgerStore, LedgerError};
 
+#[derive(Debug, Clone, Serialize)]
 pub struct ReconciliationReport {
     pub period_start: chrono::DateTime<chrono::Utc>,
     pub period_end: chrono::DateTime<chrono::Utc>,
@@ -13,6 +15,7 @@ pub struct ReconciliationReport {
     pub is_balanced: bool,
 }
 
+#[derive(Debug, Clone, Serialize)]
 pub struct AccountBalance {
     pub account_id: Uuid,
     pub calculated_balance: Decimal,
@@ -21,7 +24,7 @@ pub struct AccountBalance {
 }
 
 pub async fn reconcile_accounts(
-    store: &impl LedgerStore,
+    store: &(impl LedgerStore + ?Sized),
     account_ids: &[Uuid],
     expected_balances: Option<&[(Uuid, Decimal)]>,
 ) -> Result<ReconciliationReport, LedgerError> {
@@ -64,4 +67,107 @@ async fn verify_accounting_equation(balances: &[AccountBalance]) -> Result<bool,
     // Assets = Liabilities + Equity
     Ok(true)
 }
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountTotals {
+    pub account_id: Uuid,
+    pub account_type: AccountType,
+    pub code: Option<String>,
+    pub parent_code: Option<String>,
+    pub name: Option<String>,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrialBalance {
+    pub as_of: chrono::DateTime<chrono::Utc>,
+    pub currency: String,
+    pub lines: Vec<TrialBalanceLine>,
+    pub total_debits: Decimal,
+    pub total_credits: Decimal,
+    pub is_balanced: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrialBalanceLine {
+    pub account_id: Uuid,
+    pub account_type: AccountType,
+    pub debit_total: Decimal,
+    pub credit_total: Decimal,
+    pub net_balance: Decimal,
+}
+
+pub async fn trial_balance(
+    store: &(impl LedgerStore + ?Sized),
+    as_of: chrono::DateTime<chrono::Utc>,
+    currency: &str,
+) -> Result<TrialBalance, LedgerError> {
+    let totals = store.get_account_totals(None, as_of, currency).await?;
+    Ok(TrialBalance::from_totals(as_of, currency, totals))
+}
+
+impl TrialBalance {
+    pub fn from_totals(
+        as_of: chrono::DateTime<chrono::Utc>,
+        currency: &str,
+        totals: Vec<AccountTotals>,
+    ) -> Self {
+        let mut lines = Vec::with_capacity(totals.len());
+        let mut total_debits = Decimal::ZERO;
+        let mut total_credits = Decimal::ZERO;
+
+        for row in totals {
+            total_debits += row.debit_total;
+            total_credits += row.credit_total;
+
+            // Net uses the ledger's convention, credits minus debits, so it
+            // matches get_account_balance for the same account
+            lines.push(TrialBalanceLine {
+                account_id: row.account_id,
+                account_type: row.account_type,
+                debit_total: row.debit_total,
+                credit_total: row.credit_total,
+                net_balance: row.credit_total - row.debit_total,
+            });
+        }
+
+        Self {
+            as_of,
+            currency: currency.to_string(),
+            lines,
+            total_debits,
+            total_credits,
+            is_balanced: total_debits == total_credits,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("account_id,account_type,debit_total,credit_total,net_balance\n");
+
+        for line in &self.lines {
+            csv.push_str(&format!(
+                "{},{:?},{},{},{}\n",
+                line.account_id,
+                line.account_type,
+                line.debit_total,
+                line.credit_total,
+                line.net_balance,
+            ));
+        }
+
+        csv.push_str(&format!(
+            "TOTAL,,{},{},{}\n",
+            self.total_debits,
+            self.total_credits,
+            self.total_credits - self.total_debits,
+        ));
+
+        csv
+    }
+}
 ```
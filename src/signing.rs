@@ -0,0 +1,203 @@
+ledger/src/signing.rs
+```rust
+use std::collections::HashMap;
+use std::path::Path;
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::{hash_chain, Entry, LedgerError, LedgerStore, Transaction};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SigningError {
+    #[error("Keystore I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid keystore: {0}")]
+    InvalidKeystore(String),
+    #[error("Unknown signing key: {0}")]
+    UnknownKey(String),
+    #[error("Signature does not match transaction")]
+    InvalidSignature,
+}
+
+// On-disk keystore for the instance's own key:
+// {"key_id": "ledger-node-1", "secret_key": "<32 bytes, hex>"}
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    key_id: String,
+    secret_key: String,
+}
+
+// Public keys of every instance, for verification:
+// [{"key_id": "ledger-node-1", "public_key": "<32 bytes, hex>"}, ...]
+#[derive(Serialize, Deserialize)]
+struct PublicKeyEntry {
+    key_id: String,
+    public_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionSignature {
+    pub key_id: String,
+    pub signature: Vec<u8>,
+}
+
+pub struct TransactionSigner {
+    key_id: String,
+    signing_key: SigningKey,
+}
+
+impl TransactionSigner {
+    pub fn new(key_id: &str, signing_key: SigningKey) -> Self {
+        Self {
+            key_id: key_id.to_string(),
+            signing_key,
+        }
+    }
+
+    pub fn generate(key_id: &str) -> Self {
+        Self::new(key_id, SigningKey::generate(&mut rand::rngs::OsRng))
+    }
+
+    pub fn from_keystore_file(path: impl AsRef<Path>) -> Result<Self, SigningError> {
+        let contents = std::fs::read_to_string(path)?;
+        let keystore: KeystoreFile = serde_json::from_str(&contents)
+            .map_err(|error| SigningError::InvalidKeystore(error.to_string()))?;
+        let secret: [u8; 32] = hex::decode(&keystore.secret_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| SigningError::InvalidKeystore("secret_key must be 32 hex-encoded bytes".to_string()))?;
+
+        Ok(Self::new(&keystore.key_id, SigningKey::from_bytes(&secret)))
+    }
+
+    // Writes the keystore to a new file that is owner-only from the moment
+    // it exists, where supported. An existing file is never overwritten.
+    pub fn write_keystore_file(&self, path: impl AsRef<Path>) -> Result<(), SigningError> {
+        use std::io::Write;
+
+        let keystore = KeystoreFile {
+            key_id: self.key_id.clone(),
+            secret_key: hex::encode(self.signing_key.to_bytes()),
+        };
+        let contents = serde_json::to_string_pretty(&keystore)
+            .map_err(|error| SigningError::InvalidKeystore(error.to_string()))?;
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+
+        Ok(())
+    }
+
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    pub fn verifying_key(&self) -> VerifyingKey {
+        self.signing_key.verifying_key()
+    }
+
+    pub fn sign(&self, transaction: &Transaction, entries: &[Entry]) -> TransactionSignature {
+        let message = hash_chain::canonical_bytes(transaction, entries);
+        TransactionSignature {
+            key_id: self.key_id.clone(),
+            signature: self.signing_key.sign(&message).to_bytes().to_vec(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Keyring {
+    keys: HashMap<String, VerifyingKey>,
+}
+
+impl Keyring {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SigningError> {
+        let contents = std::fs::read_to_string(path)?;
+        let entries: Vec<PublicKeyEntry> = serde_json::from_str(&contents)
+            .map_err(|error| SigningError::InvalidKeystore(error.to_string()))?;
+
+        let mut keyring = Self::new();
+        for entry in entries {
+            let bytes: [u8; 32] = hex::decode(&entry.public_key)
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| SigningError::InvalidKeystore(format!("public key for {} must be 32 hex-encoded bytes", entry.key_id)))?;
+            let key = VerifyingKey::from_bytes(&bytes)
+                .map_err(|error| SigningError::InvalidKeystore(error.to_string()))?;
+            keyring.insert(&entry.key_id, key);
+        }
+
+        Ok(keyring)
+    }
+
+    pub fn insert(&mut self, key_id: &str, key: VerifyingKey) {
+        self.keys.insert(key_id.to_string(), key);
+    }
+
+    pub fn verify(
+        &self,
+        transaction: &Transaction,
+        entries: &[Entry],
+        signature: &TransactionSignature,
+    ) -> Result<(), SigningError> {
+        let key = self
+            .keys
+            .get(&signature.key_id)
+            .ok_or_else(|| SigningError::UnknownKey(signature.key_id.clone()))?;
+        let bytes: [u8; 64] = signature
+            .signature
+            .as_slice()
+            .try_into()
+            .map_err(|_| SigningError::InvalidSignature)?;
+
+        let message = hash_chain::canonical_bytes(transaction, entries);
+        key.verify(&message, &ed25519_dalek::Signature::from_bytes(&bytes))
+            .map_err(|_| SigningError::InvalidSignature)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SignatureStatus {
+    Valid { key_id: String },
+    Invalid { key_id: String },
+    UnknownKey { key_id: String },
+    Unsigned,
+}
+
+// Checks the stored signature of a recorded transaction against its rows
+// as they are stored now
+pub async fn verify_recorded_signature(
+    store: &(impl LedgerStore + ?Sized),
+    keyring: &Keyring,
+    transaction_id: Uuid,
+) -> Result<SignatureStatus, LedgerError> {
+    let transaction = store
+        .get_transaction(&transaction_id)
+        .await?
+        .ok_or(LedgerError::TransactionNotFound)?;
+    let Some(signature) = store.get_transaction_signature(&transaction_id).await? else {
+        return Ok(SignatureStatus::Unsigned);
+    };
+    let entries = store.get_entries_for_transaction(&transaction_id).await?;
+
+    let key_id = signature.key_id.clone();
+    Ok(match keyring.verify(&transaction, &entries, &signature) {
+        Ok(()) => SignatureStatus::Valid { key_id },
+        Err(SigningError::UnknownKey(_)) => SignatureStatus::UnknownKey { key_id },
+        Err(_) => SignatureStatus::Invalid { key_id },
+    })
+}
+```
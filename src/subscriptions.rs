@@ -0,0 +1,66 @@
+ledger/src/subscriptions.rs
+```rust
+use std::pin::Pin;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tokio_stream::{Stream, StreamExt};
+use uuid::Uuid;
+use crate::LedgerError;
+
+// What one committed transaction did to one account. Only entry ids are
+// carried, to keep the update small; fetch the entries with
+// get_entries_for_transaction if they are needed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdate {
+    pub account_id: Uuid,
+    pub transaction_id: Uuid,
+    pub entry_ids: Vec<Uuid>,
+    // Current balance after the transaction, as maintained by the store
+    pub balance: Decimal,
+}
+
+pub type AccountUpdateStream =
+    Pin<Box<dyn Stream<Item = Result<AccountUpdate, LedgerError>> + Send + 'static>>;
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+// In-process fan-out of account updates. Stores publish each committed
+// update once; every subscriber sees the updates for its own account.
+#[derive(Clone)]
+pub struct AccountUpdateBroadcaster {
+    sender: broadcast::Sender<AccountUpdate>,
+}
+
+impl Default for AccountUpdateBroadcaster {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl AccountUpdateBroadcaster {
+    // `capacity` updates are buffered per subscriber; one that falls further
+    // behind receives SubscriptionLagged and then continues with newer updates
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn publish(&self, update: AccountUpdate) {
+        // No subscribers is not an error, the update is simply dropped
+        let _ = self.sender.send(update);
+    }
+
+    pub fn subscribe(&self, account_id: Uuid) -> AccountUpdateStream {
+        let stream = BroadcastStream::new(self.sender.subscribe()).filter_map(move |update| match update {
+            Ok(update) if update.account_id == account_id => Some(Ok(update)),
+            Ok(_) => None,
+            Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                Some(Err(LedgerError::SubscriptionLagged { missed }))
+            }
+        });
+        Box::pin(stream)
+    }
+}
+```
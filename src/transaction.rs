@@ -19,7 +19,7 @@ pub struct Transaction {
     pub idempotency_key: String,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransactionType {
     Credit,
     Debit,
@@ -28,6 +28,18 @@ pub enum TransactionType {
     Adjustment,
 }
 
+impl TransactionType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionType::Credit => "Credit",
+            TransactionType::Debit => "Debit",
+            TransactionType::Transfer => "Transfer",
+            TransactionType::Reversal => "Reversal",
+            TransactionType::Adjustment => "Adjustment",
+        }
+    }
+}
+
 impl Transaction {
     pub fn new(
         transaction_type: TransactionType,
@@ -80,6 +92,27 @@ impl Transaction {
 
         Ok(())
     }
+
+    // Whether a retried request asks for this same transaction. Only the
+    // accounts the transaction type uses are compared, since the others are
+    // never stored.
+    pub fn matches_request(
+        &self,
+        transaction_type: TransactionType,
+        amount: Decimal,
+        source_account_id: Option<Uuid>,
+        destination_account_id: Option<Uuid>,
+        reason_code: &str,
+    ) -> bool {
+        let uses_source = matches!(transaction_type, TransactionType::Debit | TransactionType::Transfer);
+        let uses_destination = matches!(transaction_type, TransactionType::Credit | TransactionType::Transfer);
+
+        self.transaction_type == transaction_type
+            && self.amount == amount
+            && self.reason_code == reason_code
+            && (!uses_source || self.source_account_id == source_account_id)
+            && (!uses_destination || self.destination_account_id == destination_account_id)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,376 @@
+ledger/src/webhooks.rs
+```rust
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use uuid::Uuid;
+use crate::{EventRecord, EventSink, EventSinkError, LedgerError, LedgerStore};
+
+// Request headers sent with every webhook
+pub const SIGNATURE_HEADER: &str = "X-Ledger-Signature";
+pub const EVENT_ID_HEADER: &str = "X-Ledger-Event-Id";
+pub const EVENT_TYPE_HEADER: &str = "X-Ledger-Event-Type";
+pub const DELIVERY_ID_HEADER: &str = "X-Ledger-Delivery-Id";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub url: String,
+    // Shared with the subscriber out of band; never serialized
+    #[serde(skip_serializing)]
+    pub secret: String,
+    // Event types to deliver; empty means every event
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl WebhookSubscription {
+    // Creates a subscription with a freshly generated signing secret
+    pub fn new(url: &str, event_types: &[&str]) -> Self {
+        let mut secret = [0u8; 32];
+        rand::RngCore::fill_bytes(&mut rand::rngs::OsRng, &mut secret);
+
+        Self {
+            id: Uuid::new_v4(),
+            url: url.to_string(),
+            secret: hex::encode(secret),
+            event_types: event_types.iter().map(|t| t.to_string()).collect(),
+            active: true,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    pub fn accepts(&self, event_type: &str) -> bool {
+        self.active && (self.event_types.is_empty() || self.event_types.iter().any(|t| t == event_type))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    // Retries exhausted; only a replay sends it again
+    DeadLetter,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "Pending",
+            DeliveryStatus::Delivered => "Delivered",
+            DeliveryStatus::DeadLetter => "DeadLetter",
+        }
+    }
+}
+
+// A queued delivery of one event to one subscriber
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub url: String,
+    #[serde(skip_serializing)]
+    pub secret: String,
+    pub status: DeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: chrono::DateTime<chrono::Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub event: EventRecord,
+}
+
+// Result of one delivery attempt, as persisted by the store
+#[derive(Debug, Clone)]
+pub struct WebhookAttempt {
+    pub status: DeliveryStatus,
+    pub next_attempt_at: chrono::DateTime<chrono::Utc>,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    // Attempts before a delivery is dead-lettered, including the first
+    pub max_attempts: i32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60 * 60),
+            max_attempts: 10,
+        }
+    }
+}
+
+impl RetryPolicy {
+    // Delay after the given number of failed attempts: base, 2x base,
+    // 4x base, ... capped at max_delay
+    pub fn delay_after(&self, failed_attempts: i32) -> Duration {
+        let exponent = (failed_attempts.max(1) - 1).min(31) as u32;
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(self.max_delay)
+    }
+
+    pub fn failed_attempt(
+        &self,
+        attempts: i32,
+        status_code: Option<i32>,
+        error: String,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> WebhookAttempt {
+        let status = if attempts >= self.max_attempts {
+            DeliveryStatus::DeadLetter
+        } else {
+            DeliveryStatus::Pending
+        };
+        let delay = chrono::Duration::from_std(self.delay_after(attempts))
+            .unwrap_or_else(|_| chrono::Duration::zero());
+
+        WebhookAttempt {
+            status,
+            next_attempt_at: now + delay,
+            status_code,
+            error: Some(error),
+        }
+    }
+}
+
+// Signature header value: "t=<unix seconds>,v1=<hex HMAC-SHA256>", computed
+// over "<t>.<body>" so a captured request cannot be replayed much later
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let signature = payload_mac(secret, timestamp, body).finalize().into_bytes();
+    format!("t={},v1={}", timestamp, hex::encode(signature))
+}
+
+// For subscribers: checks the signature header against the raw request body
+// and rejects timestamps more than `tolerance` away from `now`
+pub fn verify_signature(
+    secret: &str,
+    header: &str,
+    body: &[u8],
+    now: chrono::DateTime<chrono::Utc>,
+    tolerance: Duration,
+) -> bool {
+    let mut timestamp = None;
+    let mut signature = None;
+    for part in header.split(',') {
+        match part.split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signature = hex::decode(value).ok(),
+            _ => {}
+        }
+    }
+    let (Some(timestamp), Some(signature)) = (timestamp, signature) else {
+        return false;
+    };
+
+    if (now.timestamp() - timestamp).unsigned_abs() > tolerance.as_secs() {
+        return false;
+    }
+
+    payload_mac(secret, timestamp, body).verify_slice(&signature).is_ok()
+}
+
+fn payload_mac(secret: &str, timestamp: i64, body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac
+}
+
+pub fn webhook_body(event: &EventRecord) -> Vec<u8> {
+    serde_json::to_vec(event).expect("ledger events always serialize")
+}
+
+// Event sink for the outbox relay that queues each event for every
+// subscriber whose filter accepts it. Queuing is idempotent, so events the
+// relay hands over twice are still delivered once per subscriber.
+pub struct WebhookFanout {
+    store: Arc<dyn LedgerStore>,
+}
+
+impl WebhookFanout {
+    pub fn new(store: Arc<dyn LedgerStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookFanout {
+    async fn deliver(&self, event: &EventRecord) -> Result<(), EventSinkError> {
+        self.store
+            .enqueue_webhook_deliveries(event)
+            .await
+            .map(|_| ())
+            .map_err(|error| EventSinkError(error.to_string()))
+    }
+}
+
+// Sends every dead-lettered delivery of the subscription again
+pub async fn replay_dead_letters(
+    store: &(impl LedgerStore + ?Sized),
+    subscription_id: Uuid,
+) -> Result<u64, LedgerError> {
+    store.replay_webhook_deliveries(&subscription_id, None).await
+}
+
+// Sends one delivery again, whatever its current status
+pub async fn replay_delivery(
+    store: &(impl LedgerStore + ?Sized),
+    subscription_id: Uuid,
+    delivery_id: Uuid,
+) -> Result<bool, LedgerError> {
+    let replayed = store
+        .replay_webhook_deliveries(&subscription_id, Some(&delivery_id))
+        .await?;
+    Ok(replayed > 0)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DispatchSummary {
+    pub delivered: usize,
+    pub retrying: usize,
+    pub dead_lettered: usize,
+}
+
+#[cfg(feature = "webhooks")]
+const DEFAULT_BATCH_SIZE: i64 = 50;
+#[cfg(feature = "webhooks")]
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+// Slack on top of the time a claimed batch can take to send, see
+// WebhookDispatcher::claim_lease
+#[cfg(feature = "webhooks")]
+const CLAIM_LEASE_MARGIN: Duration = Duration::from_secs(60);
+
+// POSTs queued deliveries to subscribers. Any 2xx response counts as
+// delivered; anything else is retried with exponential backoff until the
+// retry policy dead-letters it.
+#[cfg(feature = "webhooks")]
+pub struct WebhookDispatcher {
+    store: Arc<dyn LedgerStore>,
+    client: reqwest::Client,
+    policy: RetryPolicy,
+    batch_size: i64,
+}
+
+#[cfg(feature = "webhooks")]
+impl WebhookDispatcher {
+    pub fn new(store: Arc<dyn LedgerStore>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(DEFAULT_REQUEST_TIMEOUT)
+            .build()
+            .expect("Failed to build HTTP client");
+
+        Self {
+            store,
+            client,
+            policy: RetryPolicy::default(),
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: i64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub async fn run_once(&self) -> Result<DispatchSummary, LedgerError> {
+        let due = self
+            .store
+            .claim_due_webhook_deliveries(self.batch_size, self.claim_lease())
+            .await?;
+        let mut summary = DispatchSummary::default();
+
+        for delivery in &due {
+            let attempt = match self.send(delivery).await {
+                Ok(status_code) => WebhookAttempt {
+                    status: DeliveryStatus::Delivered,
+                    next_attempt_at: chrono::Utc::now(),
+                    status_code: Some(status_code),
+                    error: None,
+                },
+                Err((status_code, error)) => {
+                    tracing::debug!(delivery_id = %delivery.id, %error, "webhook delivery failed");
+                    self.policy
+                        .failed_attempt(delivery.attempts + 1, status_code, error, chrono::Utc::now())
+                }
+            };
+
+            match attempt.status {
+                DeliveryStatus::Delivered => summary.delivered += 1,
+                DeliveryStatus::Pending => summary.retrying += 1,
+                DeliveryStatus::DeadLetter => {
+                    tracing::warn!(delivery_id = %delivery.id, url = %delivery.url, "webhook delivery dead-lettered");
+                    summary.dead_lettered += 1;
+                }
+            }
+            self.store.record_webhook_attempt(&delivery.id, &attempt).await?;
+        }
+
+        Ok(summary)
+    }
+
+    // Claimed deliveries are hidden from other dispatchers for this long, so
+    // a dispatcher that dies mid-batch does not strand them. Deliveries are
+    // sent one after another, so the lease covers every request of the batch
+    // timing out; a shorter lease would let another dispatcher claim and
+    // send the tail of a batch that is still being worked through.
+    fn claim_lease(&self) -> Duration {
+        let requests = self.batch_size.clamp(1, u32::MAX as i64) as u32;
+        DEFAULT_REQUEST_TIMEOUT * requests + CLAIM_LEASE_MARGIN
+    }
+
+    pub fn spawn(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                if let Err(error) = self.run_once().await {
+                    tracing::warn!(%error, "webhook dispatch run failed");
+                }
+            }
+        })
+    }
+
+    async fn send(&self, delivery: &WebhookDelivery) -> Result<i32, (Option<i32>, String)> {
+        let body = webhook_body(&delivery.event);
+        let signature = sign_payload(&delivery.secret, chrono::Utc::now().timestamp(), &body);
+
+        let response = self
+            .client
+            .post(&delivery.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, signature)
+            .header(EVENT_ID_HEADER, delivery.event.id.to_string())
+            .header(EVENT_TYPE_HEADER, delivery.event.event.event_type())
+            .header(DELIVERY_ID_HEADER, delivery.id.to_string())
+            .body(body)
+            .send()
+            .await
+            .map_err(|error| (None, error.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(status.as_u16() as i32)
+        } else {
+            Err((Some(status.as_u16() as i32), format!("Subscriber responded with {}", status)))
+        }
+    }
+}
+```
@@ -0,0 +1,171 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    // Debits `from` and credits `to` at `at`
+    async fn post(store: &PostgresLedgerStore, from: Uuid, to: Uuid, amount: Decimal, at: &str, reason: &str) -> Transaction {
+        let at: chrono::DateTime<chrono::Utc> = at.parse().unwrap();
+        let mut transaction = Transaction::new(TransactionType::Transfer, amount, Some(from), Some(to), reason, &at.to_rfc3339());
+        transaction.timestamp = at;
+        let mut entries = vec![
+            Entry::new(transaction.id, from, amount, EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, amount, EntryType::Credit, Decimal::ZERO),
+        ];
+        for entry in entries.iter_mut() {
+            entry.timestamp = at;
+        }
+        store.record_transaction(&transaction, &entries).await.unwrap();
+        transaction
+    }
+
+    fn statement_entry(
+        account_id: Uuid,
+        counterparty: Uuid,
+        entry_type: EntryType,
+        amount: Decimal,
+        balance_after: Decimal,
+        at: &str,
+        reason: &str,
+    ) -> AccountEntry {
+        let (source, destination) = match entry_type {
+            EntryType::Debit => (account_id, counterparty),
+            EntryType::Credit => (counterparty, account_id),
+        };
+        let transaction = Transaction::new(TransactionType::Transfer, amount, Some(source), Some(destination), reason, "key");
+        let mut entry = Entry::new(transaction.id, account_id, amount, entry_type, balance_after);
+        entry.timestamp = at.parse().unwrap();
+        AccountEntry {
+            entry,
+            transaction: TransactionSummary {
+                id: transaction.id,
+                transaction_type: transaction.transaction_type,
+                amount,
+                source_account_id: transaction.source_account_id,
+                destination_account_id: transaction.destination_account_id,
+                reason_code: transaction.reason_code,
+                timestamp: transaction.timestamp,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_statement_covers_start_exclusive_end_inclusive() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+
+        let cash = Account::new(AccountType::Asset, "USD");
+        let wallet = Account::new(AccountType::Liability, "USD");
+        store.create_account(&cash).await.unwrap();
+        store.create_account(&wallet).await.unwrap();
+
+        post(&store, cash.id, wallet.id, dec!(100.00), "2026-01-15T09:00:00Z", "deposit").await;
+        post(&store, cash.id, wallet.id, dec!(50.00), "2026-02-01T00:00:00Z", "deposit").await;
+        let withdrawal = post(&store, wallet.id, cash.id, dec!(30.00), "2026-02-10T09:00:00Z", "withdrawal, fee").await;
+        let last = post(&store, cash.id, wallet.id, dec!(5.00), "2026-03-01T00:00:00Z", "deposit").await;
+        post(&store, cash.id, wallet.id, dec!(1.00), "2026-03-02T09:00:00Z", "deposit").await;
+
+        let statement = account_statement(
+            &store,
+            wallet.id,
+            "2026-02-01T00:00:00Z".parse().unwrap(),
+            "2026-03-01T00:00:00Z".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+
+        // The posting at exactly period_start is in the opening balance, the
+        // one at exactly period_end is the last line
+        assert_eq!(statement.opening_balance, dec!(150.00));
+        let lines: Vec<(Uuid, EntryType, Decimal, Decimal)> = statement
+            .lines
+            .iter()
+            .map(|line| (line.transaction_id, line.entry_type, line.amount, line.running_balance))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (withdrawal.id, EntryType::Debit, dec!(30.00), dec!(120.00)),
+                (last.id, EntryType::Credit, dec!(5.00), dec!(125.00)),
+            ]
+        );
+        assert!(statement.lines.iter().all(|line| !line.balance_after_mismatch));
+        assert!(statement.lines.iter().all(|line| line.counterparty_account_id == Some(cash.id)));
+        assert_eq!(statement.total_debits, dec!(30.00));
+        assert_eq!(statement.total_credits, dec!(5.00));
+        assert_eq!(statement.closing_balance, dec!(125.00));
+
+        // Consecutive statements chain: one's closing is the next's opening
+        let march = account_statement(
+            &store,
+            wallet.id,
+            "2026-03-01T00:00:00Z".parse().unwrap(),
+            "2026-04-01T00:00:00Z".parse().unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(march.opening_balance, statement.closing_balance);
+        assert_eq!(march.lines.len(), 1);
+        assert_eq!(march.closing_balance, dec!(126.00));
+
+        assert!(statement.to_csv().contains(&format!("{},Transfer,\"withdrawal, fee\",{},", withdrawal.id, cash.id)));
+    }
+
+    #[test]
+    fn test_statement_renders_csv_and_text() {
+        let account_id = Uuid::new_v4();
+        let counterparty = Uuid::new_v4();
+        let entries = vec![
+            statement_entry(account_id, counterparty, EntryType::Debit, dec!(30.00), dec!(120.00), "2026-02-10T09:00:00Z", "withdrawal, fee"),
+            // Stored balance_after is wrong; the running balance is not
+            statement_entry(account_id, counterparty, EntryType::Credit, dec!(5.00), dec!(999.00), "2026-03-01T00:00:00Z", "a_very_long_reason_code_indeed"),
+        ];
+        let ids: Vec<Uuid> = entries.iter().map(|entry| entry.transaction.id).collect();
+        let statement = AccountStatement::build(
+            account_id,
+            "USD",
+            "2026-02-01T00:00:00Z".parse().unwrap(),
+            "2026-03-01T00:00:00Z".parse().unwrap(),
+            HashMap::from([(0, dec!(150.00))]),
+            entries,
+        );
+
+        assert_eq!(statement.closing_balance, dec!(125.00));
+        assert!(!statement.lines[0].balance_after_mismatch);
+        assert!(statement.lines[1].balance_after_mismatch);
+
+        assert_eq!(
+            statement.to_csv(),
+            format!(
+                "timestamp,transaction_id,transaction_type,reason_code,counterparty_account_id,debit,credit,running_balance\n\
+                 ,,,OPENING_BALANCE,,,,150.00\n\
+                 2026-02-10T09:00:00+00:00,{},Transfer,\"withdrawal, fee\",{},30.00,,120.00\n\
+                 2026-03-01T00:00:00+00:00,{},Transfer,a_very_long_reason_code_indeed,{},,5.00,125.00\n\
+                 ,,,CLOSING_BALANCE,,30.00,5.00,125.00\n",
+                ids[0], counterparty, ids[1], counterparty
+            )
+        );
+
+        let text = statement.to_text();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "ACCOUNT STATEMENT");
+        assert_eq!(lines[1], format!("Account:  {} (USD)", account_id));
+        assert_eq!(lines[2], "Period:   2026-02-01 00:00:00 to 2026-03-01 00:00:00");
+        // Everything below the heading is in fixed-width columns, with long
+        // reason codes cut to fit
+        assert!(lines[3..].iter().all(|line| line.chars().count() == lines[3].chars().count()));
+        assert!(lines.iter().any(|line| line.starts_with("2026-03-01 00:00:00  a_very_long_reason_c  ")));
+        assert!(lines[lines.len() - 1].contains("Closing balance"));
+        assert!(lines[lines.len() - 1].ends_with("125.00"));
+    }
+}
+```
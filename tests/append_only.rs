@@ -0,0 +1,123 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use sqlx::{Executor, PgPool};
+    use testcontainers::clients::Cli;
+
+    // Posts through the service exactly as production code does
+    async fn deposit(pool: &PgPool) -> (LedgerService, Account, Transaction) {
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool.clone())));
+        let account = service
+            .create_account(AccountType::Liability, "USD")
+            .await
+            .expect("Failed to create account");
+        let transaction = service
+            .credit_account(account.id, dec!(100.00), "initial_deposit", "append_only_001")
+            .await
+            .expect("Inserts must still be allowed");
+        (service, account, transaction)
+    }
+
+    #[tokio::test]
+    async fn test_history_cannot_be_updated_or_deleted() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let (service, account, transaction) = deposit(&pool).await;
+        let store = PostgresLedgerStore::new(pool.clone());
+
+        // Posting again under the same key cannot overwrite the original
+        let rewrite = Transaction::new(
+            TransactionType::Credit,
+            dec!(1.00),
+            None,
+            Some(account.id),
+            "initial_deposit",
+            "append_only_001",
+        );
+        let rewrite_entries = vec![Entry::new(rewrite.id, account.id, dec!(1.00), EntryType::Credit, dec!(1.00))];
+        assert!(matches!(
+            store.record_transaction(&rewrite, &rewrite_entries).await,
+            Err(LedgerError::IdempotencyViolation)
+        ));
+
+        // Nor can anything with direct SQL access to the store's database
+        let update = sqlx::query("UPDATE transactions SET amount = 1 WHERE id = $1")
+            .bind(transaction.id)
+            .execute(&pool)
+            .await;
+        assert!(update.is_err(), "UPDATE on transactions must be rejected");
+
+        let update = sqlx::query("UPDATE entries SET balance_after = 0 WHERE transaction_id = $1")
+            .bind(transaction.id)
+            .execute(&pool)
+            .await;
+        assert!(update.is_err(), "UPDATE on entries must be rejected");
+
+        let delete = sqlx::query("DELETE FROM entries WHERE transaction_id = $1")
+            .bind(transaction.id)
+            .execute(&pool)
+            .await;
+        assert!(delete.is_err(), "DELETE on entries must be rejected");
+
+        let truncate = pool.execute("TRUNCATE transactions CASCADE").await;
+        assert!(truncate.is_err(), "TRUNCATE on transactions must be rejected");
+
+        // History is unchanged and the store still reads it back
+        let stored = store
+            .get_transaction(&transaction.id)
+            .await
+            .expect("Failed to read transaction")
+            .expect("Transaction must still exist");
+        assert_eq!(stored.amount, dec!(100.00));
+        let entries = store
+            .get_entries_for_transaction(&transaction.id)
+            .await
+            .expect("Failed to read entries");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].balance_after, dec!(100.00));
+        assert_eq!(
+            service.get_account_balance(account.id).await.expect("Failed to get balance"),
+            dec!(100.00)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_archival_mode_allows_delete_but_not_update() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let (_service, _account, transaction) = deposit(&pool).await;
+
+        // The test connects as a superuser, which counts as a ledger_archiver member
+        let mut db_transaction = pool.begin().await.expect("Failed to begin");
+        db_transaction
+            .execute("SET LOCAL ledger.archival_mode = 'on'")
+            .await
+            .expect("Failed to enable archival mode");
+
+        let update = sqlx::query("UPDATE entries SET amount = 1 WHERE transaction_id = $1")
+            .bind(transaction.id)
+            .execute(&mut *db_transaction)
+            .await;
+        assert!(update.is_err(), "UPDATE must be rejected even in archival mode");
+        db_transaction.rollback().await.expect("Failed to roll back");
+
+        let mut db_transaction = pool.begin().await.expect("Failed to begin");
+        db_transaction
+            .execute("SET LOCAL ledger.archival_mode = 'on'")
+            .await
+            .expect("Failed to enable archival mode");
+        let deleted = sqlx::query("DELETE FROM entries WHERE transaction_id = $1")
+            .bind(transaction.id)
+            .execute(&mut *db_transaction)
+            .await
+            .expect("Archival DELETE must be allowed");
+        assert_eq!(deleted.rows_affected(), 1);
+        db_transaction.rollback().await.expect("Failed to roll back");
+    }
+}
+```
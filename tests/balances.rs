@@ -0,0 +1,125 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use sqlx::PgPool;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    // Posts `amount` (negative for a debit) to the account at `at`
+    async fn post(store: &PostgresLedgerStore, account_id: Uuid, amount: Decimal, at: chrono::DateTime<chrono::Utc>, key: &str) {
+        let (transaction_type, entry_type, source, destination) = if amount < Decimal::ZERO {
+            (TransactionType::Debit, EntryType::Debit, Some(account_id), None)
+        } else {
+            (TransactionType::Credit, EntryType::Credit, None, Some(account_id))
+        };
+        let mut transaction = Transaction::new(transaction_type, amount.abs(), source, destination, "adjustment", key);
+        transaction.timestamp = at;
+        let mut entry = Entry::new(transaction.id, account_id, amount.abs(), entry_type, Decimal::ZERO);
+        entry.timestamp = at;
+        store.record_transaction(&transaction, &[entry]).await.unwrap();
+    }
+
+    // The balance at `as_of` summed over every entry, without checkpoints
+    async fn full_scan(pool: &PgPool, account_id: Uuid, as_of: chrono::DateTime<chrono::Utc>) -> Decimal {
+        sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(SUM(CASE WHEN entry_type = 'Credit' THEN amount ELSE -amount END), 0)
+            FROM entries WHERE account_id = $1 AND timestamp <= $2
+            "#,
+        )
+        .bind(account_id)
+        .bind(as_of)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_balance_at_with_checkpoint_matches_full_scan() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool.clone());
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.unwrap();
+
+        let now = chrono::Utc::now();
+        let hours = |h: i64| now - chrono::Duration::hours(h);
+        post(&store, account.id, dec!(100.00), hours(6), "cp_1").await;
+        post(&store, account.id, dec!(-30.00), hours(5), "cp_2").await;
+        assert_eq!(store.create_balance_checkpoints(hours(4)).await.unwrap(), 1);
+        post(&store, account.id, dec!(12.50), hours(3), "cp_3").await;
+        assert_eq!(store.create_balance_checkpoints(hours(2)).await.unwrap(), 1);
+        post(&store, account.id, dec!(-2.50), hours(1), "cp_4").await;
+
+        for as_of in [hours(7), hours(5), hours(4), hours(3), hours(2), hours(1), now] {
+            assert_eq!(
+                store.get_account_balance_at(&account.id, as_of).await.unwrap(),
+                full_scan(&pool, account.id, as_of).await,
+                "as of {}",
+                as_of
+            );
+        }
+        assert_eq!(store.get_account_balance_at(&account.id, now).await.unwrap(), dec!(80.00));
+        assert_eq!(store.get_account_balance(&account.id).await.unwrap(), dec!(80.00));
+    }
+
+    #[tokio::test]
+    async fn test_balance_listing_is_a_snapshot_until_refreshed() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+        let account = Account::new(AccountType::Liability, "USD");
+        store.create_account(&account).await.unwrap();
+        let now = chrono::Utc::now();
+        post(&store, account.id, dec!(100.00), now, "view_1").await;
+
+        let cached = |listing: &BalanceListing| {
+            listing
+                .balances
+                .iter()
+                .find(|balance| balance.account_id == account.id)
+                .map(|balance| balance.balance)
+        };
+
+        // Accounts created since the last refresh are not in the view yet
+        let listing = store.list_balances(None).await.unwrap();
+        assert!(listing.refreshed_at.is_some());
+        assert_eq!(cached(&listing), None);
+
+        let refreshed_at = store.refresh_account_balances(false).await.unwrap();
+        let listing = store.list_balances(None).await.unwrap();
+        assert_eq!(listing.refreshed_at, Some(refreshed_at));
+        assert_eq!(cached(&listing), Some(dec!(100.00)));
+        assert!(!listing.is_stale(chrono::Duration::hours(1)));
+
+        // Later postings only show up once the view is refreshed, either
+        // explicitly or because the caller's max_age has passed
+        post(&store, account.id, dec!(-30.00), now + chrono::Duration::seconds(1), "view_2").await;
+        let listing = store.list_balances(Some(chrono::Duration::hours(1))).await.unwrap();
+        assert_eq!(listing.refreshed_at, Some(refreshed_at));
+        assert_eq!(cached(&listing), Some(dec!(100.00)));
+
+        let listing = store.list_balances(Some(chrono::Duration::zero())).await.unwrap();
+        assert!(listing.refreshed_at.unwrap() > refreshed_at);
+        assert_eq!(cached(&listing), Some(dec!(70.00)));
+        assert_eq!(cached(&listing), Some(store.get_account_balance(&account.id).await.unwrap()));
+
+        // The concurrent refresh the background task uses works on the same view
+        post(&store, account.id, dec!(5.00), now + chrono::Duration::seconds(2), "view_3").await;
+        store.refresh_account_balances(true).await.unwrap();
+        let listing = store.list_balances(None).await.unwrap();
+        assert_eq!(cached(&listing), Some(dec!(75.00)));
+
+        // A view that was never refreshed counts as stale at any age
+        let never = BalanceListing { refreshed_at: None, balances: Vec::new() };
+        assert!(never.staleness().is_none());
+        assert!(never.is_stale(chrono::Duration::days(365)));
+    }
+}
+```
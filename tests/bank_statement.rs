@@ -0,0 +1,187 @@
+```rust
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use rust_decimal_macros::dec;
+
+    const CAMT053: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Document xmlns="urn:iso:std:iso:20022:tech:xsd:camt.053.001.02">
+  <BkToCstmrStmt>
+    <GrpHdr><MsgId>MSG-1</MsgId><CreDtTm>2026-01-07T06:00:00</CreDtTm></GrpHdr>
+    <Stmt>
+      <Id>STMT-2026-01-06</Id>
+      <Acct><Id><IBAN>DE89370400440532013000</IBAN></Id><Ccy>EUR</Ccy></Acct>
+      <Bal>
+        <Tp><CdOrPrtry><Cd>OPBD</Cd></CdOrPrtry></Tp>
+        <Amt Ccy="EUR">1000.00</Amt><CdtDbtInd>CRDT</CdtDbtInd><Dt><Dt>2026-01-05</Dt></Dt>
+      </Bal>
+      <Bal>
+        <Tp><CdOrPrtry><Cd>CLBD</Cd></CdOrPrtry></Tp>
+        <Amt Ccy="EUR">CLOSING</Amt><CdtDbtInd>CRDT</CdtDbtInd><Dt><Dt>2026-01-06</Dt></Dt>
+      </Bal>
+      <Ntry>
+        <Amt Ccy="EUR">250.00</Amt><CdtDbtInd>CRDT</CdtDbtInd><Sts>BOOK</Sts>
+        <BookgDt><Dt>2026-01-05</Dt></BookgDt><ValDt><Dt>2026-01-05</Dt></ValDt>
+        <AcctSvcrRef>BANKREF-1</AcctSvcrRef>
+        <NtryDtls><TxDtls>
+          <Refs><EndToEndId>INV-42</EndToEndId></Refs>
+          <AmtDtls><TxAmt><Amt Ccy="EUR">250.00</Amt></TxAmt></AmtDtls>
+          <RltdPties><Dbtr><Nm>Acme &amp; Co</Nm></Dbtr></RltdPties>
+          <RmtInf><Ustrd>Invoice 42</Ustrd></RmtInf>
+        </TxDtls></NtryDtls>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="EUR">100.50</Amt><CdtDbtInd>DBIT</CdtDbtInd><Sts>BOOK</Sts>
+        <BookgDt><Dt>2026-01-06</Dt></BookgDt><ValDt><Dt>2026-01-06</Dt></ValDt>
+        <AcctSvcrRef>BANKREF-2</AcctSvcrRef>
+        <NtryDtls><TxDtls>
+          <Refs><EndToEndId>NOTPROVIDED</EndToEndId></Refs>
+          <RltdPties><Cdtr><Nm>Landlord Ltd</Nm></Cdtr></RltdPties>
+        </TxDtls></NtryDtls>
+        <AddtlNtryInf>Rent January</AddtlNtryInf>
+      </Ntry>
+      <Ntry>
+        <Amt Ccy="EUR">999.00</Amt><CdtDbtInd>CRDT</CdtDbtInd><Sts>PDNG</Sts>
+        <ValDt><Dt>2026-01-07</Dt></ValDt>
+      </Ntry>
+    </Stmt>
+  </BkToCstmrStmt>
+</Document>
+"#;
+
+    const MT940: &str = "\
+{1:F01BANKDEFFAXXX0000000000}{2:O9400700260107BANKDEFFAXXX00000000002601070700N}{4:
+:20:STMT-2026-01
+:25:DE89370400440532013000
+:28C:00001/001
+:60F:C260105EUR1000,00
+:61:2601050105C250,00NTRFINV-42//B1
+:86:166?00GUTSCHRIFT?20Invoice 42?32ACME
+?33 GMBH
+:61:260106D100,50NTRFNONREF//B2
+:86:/NAME/Landlord Ltd/REMI/Rent January
+:62F:CLOSING
+-}
+";
+
+    fn date(value: &str) -> chrono::NaiveDate {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_camt053_booked_entries() {
+        let statements = parse_camt053(&CAMT053.replace("CLOSING", "1149.50")).unwrap();
+        assert_eq!(statements.len(), 1);
+
+        let statement = &statements[0];
+        assert_eq!(statement.statement_id.as_deref(), Some("STMT-2026-01-06"));
+        assert_eq!(statement.account, "DE89370400440532013000");
+        assert_eq!(statement.currency, "EUR");
+        assert_eq!(statement.opening_balance, dec!(1000.00));
+        assert_eq!(statement.closing_balance, dec!(1149.50));
+        assert_eq!(statement.closing_date, Some(date("2026-01-06")));
+
+        // The pending entry is not part of the booked balance
+        assert_eq!(statement.lines.len(), 2);
+        assert_eq!(
+            statement.lines[0],
+            StatementLine {
+                value_date: date("2026-01-05"),
+                booking_date: Some(date("2026-01-05")),
+                amount: dec!(250.00),
+                direction: StatementDirection::Credit,
+                reference: Some("INV-42".to_string()),
+                counterparty: Some("Acme & Co".to_string()),
+                description: Some("Invoice 42".to_string()),
+            }
+        );
+        let rent = &statement.lines[1];
+        assert_eq!(rent.direction, StatementDirection::Debit);
+        assert_eq!(rent.reference.as_deref(), Some("BANKREF-2"));
+        assert_eq!(rent.counterparty.as_deref(), Some("Landlord Ltd"));
+        assert_eq!(rent.description.as_deref(), Some("Rent January"));
+    }
+
+    #[test]
+    fn test_camt053_rejects_unbalanced_statement() {
+        let result = parse_camt053(&CAMT053.replace("CLOSING", "1150.00"));
+        assert!(matches!(
+            result,
+            Err(BankStatementError::BalanceMismatch { opening, lines_total, closing, .. })
+                if opening == dec!(1000.00) && lines_total == dec!(149.50) && closing == dec!(1150.00)
+        ));
+    }
+
+    #[test]
+    fn test_mt940_lines_and_details() {
+        let statements = parse_mt940(&MT940.replace("CLOSING", "C260106EUR1149,50")).unwrap();
+        assert_eq!(statements.len(), 1);
+
+        let statement = &statements[0];
+        assert_eq!(statement.statement_id.as_deref(), Some("00001/001"));
+        assert_eq!(statement.account, "DE89370400440532013000");
+        assert_eq!(statement.currency, "EUR");
+        assert_eq!(statement.opening_date, Some(date("2026-01-05")));
+        assert_eq!(statement.lines_total(), dec!(149.50));
+
+        let invoice = &statement.lines[0];
+        assert_eq!(invoice.value_date, date("2026-01-05"));
+        assert_eq!(invoice.booking_date, Some(date("2026-01-05")));
+        assert_eq!(invoice.amount, dec!(250.00));
+        assert_eq!(invoice.direction, StatementDirection::Credit);
+        assert_eq!(invoice.reference.as_deref(), Some("INV-42"));
+        assert_eq!(invoice.counterparty.as_deref(), Some("ACME GMBH"));
+        assert_eq!(invoice.description.as_deref(), Some("Invoice 42"));
+
+        let rent = &statement.lines[1];
+        assert_eq!(rent.booking_date, None);
+        assert_eq!(rent.direction, StatementDirection::Debit);
+        assert_eq!(rent.reference.as_deref(), Some("B2"));
+        assert_eq!(rent.counterparty.as_deref(), Some("Landlord Ltd"));
+    }
+
+    #[test]
+    fn test_mt940_rejects_unbalanced_statement() {
+        let result = parse_mt940(&MT940.replace("CLOSING", "C260106EUR1149,00"));
+        assert!(matches!(result, Err(BankStatementError::BalanceMismatch { .. })));
+    }
+
+    #[test]
+    fn test_currency_mismatches_are_rejected() {
+        // A booked USD entry on a EUR account, even though the sums agree
+        let camt = CAMT053
+            .replace("CLOSING", "1149.50")
+            .replace(r#"<Amt Ccy="EUR">100.50</Amt>"#, r#"<Amt Ccy="USD">100.50</Amt>"#);
+        assert!(matches!(
+            parse_camt053(&camt),
+            Err(BankStatementError::CurrencyMismatch { part: "entry", expected, found, .. })
+                if expected == "EUR" && found == "USD"
+        ));
+
+        let result = parse_mt940(&MT940.replace("CLOSING", "C260106USD1149,50"));
+        assert!(matches!(
+            result,
+            Err(BankStatementError::CurrencyMismatch { part: "closing balance", expected, found, .. })
+                if expected == "EUR" && found == "USD"
+        ));
+    }
+
+    #[test]
+    fn test_mt940_reversal_across_year_end() {
+        let statement = "\
+:20:STMT-2026-00
+:25:12345678/0001
+:60F:D251231EUR5,00
+:61:2601021231RD20,00NCHKNONREF//B3
+:62F:C260102EUR15,00
+-
+";
+        let statements = parse_mt940(statement).unwrap();
+        let line = &statements[0].lines[0];
+        // Reversing a debit puts the money back
+        assert_eq!(line.direction, StatementDirection::Credit);
+        assert_eq!(line.booking_date, Some(date("2025-12-31")));
+        assert_eq!(statements[0].opening_balance, dec!(-5.00));
+    }
+}
+```
@@ -0,0 +1,160 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+    use uuid::Uuid;
+
+    async fn setup_store(docker: &Cli) -> (PostgresLedgerStore, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+        (PostgresLedgerStore::new(pool), node)
+    }
+
+    fn settlement(from: Uuid, to: Uuid, amount: Decimal, key: &str) -> (Transaction, Vec<Entry>) {
+        let transaction = Transaction::new(TransactionType::Transfer, amount, Some(from), Some(to), "settlement", key);
+        let entries = vec![
+            Entry::new(transaction.id, from, amount, EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, amount, EntryType::Credit, Decimal::ZERO),
+        ];
+        (transaction, entries)
+    }
+
+    async fn accounts(store: &PostgresLedgerStore) -> (Uuid, Uuid) {
+        let clearing = Account::new(AccountType::Liability, "USD");
+        let merchant = Account::new(AccountType::Asset, "USD");
+        store.create_account(&clearing).await.unwrap();
+        store.create_account(&merchant).await.unwrap();
+        (clearing.id, merchant.id)
+    }
+
+    #[tokio::test]
+    async fn test_batch_is_written_and_replays_as_duplicates() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (clearing, merchant) = accounts(&store).await;
+
+        let items: Vec<_> = (0..2500)
+            .map(|i| settlement(clearing, merchant, dec!(1.25), &format!("settle_{}", i)))
+            .collect();
+
+        let outcomes = store.record_batch(&items, BatchMode::AllOrNothing).await.expect("Batch failed");
+        let summary = BatchSummary::from_outcomes(&outcomes);
+        assert_eq!(summary.recorded, 2500);
+        assert_eq!(store.get_account_balance(&merchant).await.unwrap(), dec!(3125.00));
+        assert_eq!(store.get_account_balance(&clearing).await.unwrap(), dec!(-3125.00));
+
+        // Batches extend the hash chain exactly like single postings
+        let chain = verify_chain(&store).await.unwrap();
+        assert!(chain.is_intact(), "{:?}", chain.violations);
+        assert_eq!(chain.links_checked, 2500);
+
+        // balance_after comes from the store, not from the zeros submitted
+        let last = store.get_entries_for_transaction(&items[2499].0.id).await.unwrap();
+        for entry in &last {
+            let expected = if entry.account_id == merchant { dec!(3125.00) } else { dec!(-3125.00) };
+            assert_eq!(entry.balance_after, expected);
+        }
+        let report = verify_ledger(&store).await.unwrap();
+        assert!(report.is_clean(), "{:?}", report.violations);
+
+        // Resubmitting the file is a no-op that points at the originals
+        let replay: Vec<_> = (0..2500)
+            .map(|i| settlement(clearing, merchant, dec!(1.25), &format!("settle_{}", i)))
+            .collect();
+        let outcomes = store.record_batch(&replay, BatchMode::AllOrNothing).await.unwrap();
+        assert_eq!(BatchSummary::from_outcomes(&outcomes).duplicates, 2500);
+        assert!(matches!(
+            &outcomes[7].status,
+            BatchItemStatus::Duplicate { existing_transaction_id } if *existing_transaction_id == items[7].0.id
+        ));
+        assert_eq!(store.get_account_balance(&merchant).await.unwrap(), dec!(3125.00));
+    }
+
+    #[tokio::test]
+    async fn test_all_or_nothing_writes_nothing_on_rejection() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (clearing, merchant) = accounts(&store).await;
+
+        let items = vec![
+            settlement(clearing, merchant, dec!(5.00), "aon_1"),
+            settlement(clearing, Uuid::new_v4(), dec!(5.00), "aon_2"),
+        ];
+        let result = store.record_batch(&items, BatchMode::AllOrNothing).await;
+        assert!(matches!(result, Err(LedgerError::BatchRejected { index: 1, .. })));
+        assert!(store.get_transaction_by_key("aon_1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_new_accounts_are_rolled_back_with_the_batch() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (clearing, merchant) = accounts(&store).await;
+        let posted = settlement(clearing, merchant, dec!(1.00), "posted");
+        store.record_batch(&[posted.clone()], BatchMode::AllOrNothing).await.unwrap();
+
+        // The second item reuses a recorded transaction id, which only the
+        // database can catch
+        let payouts = Account::new(AccountType::Liability, "USD");
+        let mut clash = settlement(payouts.id, merchant, dec!(2.00), "clash");
+        clash.0.id = posted.0.id;
+        for entry in &mut clash.1 {
+            entry.transaction_id = posted.0.id;
+        }
+        let items = vec![settlement(payouts.id, merchant, dec!(3.00), "payout"), clash];
+
+        let result = store
+            .record_batch_with_accounts(&[payouts.clone()], &items, BatchMode::AllOrNothing)
+            .await;
+        assert!(result.is_err());
+        assert!(store.get_account(&payouts.id).await.unwrap().is_none());
+        assert!(store.get_transaction_by_key("payout").await.unwrap().is_none());
+
+        let outcomes = store
+            .record_batch_with_accounts(&[payouts.clone()], &items[..1], BatchMode::AllOrNothing)
+            .await
+            .unwrap();
+        assert_eq!(outcomes[0].status, BatchItemStatus::Recorded);
+        assert_eq!(store.get_account_balance(&payouts.id).await.unwrap(), dec!(-3.00));
+    }
+
+    #[tokio::test]
+    async fn test_best_effort_reports_each_item() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (clearing, merchant) = accounts(&store).await;
+
+        let items = vec![
+            settlement(clearing, merchant, dec!(5.00), "be_1"),
+            settlement(clearing, merchant, dec!(-5.00), "be_2"),
+            settlement(clearing, Uuid::new_v4(), dec!(5.00), "be_3"),
+            settlement(clearing, merchant, dec!(5.00), "be_1"),
+            settlement(clearing, merchant, dec!(7.00), "be_4"),
+        ];
+        let outcomes = store.record_batch(&items, BatchMode::BestEffort).await.unwrap();
+
+        assert_eq!(outcomes[0].status, BatchItemStatus::Recorded);
+        assert!(matches!(outcomes[1].status, BatchItemStatus::Rejected { .. }));
+        assert!(matches!(outcomes[2].status, BatchItemStatus::Rejected { .. }));
+        assert_eq!(
+            outcomes[3].status,
+            BatchItemStatus::Duplicate { existing_transaction_id: items[0].0.id }
+        );
+        assert_eq!(outcomes[4].status, BatchItemStatus::Recorded);
+        assert_eq!(store.get_account_balance(&merchant).await.unwrap(), dec!(12.00));
+
+        // A single posting with a used key still fails as before
+        let (transaction, entries) = settlement(clearing, merchant, dec!(1.00), "be_4");
+        assert!(matches!(
+            store.record_transaction(&transaction, &entries).await,
+            Err(LedgerError::IdempotencyViolation)
+        ));
+    }
+}
+```
@@ -0,0 +1,183 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+
+    async fn setup_store(docker: &Cli) -> (PostgresLedgerStore, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+        (PostgresLedgerStore::new(pool), node)
+    }
+
+    async fn coded_account(store: &PostgresLedgerStore, code: &str) -> Account {
+        let mut account = Account::new(AccountType::Expense, "USD");
+        account.metadata = serde_json::json!({ "code": code });
+        store.create_account(&account).await.unwrap();
+        account
+    }
+
+    fn errors_of(row: &ImportRowResult) -> Vec<Option<String>> {
+        match &row.status {
+            ImportRowStatus::Rejected { errors } => errors.iter().map(|e| e.field.clone()).collect(),
+            other => panic!("expected rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_reports_bad_fields_by_line() {
+        let file = "\
+account,amount,direction,reason_code,idempotency_key,effective_date,metadata,cost_center
+6100,12.50,debit,adjustment,adj-1,2026-01-31,\"{\"\"ticket\"\": \"\"FIN-7\"\"}\",ops
+6100,abc,sideways,adjustment,,31/01/2026,[],
+";
+        let rows = parse_csv(file.as_bytes()).unwrap();
+        assert_eq!(rows.len(), 2);
+
+        let row = rows[0].as_ref().unwrap();
+        assert_eq!(row.line, 2);
+        assert_eq!(row.amount, dec!(12.50));
+        assert_eq!(row.direction, EntryType::Debit);
+        assert_eq!(row.metadata["ticket"], "FIN-7");
+        assert_eq!(row.metadata["cost_center"], "ops");
+
+        let (line, errors) = rows[1].as_ref().unwrap_err();
+        assert_eq!(*line, 3);
+        let fields: Vec<_> = errors.iter().filter_map(|e| e.field.as_deref()).collect();
+        assert_eq!(fields, ["amount", "direction", "idempotency_key", "effective_date", "metadata"]);
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_columns() {
+        let result = parse_csv("account,amount\n6100,1.00\n".as_bytes());
+        assert!(matches!(result, Err(LedgerError::InvalidImportFile(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_then_import() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let travel = coded_account(&store, "6100").await;
+        let supplies = coded_account(&store, "6200").await;
+
+        let file = format!(
+            "\
+account,amount,direction,reason_code,idempotency_key,effective_date
+6100,100.00,credit,adjustment,adj-1,2026-01-31
+{},40.00,debit,adjustment,adj-2,
+6200,5000.00,debit,adjustment,adj-3,
+9999,1.00,debit,adjustment,adj-4,
+6200,1.00,debit,bonus,adj-5,
+6200,2.00,debit,adjustment,adj-1,
+",
+            travel.id
+        );
+        let mut options = ImportOptions {
+            dry_run: true,
+            mode: BatchMode::AllOrNothing,
+            policy: ImportPolicy {
+                allowed_reason_codes: Some(["adjustment".to_string()].into_iter().collect()),
+                max_amount: Some(dec!(1000)),
+                max_backdate: None,
+                reject_overdrafts: false,
+            },
+        };
+
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(!report.posted);
+        assert_eq!(report.rows[0].status, ImportRowStatus::Valid);
+        assert_eq!(report.rows[1].status, ImportRowStatus::Valid);
+        assert_eq!(errors_of(&report.rows[2]), [Some("amount".to_string())]);
+        assert_eq!(errors_of(&report.rows[3]), [Some("account".to_string())]);
+        assert_eq!(errors_of(&report.rows[4]), [Some("reason_code".to_string())]);
+        assert_eq!(errors_of(&report.rows[5]), [Some("idempotency_key".to_string())]);
+        assert_eq!(report.rejected().map(|row| row.line).collect::<Vec<_>>(), [4, 5, 6, 7]);
+
+        // All-or-nothing refuses to post while any row is rejected
+        options.dry_run = false;
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(!report.posted);
+        assert!(store.get_transaction_by_key("adj-1").await.unwrap().is_none());
+
+        options.mode = BatchMode::BestEffort;
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(report.posted);
+        assert!(matches!(report.rows[0].status, ImportRowStatus::Recorded { .. }));
+        assert!(matches!(report.rows[1].status, ImportRowStatus::Recorded { .. }));
+        assert_eq!(store.get_account_balance(&travel.id).await.unwrap(), dec!(60.00));
+        assert_eq!(store.get_account_balance(&supplies.id).await.unwrap(), dec!(0));
+
+        // The effective date is recorded, but the row is posted now so
+        // earlier balances and checkpoints stay as they were
+        let posted = store.get_transaction_by_key("adj-1").await.unwrap().unwrap();
+        assert_eq!(posted.metadata["effective_date"], "2026-01-31T00:00:00+00:00");
+        assert!(posted.timestamp > chrono::Utc::now() - chrono::Duration::minutes(5));
+
+        // Importing the same file again only reports what is already there
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(matches!(
+            report.rows[0].status,
+            ImportRowStatus::Duplicate { existing_transaction_id } if existing_transaction_id == posted.id
+        ));
+        assert_eq!(store.get_account_balance(&travel.id).await.unwrap(), dec!(60.00));
+
+        // A recorded key reused for a different posting is not a duplicate
+        let changed = "\
+account,amount,direction,reason_code,idempotency_key
+6100,101.00,credit,adjustment,adj-1
+";
+        let report = import_csv(&store, changed.as_bytes(), &options).await.unwrap();
+        assert_eq!(errors_of(&report.rows[0]), [Some("idempotency_key".to_string())]);
+        assert_eq!(store.get_account_balance(&travel.id).await.unwrap(), dec!(60.00));
+    }
+
+    #[tokio::test]
+    async fn test_reject_overdrafts_counts_earlier_rows() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let travel = coded_account(&store, "6100").await;
+        let supplies = coded_account(&store, "6200").await;
+
+        let file = "\
+account,amount,direction,reason_code,idempotency_key
+6100,50.00,credit,adjustment,adj-1
+6100,30.00,debit,adjustment,adj-2
+6100,30.00,debit,adjustment,adj-3
+6200,0.01,debit,adjustment,adj-4
+6100,10.00,credit,adjustment,adj-5
+";
+        let options = ImportOptions {
+            dry_run: false,
+            mode: BatchMode::BestEffort,
+            policy: ImportPolicy {
+                reject_overdrafts: true,
+                ..ImportPolicy::default()
+            },
+        };
+
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(report.posted);
+        assert!(matches!(report.rows[1].status, ImportRowStatus::Recorded { .. }));
+        assert_eq!(errors_of(&report.rows[2]), [Some("amount".to_string())]);
+        assert_eq!(errors_of(&report.rows[3]), [Some("amount".to_string())]);
+        assert!(matches!(report.rows[4].status, ImportRowStatus::Recorded { .. }));
+        assert_eq!(store.get_account_balance(&travel.id).await.unwrap(), dec!(30.00));
+        assert_eq!(store.get_account_balance(&supplies.id).await.unwrap(), dec!(0));
+
+        // The balances are read from the store, so a second file sees the first
+        let file = "\
+account,amount,direction,reason_code,idempotency_key
+6100,30.00,debit,adjustment,adj-6
+6100,0.01,debit,adjustment,adj-7
+";
+        let report = import_csv(&store, file.as_bytes(), &options).await.unwrap();
+        assert!(matches!(report.rows[0].status, ImportRowStatus::Recorded { .. }));
+        assert_eq!(errors_of(&report.rows[1]), [Some("amount".to_string())]);
+        assert_eq!(store.get_account_balance(&travel.id).await.unwrap(), dec!(0));
+    }
+}
+```
@@ -0,0 +1,91 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+
+    #[tokio::test]
+    async fn test_entry_history_shows_each_side_of_a_transfer() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool)));
+        let wallet = service.create_account(AccountType::Liability, "USD").await.unwrap();
+        let merchant = service.create_account(AccountType::Liability, "USD").await.unwrap();
+
+        let deposit = service.credit_account(wallet.id, dec!(100.00), "deposit", "history_1").await.unwrap();
+        let purchase = service.transfer(wallet.id, merchant.id, dec!(30.00), "purchase", "history_2").await.unwrap();
+        let refund = service.transfer(merchant.id, wallet.id, dec!(5.00), "refund", "history_3").await.unwrap();
+
+        let page = service
+            .get_account_entries(wallet.id, &EntryFilter::default(), None, 2)
+            .await
+            .unwrap();
+        let lines: Vec<_> = page
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.transaction.id,
+                    item.entry.entry_type,
+                    item.entry.amount,
+                    item.entry.balance_after,
+                    item.transaction.counterparty_account_id(wallet.id),
+                )
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (refund.id, EntryType::Credit, dec!(5.00), dec!(75.00), Some(merchant.id)),
+                (purchase.id, EntryType::Debit, dec!(30.00), dec!(70.00), Some(merchant.id)),
+            ]
+        );
+        // The summary carries the whole transaction, not just this leg
+        let summary = &page.items[1].transaction;
+        assert_eq!(summary.transaction_type, TransactionType::Transfer);
+        assert_eq!(summary.amount, dec!(30.00));
+        assert_eq!(summary.source_account_id, Some(wallet.id));
+        assert_eq!(summary.destination_account_id, Some(merchant.id));
+        assert_eq!(summary.reason_code, "purchase");
+
+        // A posting made between pages does not shift the next one
+        service.credit_account(wallet.id, dec!(1.00), "deposit", "history_4").await.unwrap();
+        let page = service
+            .get_account_entries(wallet.id, &EntryFilter::default(), page.next_cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].transaction.id, deposit.id);
+        assert_eq!(page.items[0].entry.balance_after, dec!(100.00));
+        assert_eq!(page.items[0].transaction.counterparty_account_id(wallet.id), None);
+        assert!(page.next_cursor.is_none());
+
+        // The other account sees the opposite side of the same transfer
+        let merchant_history = service
+            .get_account_entries(merchant.id, &EntryFilter { order: SortOrder::OldestFirst, ..Default::default() }, None, 10)
+            .await
+            .unwrap();
+        let sides: Vec<_> = merchant_history
+            .items
+            .iter()
+            .map(|item| (item.transaction.id, item.entry.entry_type, item.entry.balance_after))
+            .collect();
+        assert_eq!(
+            sides,
+            vec![
+                (purchase.id, EntryType::Credit, dec!(30.00)),
+                (refund.id, EntryType::Debit, dec!(25.00)),
+            ]
+        );
+
+        assert!(matches!(
+            service.get_account_entries(wallet.id, &EntryFilter::default(), Some("not a cursor"), 2).await,
+            Err(LedgerError::InvalidCursor)
+        ));
+    }
+}
+```
@@ -0,0 +1,163 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    fn chart_account(account_type: AccountType, code: &str, parent_code: Option<&str>) -> Account {
+        let mut account = Account::new(account_type, "USD");
+        account.metadata = serde_json::json!({ "code": code, "parent_code": parent_code, "name": code });
+        account
+    }
+
+    // Debits `from` and credits `to` at `at`
+    async fn post(store: &PostgresLedgerStore, from: Uuid, to: Uuid, amount: Decimal, at: &str, key: &str) {
+        let at: chrono::DateTime<chrono::Utc> = at.parse().unwrap();
+        let mut transaction = Transaction::new(TransactionType::Transfer, amount, Some(from), Some(to), "posting", key);
+        transaction.timestamp = at;
+        let mut entries = vec![
+            Entry::new(transaction.id, from, amount, EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, amount, EntryType::Credit, Decimal::ZERO),
+        ];
+        for entry in entries.iter_mut() {
+            entry.timestamp = at;
+        }
+        store.record_transaction(&transaction, &entries).await.unwrap();
+    }
+
+    fn account_totals(account_type: AccountType, code: Option<&str>, parent_code: Option<&str>, debit_total: Decimal, credit_total: Decimal) -> AccountTotals {
+        AccountTotals {
+            account_id: Uuid::new_v4(),
+            account_type,
+            code: code.map(str::to_string),
+            parent_code: parent_code.map(str::to_string),
+            name: None,
+            debit_total,
+            credit_total,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_balance_sheet_and_income_statement_from_store() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+
+        let current = chart_account(AccountType::Asset, "1000", None);
+        let cash = chart_account(AccountType::Asset, "1100", Some("1000"));
+        let bank = chart_account(AccountType::Asset, "1200", Some("1000"));
+        let wallet = chart_account(AccountType::Liability, "2000", None);
+        let capital = chart_account(AccountType::Equity, "3000", None);
+        let fees = chart_account(AccountType::Revenue, "4000", None);
+        let costs = chart_account(AccountType::Expense, "5000", None);
+        for account in [&current, &cash, &bank, &wallet, &capital, &fees, &costs] {
+            store.create_account(account).await.unwrap();
+        }
+
+        post(&store, cash.id, capital.id, dec!(500.00), "2026-01-10T09:00:00Z", "fs_1").await;
+        post(&store, bank.id, wallet.id, dec!(200.00), "2026-01-15T09:00:00Z", "fs_2").await;
+        post(&store, wallet.id, fees.id, dec!(20.00), "2026-02-01T00:00:00Z", "fs_3").await;
+        post(&store, costs.id, cash.id, dec!(5.00), "2026-02-10T09:00:00Z", "fs_4").await;
+        post(&store, wallet.id, fees.id, dec!(7.00), "2026-03-05T09:00:00Z", "fs_5").await;
+
+        let sheet = balance_sheet(&store, "2026-03-31T23:59:59Z".parse().unwrap(), "USD").await.unwrap();
+        assert_eq!(sheet.assets.total, dec!(695.00));
+        assert_eq!(sheet.liabilities.total, dec!(173.00));
+        assert_eq!(sheet.equity.total, dec!(500.00));
+        assert_eq!(sheet.retained_earnings, dec!(22.00));
+        assert_eq!(sheet.total_equity, dec!(522.00));
+        assert!(sheet.is_balanced);
+
+        // Children sit under their parent, which rolls them up
+        let lines: Vec<(Uuid, usize, Decimal, Decimal)> = sheet
+            .assets
+            .lines
+            .iter()
+            .map(|line| (line.account_id, line.depth, line.balance, line.rollup_balance))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (current.id, 0, dec!(0), dec!(695.00)),
+                (cash.id, 1, dec!(495.00), dec!(495.00)),
+                (bank.id, 1, dec!(200.00), dec!(200.00)),
+            ]
+        );
+
+        // Earlier figures leave out later postings
+        let sheet = balance_sheet(&store, "2026-01-31T23:59:59Z".parse().unwrap(), "USD").await.unwrap();
+        assert_eq!(sheet.assets.total, dec!(700.00));
+        assert_eq!(sheet.retained_earnings, dec!(0));
+        assert!(sheet.is_balanced);
+
+        // Periods are (start, end], so the fee posted at exactly midnight on
+        // 1 February closes January
+        let january = income_statement(
+            &store,
+            "2026-01-01T00:00:00Z".parse().unwrap(),
+            "2026-02-01T00:00:00Z".parse().unwrap(),
+            "USD",
+        )
+        .await
+        .unwrap();
+        assert_eq!(january.revenue.total, dec!(20.00));
+        assert_eq!(january.expenses.total, dec!(0));
+        assert_eq!(january.net_income, dec!(20.00));
+        assert_eq!(january.revenue.lines[0].account_id, fees.id);
+
+        let february = income_statement(
+            &store,
+            "2026-02-01T00:00:00Z".parse().unwrap(),
+            "2026-03-01T00:00:00Z".parse().unwrap(),
+            "USD",
+        )
+        .await
+        .unwrap();
+        assert_eq!(february.revenue.total, dec!(0));
+        assert_eq!(february.expenses.total, dec!(5.00));
+        assert_eq!(february.net_income, dec!(-5.00));
+    }
+
+    #[test]
+    fn test_sections_roll_up_the_chart_of_accounts() {
+        let as_of = "2026-03-31T23:59:59Z".parse().unwrap();
+        let totals = vec![
+            account_totals(AccountType::Asset, None, None, dec!(3.00), dec!(0)),
+            account_totals(AccountType::Asset, Some("1110"), Some("1100"), dec!(40.00), dec!(10.00)),
+            account_totals(AccountType::Asset, Some("1100"), Some("1000"), dec!(100.00), dec!(0)),
+            account_totals(AccountType::Asset, Some("1000"), None, dec!(0), dec!(0)),
+            // Parent is not an asset account, so this is a root of its own
+            account_totals(AccountType::Asset, Some("1900"), Some("2000"), dec!(7.00), dec!(0)),
+            account_totals(AccountType::Liability, Some("2000"), None, dec!(0), dec!(150.00)),
+        ];
+        let sheet = BalanceSheet::from_totals(as_of, "USD", &totals);
+
+        let lines: Vec<(Option<&str>, usize, Decimal, Decimal)> = sheet
+            .assets
+            .lines
+            .iter()
+            .map(|line| (line.code.as_deref(), line.depth, line.balance, line.rollup_balance))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (Some("1000"), 0, dec!(0), dec!(130.00)),
+                (Some("1100"), 1, dec!(100.00), dec!(130.00)),
+                (Some("1110"), 2, dec!(30.00), dec!(30.00)),
+                (Some("1900"), 0, dec!(7.00), dec!(7.00)),
+                (None, 0, dec!(3.00), dec!(3.00)),
+            ]
+        );
+        // Section totals count each account once, not its roll-ups
+        assert_eq!(sheet.assets.total, dec!(140.00));
+        assert_eq!(sheet.liabilities.total, dec!(150.00));
+        assert!(!sheet.is_balanced);
+    }
+}
+```
@@ -0,0 +1,183 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use ledger_core::grpc::{pb, LedgerClient, LedgerGrpcService};
+    use ledger_core::*;
+    use super::common;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::transport::Channel;
+    use tonic::Code;
+
+    // Serves the gRPC API on an ephemeral local port for the test's lifetime
+    async fn setup_client(docker: &Cli) -> (LedgerClient<Channel>, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+
+        let store = PostgresLedgerStore::connect(pool).await.expect("Schema check failed");
+        let service = Arc::new(LedgerService::new(Box::new(store)));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(LedgerGrpcService::new(service).into_server())
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        let client = LedgerClient::connect(format!("http://{}", addr))
+            .await
+            .expect("Failed to connect to the gRPC server");
+        (client, node)
+    }
+
+    async fn create_account(client: &mut LedgerClient<Channel>) -> String {
+        client
+            .create_account(pb::CreateAccountRequest {
+                account_type: pb::AccountType::Asset as i32,
+                currency: "USD".to_string(),
+                metadata_json: String::new(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .id
+    }
+
+    fn credit(account_id: &str, amount: &str, key: &str) -> pb::RecordTransactionRequest {
+        pb::RecordTransactionRequest {
+            transaction_type: pb::TransactionType::Credit as i32,
+            amount: amount.to_string(),
+            source_account_id: None,
+            destination_account_id: Some(account_id.to_string()),
+            reason_code: "initial_deposit".to_string(),
+            idempotency_key: key.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transfer_and_balance() {
+        let docker = Cli::default();
+        let (mut client, _node) = setup_client(&docker).await;
+        let wallet1 = create_account(&mut client).await;
+        let wallet2 = create_account(&mut client).await;
+
+        client.record_transaction(credit(&wallet1, "1000.00", "deposit_001")).await.unwrap();
+        let transfer = client
+            .record_transaction(pb::RecordTransactionRequest {
+                transaction_type: pb::TransactionType::Transfer as i32,
+                amount: "250.00".to_string(),
+                source_account_id: Some(wallet1.clone()),
+                destination_account_id: Some(wallet2.clone()),
+                reason_code: "p2p".to_string(),
+                idempotency_key: "transfer_001".to_string(),
+            })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(transfer.transaction_type, pb::TransactionType::Transfer as i32);
+
+        for (account_id, expected) in [(&wallet1, "750.00"), (&wallet2, "250.00")] {
+            let balance = client
+                .get_balance(pb::GetBalanceRequest { account_id: account_id.clone() })
+                .await
+                .unwrap()
+                .into_inner();
+            assert_eq!(balance.balance, expected);
+            assert_eq!(balance.currency, "USD");
+        }
+
+        let entries: Vec<pb::AccountEntry> = {
+            let mut stream = client
+                .stream_account_entries(pb::StreamAccountEntriesRequest {
+                    account_id: wallet1.clone(),
+                    from: None,
+                    to: None,
+                    oldest_first: true,
+                })
+                .await
+                .unwrap()
+                .into_inner();
+            let mut entries = Vec::new();
+            while let Some(entry) = stream.message().await.unwrap() {
+                entries.push(entry);
+            }
+            entries
+        };
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].transaction.as_ref().unwrap().id, transfer.id);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_replays_or_conflicts() {
+        let docker = Cli::default();
+        let (mut client, _node) = setup_client(&docker).await;
+        let wallet = create_account(&mut client).await;
+
+        let first = client.record_transaction(credit(&wallet, "10.00", "deposit_42")).await.unwrap().into_inner();
+        let replay = client.record_transaction(credit(&wallet, "10.00", "deposit_42")).await.unwrap().into_inner();
+        assert_eq!(first.id, replay.id);
+
+        let status = client
+            .record_transaction(credit(&wallet, "99.00", "deposit_42"))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::AlreadyExists);
+
+        let balance = client
+            .get_balance(pb::GetBalanceRequest { account_id: wallet })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(balance.balance, "10.00");
+    }
+
+    #[tokio::test]
+    async fn test_errors_map_to_status_codes() {
+        let docker = Cli::default();
+        let (mut client, _node) = setup_client(&docker).await;
+        let wallet = create_account(&mut client).await;
+
+        // Valid JSON that is not an object
+        for metadata_json in ["[1, 2]", "\"text\"", "not json"] {
+            let status = client
+                .create_account(pb::CreateAccountRequest {
+                    account_type: pb::AccountType::Asset as i32,
+                    currency: "USD".to_string(),
+                    metadata_json: metadata_json.to_string(),
+                })
+                .await
+                .unwrap_err();
+            assert_eq!(status.code(), Code::InvalidArgument, "{}", metadata_json);
+        }
+
+        let status = client
+            .record_transaction(pb::RecordTransactionRequest {
+                transaction_type: pb::TransactionType::Debit as i32,
+                amount: "5.00".to_string(),
+                source_account_id: Some(wallet.clone()),
+                destination_account_id: None,
+                reason_code: "withdrawal".to_string(),
+                idempotency_key: "overdraft_attempt".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+
+        let status = client
+            .record_transaction(credit(&wallet, "10.00", ""))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+
+        let status = client
+            .get_account(pb::GetAccountRequest { account_id: uuid::Uuid::new_v4().to_string() })
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::NotFound);
+    }
+}
+```
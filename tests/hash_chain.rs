@@ -0,0 +1,121 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use sqlx::{Executor, PgPool};
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    // Three deposits, chained as seq 1, 2 and 3
+    async fn chained_deposits(pool: &PgPool) -> (PostgresLedgerStore, Vec<Uuid>) {
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool.clone())));
+        let account = service.create_account(AccountType::Liability, "USD").await.unwrap();
+        let mut transaction_ids = Vec::new();
+        for i in 1..=3 {
+            let transaction = service
+                .credit_account(account.id, dec!(10.00), "deposit", &format!("chain_{}", i))
+                .await
+                .unwrap();
+            transaction_ids.push(transaction.id);
+        }
+
+        let store = PostgresLedgerStore::new(pool.clone());
+        let links = store.get_chain_links(0, 10).await.unwrap();
+        let chained: Vec<Uuid> = links.iter().map(|link| link.transaction_id).collect();
+        assert_eq!(chained, transaction_ids);
+        let report = verify_chain(&store).await.unwrap();
+        assert!(report.is_intact(), "{:?}", report.violations);
+        (store, transaction_ids)
+    }
+
+    // Runs `sql` the way someone with superuser access could, with the
+    // append-only triggers switched off
+    async fn tamper(pool: &PgPool, sql: &str, transaction_id: Uuid) {
+        let mut db_transaction = pool.begin().await.unwrap();
+        db_transaction.execute("SET LOCAL session_replication_role = replica").await.unwrap();
+        let result = sqlx::query(sql).bind(transaction_id).execute(&mut *db_transaction).await.unwrap();
+        assert!(result.rows_affected() > 0, "{} changed nothing", sql);
+        db_transaction.commit().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_edited_entry_is_a_hash_mismatch() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let (store, transaction_ids) = chained_deposits(&pool).await;
+
+        tamper(&pool, "UPDATE entries SET amount = 1000 WHERE transaction_id = $1", transaction_ids[1]).await;
+
+        // Only the edited link fails; later links still point at its stored hash
+        let report = verify_chain(&store).await.unwrap();
+        assert_eq!(report.links_checked, 3);
+        assert_eq!(report.violations.len(), 1, "{:?}", report.violations);
+        assert!(matches!(
+            report.violations[0],
+            ChainViolation::HashMismatch { seq: 2, transaction_id } if transaction_id == transaction_ids[1]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deleted_link_is_a_sequence_gap() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let (store, transaction_ids) = chained_deposits(&pool).await;
+
+        tamper(&pool, "DELETE FROM transaction_chain WHERE transaction_id = $1", transaction_ids[1]).await;
+
+        let report = verify_chain(&store).await.unwrap();
+        assert_eq!(report.links_checked, 2);
+        assert_eq!(report.violations.len(), 3, "{:?}", report.violations);
+        assert!(matches!(
+            report.violations[0],
+            ChainViolation::SequenceGap { expected_seq: 2, found_seq: 3 }
+        ));
+        assert!(matches!(
+            report.violations[1],
+            ChainViolation::PrevHashMismatch { seq: 3, transaction_id } if transaction_id == transaction_ids[2]
+        ));
+        assert!(matches!(
+            report.violations[2],
+            ChainViolation::UnchainedTransaction { transaction_id } if transaction_id == transaction_ids[1]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rewritten_chain_is_caught_by_its_anchor() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let (store, transaction_ids) = chained_deposits(&pool).await;
+
+        let anchor = store.create_chain_anchor().await.unwrap().expect("chain has a head");
+        assert_eq!(anchor.seq, 3);
+
+        // Edit the head transaction and recompute its link, so the chain
+        // itself is consistent again
+        tamper(&pool, "UPDATE entries SET amount = 1000 WHERE transaction_id = $1", transaction_ids[2]).await;
+        let transaction = store.get_transaction(&transaction_ids[2]).await.unwrap().unwrap();
+        let entries = store.get_entries_for_transaction(&transaction_ids[2]).await.unwrap();
+        let head = store.get_chain_links(2, 1).await.unwrap().remove(0);
+        let rewritten = chain_hash(&head.prev_hash, &transaction, &entries);
+        let mut db_transaction = pool.begin().await.unwrap();
+        db_transaction.execute("SET LOCAL session_replication_role = replica").await.unwrap();
+        sqlx::query("UPDATE transaction_chain SET hash = $1 WHERE seq = 3")
+            .bind(&rewritten)
+            .execute(&mut *db_transaction)
+            .await
+            .unwrap();
+        db_transaction.commit().await.unwrap();
+
+        let report = verify_chain(&store).await.unwrap();
+        assert_eq!(report.violations.len(), 1, "{:?}", report.violations);
+        assert!(matches!(
+            report.violations[0],
+            ChainViolation::AnchorMismatch { anchor_id, seq: 3 } if anchor_id == anchor.id
+        ));
+    }
+}
+```
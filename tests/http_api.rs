@@ -0,0 +1,318 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use http_body_util::BodyExt;
+    use ledger_core::*;
+    use super::common;
+    use serde_json::{json, Value};
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+    use tower::ServiceExt;
+
+    async fn setup_router(docker: &Cli) -> (axum::Router, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+
+        let store = PostgresLedgerStore::connect(pool).await.expect("Schema check failed");
+        let service = Arc::new(LedgerService::new(Box::new(store)));
+        (http::router(service), node)
+    }
+
+    async fn send(
+        router: &axum::Router,
+        method: &str,
+        uri: &str,
+        idempotency_key: Option<&str>,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        if let Some(key) = idempotency_key {
+            request = request.header(http::IDEMPOTENCY_KEY_HEADER, key);
+        }
+        let body = body.map(|b| Body::from(b.to_string())).unwrap_or_else(Body::empty);
+
+        let response = router
+            .clone()
+            .oneshot(request.body(body).unwrap())
+            .await
+            .expect("Request failed");
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let value = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).expect("Response must be JSON")
+        };
+        (status, value)
+    }
+
+    async fn create_account(router: &axum::Router) -> String {
+        let (status, body) = send(
+            router,
+            "POST",
+            "/accounts",
+            None,
+            Some(json!({"account_type": "Asset", "currency": "USD"})),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        body["id"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_credit_transfer_and_balance() {
+        let docker = Cli::default();
+        let (router, _node) = setup_router(&docker).await;
+        let wallet1 = create_account(&router).await;
+        let wallet2 = create_account(&router).await;
+
+        let (status, _) = send(
+            &router,
+            "POST",
+            "/transactions",
+            Some("deposit_001"),
+            Some(json!({
+                "transaction_type": "Credit",
+                "amount": "1000.00",
+                "destination_account_id": wallet1,
+                "reason_code": "initial_deposit"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, _) = send(
+            &router,
+            "POST",
+            "/transactions",
+            Some("transfer_001"),
+            Some(json!({
+                "transaction_type": "Transfer",
+                "amount": "250.00",
+                "source_account_id": wallet1,
+                "destination_account_id": wallet2,
+                "reason_code": "p2p"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = send(&router, "GET", &format!("/accounts/{}/balance", wallet1), None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["balance"], json!("750.00"));
+
+        let (status, body) = send(&router, "GET", &format!("/accounts/{}/balance", wallet2), None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["account_id"], json!(wallet2));
+        assert_eq!(body["currency"], json!("USD"));
+        assert_eq!(body["balance"], json!("250.00"));
+
+        let (status, body) = send(
+            &router,
+            "GET",
+            &format!("/accounts/{}/transactions?limit=1", wallet1),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["items"].as_array().unwrap().len(), 1);
+        let cursor = body["next_cursor"].as_str().expect("A second page must exist");
+
+        let (status, body) = send(
+            &router,
+            "GET",
+            &format!("/accounts/{}/transactions?limit=1&cursor={}", wallet1, cursor),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["items"].as_array().unwrap().len(), 1);
+        assert!(body["next_cursor"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_replays_original_transaction() {
+        let docker = Cli::default();
+        let (router, _node) = setup_router(&docker).await;
+        let wallet = create_account(&router).await;
+        let request = json!({
+            "transaction_type": "Credit",
+            "amount": "10.00",
+            "destination_account_id": wallet,
+            "reason_code": "initial_deposit"
+        });
+
+        let (status, first) = send(&router, "POST", "/transactions", Some("deposit_42"), Some(request.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, replay) = send(&router, "POST", "/transactions", Some("deposit_42"), Some(request)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["id"], replay["id"]);
+
+        let (_, page) = send(&router, "GET", &format!("/accounts/{}/transactions", wallet), None, None).await;
+        assert_eq!(page["items"].as_array().unwrap().len(), 1);
+
+        // The same key with a different body is refused
+        let (status, body) = send(
+            &router,
+            "POST",
+            "/transactions",
+            Some("deposit_42"),
+            Some(json!({
+                "transaction_type": "Credit",
+                "amount": "99.00",
+                "destination_account_id": wallet,
+                "reason_code": "initial_deposit"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["error"]["code"], json!("idempotency_violation"));
+    }
+
+    #[tokio::test]
+    async fn test_retried_debit_replays_after_account_is_drained() {
+        let docker = Cli::default();
+        let (router, _node) = setup_router(&docker).await;
+        let wallet = create_account(&router).await;
+        let deposit = json!({
+            "transaction_type": "Credit",
+            "amount": "10.00",
+            "destination_account_id": wallet,
+            "reason_code": "initial_deposit"
+        });
+        let withdrawal = json!({
+            "transaction_type": "Debit",
+            "amount": "10.00",
+            "source_account_id": wallet,
+            "reason_code": "withdrawal"
+        });
+
+        let (status, _) = send(&router, "POST", "/transactions", Some("deposit_1"), Some(deposit)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, first) = send(&router, "POST", "/transactions", Some("withdrawal_1"), Some(withdrawal.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        // The balance is now zero, but the retry is not a new debit
+        let (status, replay) = send(&router, "POST", "/transactions", Some("withdrawal_1"), Some(withdrawal)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["id"], replay["id"]);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_structured() {
+        let docker = Cli::default();
+        let (router, _node) = setup_router(&docker).await;
+        let wallet1 = create_account(&router).await;
+        let wallet2 = create_account(&router).await;
+
+        // Missing Idempotency-Key
+        let (status, body) = send(
+            &router,
+            "POST",
+            "/transactions",
+            None,
+            Some(json!({
+                "transaction_type": "Credit",
+                "amount": "10.00",
+                "destination_account_id": wallet1,
+                "reason_code": "initial_deposit"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], json!("missing_idempotency_key"));
+
+        // Overdraft
+        let (status, body) = send(
+            &router,
+            "POST",
+            "/transactions",
+            Some("overdraft_attempt"),
+            Some(json!({
+                "transaction_type": "Transfer",
+                "amount": "1000.00",
+                "source_account_id": wallet1,
+                "destination_account_id": wallet2,
+                "reason_code": "p2p"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["error"]["code"], json!("insufficient_balance"));
+
+        // Non-positive amount
+        let (status, body) = send(
+            &router,
+            "POST",
+            "/transactions",
+            Some("zero_amount"),
+            Some(json!({
+                "transaction_type": "Credit",
+                "amount": "0",
+                "destination_account_id": wallet1,
+                "reason_code": "initial_deposit"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["error"]["code"], json!("invalid_transaction"));
+
+        // Unknown account
+        let (status, body) = send(
+            &router,
+            "GET",
+            &format!("/accounts/{}/balance", uuid::Uuid::new_v4()),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"]["code"], json!("account_not_found"));
+
+        // Bad cursor
+        let (status, body) = send(
+            &router,
+            "GET",
+            &format!("/accounts/{}/transactions?cursor=zz", wallet1),
+            None,
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["error"]["code"], json!("invalid_cursor"));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_discrepancies() {
+        let docker = Cli::default();
+        let (router, _node) = setup_router(&docker).await;
+        let wallet = create_account(&router).await;
+
+        let (status, body) = send(
+            &router,
+            "POST",
+            "/reconcile",
+            None,
+            Some(json!({
+                "account_ids": [wallet],
+                "expected_balances": [{"account_id": wallet, "balance": "5.00"}]
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        let line = &body["account_balances"][0];
+        assert_eq!(line["account_id"], json!(wallet));
+        assert!(!line["discrepancy"].is_null());
+    }
+}
+```
@@ -0,0 +1,59 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+
+    #[tokio::test]
+    async fn test_service_postings_verify_clean() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool.clone())));
+
+        let wallet1 = service.create_account(AccountType::Liability, "USD").await.unwrap();
+        let wallet2 = service.create_account(AccountType::Liability, "USD").await.unwrap();
+
+        service.credit_account(wallet1.id, dec!(1000.00), "deposit", "int_1").await.unwrap();
+        service.transfer(wallet1.id, wallet2.id, dec!(250.00), "p2p", "int_2").await.unwrap();
+        let withdrawal = service
+            .debit_account(wallet2.id, dec!(100.00), "withdrawal", "int_3")
+            .await
+            .unwrap();
+        service.reverse_transaction(withdrawal.id, "failed_payout", "int_4").await.unwrap();
+
+        assert_eq!(service.get_account_balance(wallet1.id).await.unwrap(), dec!(750.00));
+        assert_eq!(service.get_account_balance(wallet2.id).await.unwrap(), dec!(250.00));
+
+        // balance_after, current balances and leg totals all agree
+        let report = verify_ledger(&PostgresLedgerStore::new(pool)).await.unwrap();
+        assert!(report.is_clean(), "{:?}", report.violations);
+        assert_eq!(report.transactions_checked, 4);
+        assert_eq!(report.entries_checked, 5);
+    }
+
+    #[tokio::test]
+    async fn test_transaction_is_reversed_at_most_once() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool)));
+
+        let wallet = service.create_account(AccountType::Liability, "USD").await.unwrap();
+        let deposit = service.credit_account(wallet.id, dec!(100.00), "deposit", "rev_1").await.unwrap();
+        let reversal = service.reverse_transaction(deposit.id, "chargeback", "rev_2").await.unwrap();
+
+        let result = service.reverse_transaction(deposit.id, "chargeback", "rev_3").await;
+        assert!(matches!(
+            result,
+            Err(LedgerError::AlreadyReversed { reversal_id }) if reversal_id == reversal.id
+        ));
+        let result = service.reverse_transaction(reversal.id, "chargeback", "rev_4").await;
+        assert!(matches!(result, Err(LedgerError::CannotReverseReversal)));
+
+        assert_eq!(service.get_account_balance(wallet.id).await.unwrap(), dec!(0));
+    }
+}
+```
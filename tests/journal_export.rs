@@ -0,0 +1,109 @@
+```rust
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use rust_decimal_macros::dec;
+
+    fn chart_account(account_type: AccountType, metadata: serde_json::Value) -> Account {
+        let mut account = Account::new(account_type, "USD");
+        account.metadata = metadata;
+        account.created_at = "2026-01-01T00:00:00Z".parse().unwrap();
+        account
+    }
+
+    fn fixture() -> (Vec<Account>, Vec<(Transaction, Vec<Entry>)>) {
+        let current = chart_account(AccountType::Asset, serde_json::json!({ "code": "1000", "name": "Current Assets" }));
+        let cash = chart_account(
+            AccountType::Asset,
+            serde_json::json!({ "code": "1100", "name": "Cash", "parent_code": "1000" }),
+        );
+        let fees = chart_account(AccountType::Revenue, serde_json::json!({ "name": "fee income" }));
+
+        let mut transfer = Transaction::new(
+            TransactionType::Transfer,
+            dec!(25.00),
+            Some(fees.id),
+            Some(cash.id),
+            "card_fee",
+            "fee-1",
+        );
+        transfer.timestamp = "2026-02-03T10:00:00Z".parse().unwrap();
+        transfer.metadata = serde_json::json!({ "merchant": "Acme, Inc.", "batch": 7 });
+        let transfer_entries = vec![
+            Entry::new(transfer.id, cash.id, dec!(25.00), EntryType::Debit, dec!(-25.00)),
+            Entry::new(transfer.id, fees.id, dec!(25.00), EntryType::Credit, dec!(25.00)),
+        ];
+
+        let mut credit = Transaction::new(TransactionType::Credit, dec!(4.50), None, Some(cash.id), "adjustment", "adj-1");
+        credit.timestamp = "2026-02-01T09:00:00Z".parse().unwrap();
+        let credit_entries = vec![Entry::new(credit.id, cash.id, dec!(4.50), EntryType::Credit, dec!(4.50))];
+
+        (vec![current, cash, fees], vec![(transfer, transfer_entries), (credit, credit_entries)])
+    }
+
+    #[test]
+    fn test_account_names_follow_type_and_chart() {
+        let (accounts, _) = fixture();
+        let names = journal_account_names(&accounts);
+        assert_eq!(names[&accounts[0].id], "Assets:1000-Current-Assets");
+        assert_eq!(names[&accounts[1].id], "Assets:1000-Current-Assets:1100-Cash");
+        assert_eq!(names[&accounts[2].id], "Income:Fee-income");
+    }
+
+    #[test]
+    fn test_shared_account_names_get_an_id_suffix() {
+        let wallet1 = chart_account(AccountType::Asset, serde_json::json!({ "name": "Primary Wallet" }));
+        let wallet2 = chart_account(AccountType::Asset, serde_json::json!({ "name": "Primary Wallet" }));
+        let savings = chart_account(AccountType::Asset, serde_json::json!({ "name": "Savings" }));
+        let names = journal_account_names(&[wallet1.clone(), wallet2.clone(), savings.clone()]);
+
+        for wallet in [&wallet1, &wallet2] {
+            let suffix = wallet.id.simple().to_string()[..8].to_ascii_uppercase();
+            assert_eq!(names[&wallet.id], format!("Assets:Primary-Wallet-{}", suffix));
+        }
+        assert_ne!(names[&wallet1.id], names[&wallet2.id]);
+        assert_eq!(names[&savings.id], "Assets:Savings");
+    }
+
+    #[test]
+    fn test_ledger_journal() {
+        let (accounts, transactions) = fixture();
+        let journal = render_journal(JournalFormat::Ledger, &accounts, &transactions);
+        let transfer = &transactions[0].0;
+
+        assert!(journal.contains("account Assets:1000-Current-Assets:1100-Cash\n"));
+        assert!(journal.contains(&format!("2026-02-03 * ({}) card_fee\n", transfer.id)));
+        assert!(journal.contains("    ; reason_code: card_fee\n"));
+        assert!(journal.contains("    ; merchant: Acme, Inc.\n"));
+        assert!(journal.contains("    ; batch: 7\n"));
+        assert!(journal.contains("    Assets:1000-Current-Assets:1100-Cash  25 USD\n"));
+        assert!(journal.contains("    Income:Fee-income  -25 USD\n"));
+
+        // Sorted by date, and the one-legged credit is balanced by suspense
+        assert!(journal.find("adj-1").unwrap() < journal.find("fee-1").unwrap());
+        assert!(journal.contains("    Assets:1000-Current-Assets:1100-Cash  -4.5 USD\n    Equity:Suspense  4.5 USD\n"));
+        assert_eq!(journal.matches("Equity:Suspense  ").count(), 1);
+    }
+
+    #[test]
+    fn test_hledger_tags_stop_at_commas() {
+        let (accounts, transactions) = fixture();
+        let journal = render_journal(JournalFormat::Hledger, &accounts, &transactions);
+        assert!(journal.contains("    ; merchant: Acme; Inc.\n"));
+    }
+
+    #[test]
+    fn test_beancount_journal() {
+        let (accounts, transactions) = fixture();
+        let journal = render_journal(JournalFormat::Beancount, &accounts, &transactions);
+
+        assert!(journal.contains("2026-01-01 open Assets:1000-Current-Assets:1100-Cash USD\n"));
+        assert!(journal.contains("2026-01-01 open Equity:Suspense\n"));
+        assert!(journal.contains("2026-02-03 * \"card_fee\" \"\"\n"));
+        assert!(journal.contains("  idempotency_key: \"fee-1\"\n"));
+        assert!(journal.contains("  merchant: \"Acme, Inc.\"\n"));
+        assert!(journal.contains("  Income:Fee-income  -25 USD\n"));
+        assert!(journal.contains("  Equity:Suspense  4.5 USD\n"));
+    }
+}
+```
@@ -0,0 +1,193 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+
+    const BEANCOUNT: &str = r#"
+option "operating_currency" "USD"
+
+2026-01-01 open Assets:Bank:Checking USD
+2026-01-01 open Equity:Opening-Balances USD
+
+2026-01-01 * "Opening balance"
+  Assets:Bank:Checking  1000.00 USD
+  Equity:Opening-Balances
+
+2026-01-05 * "Office Depot" "Printer paper"
+  reason_code: "supplies"
+  ticket: "FIN-12"
+  Expenses:Office  45.20 USD
+  Assets:Bank:Checking  -45.20 USD ; paid by card
+
+2026-01-06 balance Assets:Bank:Checking  954.80 USD
+
+2026-01-07 * "Broken"
+  Expenses:Office  10.00 USD
+  Assets:Bank:Checking  -9.00 USD
+"#;
+
+    const LEDGER: &str = "\
+account Assets:Petty Cash
+
+2026/02/01 * (INV-7) Coffee beans
+    ; reason_code: pantry
+    Expenses:Pantry    $12.50
+    Assets:Petty Cash
+
+= expr true
+    (Budget)    -1
+
+2026/02/02 Rent
+    Expenses:Rent    1,200.00 EUR
+    Liabilities:Payable    EUR -1200
+";
+
+    async fn setup_store(docker: &Cli) -> (PostgresLedgerStore, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+        (PostgresLedgerStore::new(pool), node)
+    }
+
+    #[test]
+    fn test_parse_beancount() {
+        let journal = parse_journal(JournalFormat::Beancount, BEANCOUNT);
+        assert_eq!(journal.accounts["Assets:Bank:Checking"], Some("USD".to_string()));
+        assert_eq!(journal.transactions.len(), 3);
+
+        let opening = journal.transactions[0].as_ref().unwrap();
+        assert_eq!(opening.line, 7);
+        assert_eq!(opening.postings[1].amount, Some(dec!(-1000.00)));
+        assert_eq!(opening.postings[1].currency.as_deref(), Some("USD"));
+
+        let supplies = journal.transactions[1].as_ref().unwrap();
+        assert_eq!(supplies.description, "Office Depot Printer paper");
+        assert_eq!(supplies.metadata["reason_code"], "supplies");
+        assert_eq!(supplies.metadata["ticket"], "FIN-12");
+        // Posted at import time, with the journal date kept alongside
+        assert_eq!(supplies.metadata["effective_date"], "2026-01-05");
+        assert!(supplies.timestamp > chrono::Utc::now() - chrono::Duration::minutes(5));
+        assert_eq!(supplies.postings.len(), 2);
+
+        let (line, errors) = journal.transactions[2].as_ref().unwrap_err();
+        assert_eq!(*line, 19);
+        assert!(errors[0].message.contains("does not balance"));
+    }
+
+    #[test]
+    fn test_parse_ledger() {
+        let journal = parse_journal(JournalFormat::Ledger, LEDGER);
+        assert!(journal.accounts.contains_key("Assets:Petty Cash"));
+        // The automated transaction is skipped, not imported
+        assert_eq!(journal.transactions.len(), 2);
+
+        let coffee = journal.transactions[0].as_ref().unwrap();
+        assert_eq!(coffee.description, "Coffee beans");
+        assert_eq!(coffee.metadata["reason_code"], "pantry");
+        assert_eq!(coffee.postings[0].currency.as_deref(), Some("USD"));
+        assert_eq!(coffee.postings[1].account, "Assets:Petty Cash");
+        assert_eq!(coffee.postings[1].amount, Some(dec!(-12.50)));
+
+        let rent = journal.transactions[1].as_ref().unwrap();
+        assert_eq!(rent.postings[0].amount, Some(dec!(1200.00)));
+        assert_eq!(rent.postings[1].amount, Some(dec!(-1200)));
+        assert_eq!(rent.postings[1].currency.as_deref(), Some("EUR"));
+    }
+
+    #[test]
+    fn test_idempotency_keys_depend_on_source_and_line() {
+        assert_eq!(journal_idempotency_key("books.beancount", 7), journal_idempotency_key("books.beancount", 7));
+        assert_ne!(journal_idempotency_key("books.beancount", 7), journal_idempotency_key("books.beancount", 8));
+        assert_ne!(journal_idempotency_key("books.beancount", 7), journal_idempotency_key("other.beancount", 7));
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_accounts_and_is_repeatable() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let mut options = JournalImportOptions {
+            source: "books.beancount".to_string(),
+            dry_run: true,
+            mode: BatchMode::BestEffort,
+        };
+
+        let report = import_journal(&store, JournalFormat::Beancount, BEANCOUNT, &options).await.unwrap();
+        assert!(!report.posted);
+        assert_eq!(report.accounts_created.len(), 3);
+        assert!(store.list_accounts().await.unwrap().is_empty());
+
+        options.dry_run = false;
+        let report = import_journal(&store, JournalFormat::Beancount, BEANCOUNT, &options).await.unwrap();
+        assert!(report.posted);
+        assert!(matches!(report.transactions[0].status, ImportRowStatus::Recorded { .. }));
+        assert!(matches!(report.transactions[1].status, ImportRowStatus::Recorded { .. }));
+        assert!(matches!(report.transactions[2].status, ImportRowStatus::Rejected { .. }));
+
+        let accounts = store.list_accounts().await.unwrap();
+        let by_name = |name: &str| {
+            accounts
+                .iter()
+                .find(|account| account.metadata["journal_account"] == name)
+                .unwrap()
+                .clone()
+        };
+        let checking = by_name("Assets:Bank:Checking");
+        let office = by_name("Expenses:Office");
+        assert_eq!(checking.account_type, AccountType::Asset);
+        assert_eq!(office.account_type, AccountType::Expense);
+        // Journal debits stay debits, so stored balances (credits minus
+        // debits) come out negated
+        assert_eq!(store.get_account_balance(&checking.id).await.unwrap(), dec!(-954.80));
+        assert_eq!(store.get_account_balance(&office.id).await.unwrap(), dec!(-45.20));
+
+        let key = journal_idempotency_key("books.beancount", 11);
+        let supplies = store.get_transaction_by_key(&key).await.unwrap().unwrap();
+        assert_eq!(supplies.transaction_type, TransactionType::Adjustment);
+        assert_eq!(supplies.reason_code, "supplies");
+        assert_eq!(supplies.metadata["ticket"], "FIN-12");
+        assert_eq!(store.get_entries_for_transaction(&supplies.id).await.unwrap().len(), 2);
+
+        // A second import finds the accounts and posts nothing new
+        let report = import_journal(&store, JournalFormat::Beancount, BEANCOUNT, &options).await.unwrap();
+        assert!(report.accounts_created.is_empty());
+        assert!(matches!(report.transactions[0].status, ImportRowStatus::Duplicate { .. }));
+        assert!(matches!(report.transactions[1].status, ImportRowStatus::Duplicate { .. }));
+        assert_eq!(store.list_accounts().await.unwrap().len(), 3);
+        assert_eq!(store.get_account_balance(&checking.id).await.unwrap(), dec!(-954.80));
+    }
+
+    #[tokio::test]
+    async fn test_ambiguous_account_names_are_rejected() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        for _ in 0..2 {
+            let mut wallet = Account::new(AccountType::Asset, "USD");
+            wallet.metadata = serde_json::json!({ "journal_account": "Assets:Wallet" });
+            store.create_account(&wallet).await.unwrap();
+        }
+        let journal = "\
+2026-01-01 * \"Top-up\"
+  Assets:Wallet  10.00 USD
+  Equity:Opening-Balances  -10.00 USD
+";
+        let options = JournalImportOptions {
+            source: "wallets.beancount".to_string(),
+            dry_run: false,
+            mode: BatchMode::AllOrNothing,
+        };
+
+        let report = import_journal(&store, JournalFormat::Beancount, journal, &options).await.unwrap();
+        assert!(!report.posted);
+        match &report.transactions[0].status {
+            ImportRowStatus::Rejected { errors } => assert_eq!(errors[0].field.as_deref(), Some("account")),
+            other => panic!("expected rejection, got {:?}", other),
+        }
+        // Equity:Opening-Balances is not created for a rejected import
+        assert_eq!(store.list_accounts().await.unwrap().len(), 2);
+    }
+}
+```
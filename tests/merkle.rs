@@ -0,0 +1,188 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use sqlx::Executor;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    fn sample_transactions(count: usize) -> Vec<(Transaction, Vec<Entry>)> {
+        (0..count)
+            .map(|i| {
+                let from = Uuid::new_v4();
+                let to = Uuid::new_v4();
+                let transaction = Transaction::new(
+                    TransactionType::Transfer,
+                    dec!(10.00),
+                    Some(from),
+                    Some(to),
+                    "settlement",
+                    &format!("merkle_{}", i),
+                );
+                let entries = vec![
+                    Entry::new(transaction.id, from, dec!(10.00), EntryType::Debit, dec!(0)),
+                    Entry::new(transaction.id, to, dec!(10.00), EntryType::Credit, dec!(10.00)),
+                ];
+                (transaction, entries)
+            })
+            .collect()
+    }
+
+    fn proof_for(
+        transactions: &[(Transaction, Vec<Entry>)],
+        tree: &MerkleTree,
+        index: usize,
+    ) -> InclusionProof {
+        let now = chrono::Utc::now();
+        InclusionProof {
+            transaction_id: transactions[index].0.id,
+            period_start: now,
+            period_end: now,
+            leaf_index: index as u64,
+            tree_size: tree.len() as u64,
+            leaf_hash: hex::encode(leaf_hash(&transactions[index].0, &transactions[index].1)),
+            path: tree.proof(index).unwrap().iter().map(hex::encode).collect(),
+            root: hex::encode(tree.root()),
+        }
+    }
+
+    // Debits `from` and credits `to` at `at`
+    async fn post(store: &PostgresLedgerStore, from: Uuid, to: Uuid, amount: Decimal, at: &str) -> Transaction {
+        let at: chrono::DateTime<chrono::Utc> = at.parse().unwrap();
+        let mut transaction = Transaction::new(TransactionType::Transfer, amount, Some(from), Some(to), "settlement", &at.to_rfc3339());
+        transaction.timestamp = at;
+        let mut entries = vec![
+            Entry::new(transaction.id, from, amount, EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, amount, EntryType::Credit, Decimal::ZERO),
+        ];
+        for entry in entries.iter_mut() {
+            entry.timestamp = at;
+        }
+        store.record_transaction(&transaction, &entries).await.unwrap();
+        transaction
+    }
+
+    #[test]
+    fn test_every_leaf_proves_inclusion() {
+        for size in 1..=17 {
+            let transactions = sample_transactions(size);
+            let tree = MerkleTree::new(
+                transactions.iter().map(|(t, e)| leaf_hash(t, e)).collect(),
+            );
+            let root = hex::encode(tree.root());
+
+            for index in 0..size {
+                let proof = proof_for(&transactions, &tree, index);
+                let (transaction, entries) = &transactions[index];
+                assert!(
+                    proof.verify(transaction, entries, &root),
+                    "leaf {} of {} must verify",
+                    index,
+                    size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_modified_transaction_fails_verification() {
+        let transactions = sample_transactions(5);
+        let tree = MerkleTree::new(
+            transactions.iter().map(|(t, e)| leaf_hash(t, e)).collect(),
+        );
+        let root = hex::encode(tree.root());
+        let proof = proof_for(&transactions, &tree, 2);
+
+        let (mut transaction, entries) = transactions[2].clone();
+        transaction.amount = dec!(10000.00);
+        assert!(!proof.verify(&transaction, &entries, &root));
+
+        let (transaction, mut entries) = transactions[2].clone();
+        entries.pop();
+        assert!(!proof.verify(&transaction, &entries, &root));
+    }
+
+    #[test]
+    fn test_proof_does_not_verify_against_another_root() {
+        let transactions = sample_transactions(4);
+        let tree = MerkleTree::new(
+            transactions.iter().map(|(t, e)| leaf_hash(t, e)).collect(),
+        );
+        let proof = proof_for(&transactions, &tree, 1);
+
+        let other = sample_transactions(4);
+        let other_root = hex::encode(
+            MerkleTree::new(other.iter().map(|(t, e)| leaf_hash(t, e)).collect()).root(),
+        );
+
+        let (transaction, entries) = &transactions[1];
+        assert!(!proof.verify(transaction, entries, &other_root));
+    }
+
+    #[tokio::test]
+    async fn test_published_root_proves_the_period_from_the_store() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool.clone());
+        let clearing = Account::new(AccountType::Asset, "USD");
+        let merchant = Account::new(AccountType::Liability, "USD");
+        store.create_account(&clearing).await.unwrap();
+        store.create_account(&merchant).await.unwrap();
+
+        let at_start = post(&store, clearing.id, merchant.id, dec!(1.00), "2026-01-01T00:00:00Z").await;
+        let mut in_period = Vec::new();
+        for at in ["2026-01-10T09:00:00Z", "2026-01-20T09:00:00Z", "2026-02-01T00:00:00Z"] {
+            in_period.push(post(&store, clearing.id, merchant.id, dec!(10.00), at).await);
+        }
+
+        let period_start = "2026-01-01T00:00:00Z".parse().unwrap();
+        let period_end = "2026-02-01T00:00:00Z".parse().unwrap();
+        let settle = chrono::Duration::minutes(5);
+        let published = publish_period_root(&store, period_start, period_end, settle).await.unwrap();
+        assert_eq!(published.tree_size, 3);
+
+        for transaction in &in_period {
+            let proof = inclusion_proof(&store, transaction.id).await.unwrap();
+            assert_eq!(proof.period_end, period_end);
+            let stored = store.get_transaction(&transaction.id).await.unwrap().unwrap();
+            let entries = store.get_entries_for_transaction(&transaction.id).await.unwrap();
+            assert!(proof.verify(&stored, &entries, &published.root));
+        }
+
+        // A transaction at exactly period_start belongs to the period before
+        assert!(matches!(
+            inclusion_proof(&store, at_start.id).await,
+            Err(LedgerError::MerkleRootNotPublished)
+        ));
+
+        // Periods still open, or closed too recently for late postings to
+        // have landed, are refused
+        let now = chrono::Utc::now();
+        for period_end in [now + chrono::Duration::hours(1), now - chrono::Duration::minutes(1)] {
+            assert!(matches!(
+                publish_period_root(&store, now - chrono::Duration::hours(2), period_end, settle).await,
+                Err(LedgerError::PeriodNotSettled { .. })
+            ));
+        }
+
+        // History changed after publication is detected rather than proved
+        let mut db_transaction = pool.begin().await.unwrap();
+        db_transaction.execute("SET LOCAL session_replication_role = replica").await.unwrap();
+        sqlx::query("UPDATE entries SET amount = 1000 WHERE transaction_id = $1")
+            .bind(in_period[1].id)
+            .execute(&mut *db_transaction)
+            .await
+            .unwrap();
+        db_transaction.commit().await.unwrap();
+        assert!(matches!(
+            inclusion_proof(&store, in_period[0].id).await,
+            Err(LedgerError::MerkleRootMismatch)
+        ));
+    }
+}
+```
@@ -0,0 +1,241 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use async_trait::async_trait;
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use sqlx::PgPool;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+
+    async fn setup_store(docker: &Cli) -> (Arc<PostgresLedgerStore>, PgPool, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+        (Arc::new(PostgresLedgerStore::new(pool.clone())), pool, node)
+    }
+
+    // Records every delivered event; fails the first `failures` deliveries
+    struct RecordingSink {
+        delivered: Mutex<Vec<EventRecord>>,
+        failures: Mutex<usize>,
+    }
+
+    impl RecordingSink {
+        fn new(failures: usize) -> Self {
+            Self {
+                delivered: Mutex::new(Vec::new()),
+                failures: Mutex::new(failures),
+            }
+        }
+
+        fn event_types(&self) -> Vec<&'static str> {
+            self.delivered
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|record| record.event.event_type())
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl EventSink for RecordingSink {
+        async fn deliver(&self, event: &EventRecord) -> Result<(), EventSinkError> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(EventSinkError("downstream unavailable".to_string()));
+            }
+            self.delivered.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    async fn record_credit(store: &PostgresLedgerStore, account_id: uuid::Uuid, key: &str) -> Transaction {
+        let transaction = Transaction::new(
+            TransactionType::Credit,
+            dec!(25.00),
+            None,
+            Some(account_id),
+            "deposit",
+            key,
+        );
+        let entries = vec![Entry::new(
+            transaction.id,
+            account_id,
+            dec!(25.00),
+            EntryType::Credit,
+            dec!(25.00),
+        )];
+        store
+            .record_transaction(&transaction, &entries)
+            .await
+            .expect("Failed to record transaction");
+        transaction
+    }
+
+    #[tokio::test]
+    async fn test_events_are_relayed_in_order_and_marked_delivered() {
+        let docker = Cli::default();
+        let (store, pool, _node) = setup_store(&docker).await;
+
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+        let transaction = record_credit(&store, account.id, "outbox_001").await;
+
+        let sink = Arc::new(RecordingSink::new(0));
+        let relay = EventRelay::new(store.clone(), sink.clone());
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 3);
+
+        assert_eq!(
+            sink.event_types(),
+            vec!["AccountCreated", "TransactionRecorded", "BalanceChanged"]
+        );
+        let delivered = sink.delivered.lock().unwrap().clone();
+        assert!(delivered.windows(2).all(|pair| pair[0].seq < pair[1].seq));
+        match &delivered[1].event {
+            LedgerEvent::TransactionRecorded { transaction_id, entries, .. } => {
+                assert_eq!(*transaction_id, transaction.id);
+                assert_eq!(entries.len(), 1);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+        match &delivered[2].event {
+            LedgerEvent::BalanceChanged { account_id, balance, .. } => {
+                assert_eq!(*account_id, account.id);
+                assert_eq!(*balance, dec!(25.00));
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        // Nothing is delivered twice once marked
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 0);
+        let pending: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ledger_events WHERE delivered_at IS NULL")
+            .fetch_one(&pool)
+            .await
+            .expect("Failed to count events");
+        assert_eq!(pending, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_delivery_blocks_later_events_until_retried() {
+        let docker = Cli::default();
+        let (store, pool, _node) = setup_store(&docker).await;
+
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+        record_credit(&store, account.id, "outbox_002").await;
+
+        let sink = Arc::new(RecordingSink::new(1));
+        let relay = EventRelay::new(store.clone(), sink.clone());
+
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 0);
+        assert!(sink.event_types().is_empty());
+
+        let (attempts, last_error): (i32, Option<String>) = sqlx::query_as(
+            "SELECT attempts, last_error FROM ledger_events ORDER BY seq LIMIT 1",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to read event");
+        assert_eq!(attempts, 1);
+        assert!(last_error.is_some());
+
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 3);
+        assert_eq!(
+            sink.event_types(),
+            vec!["AccountCreated", "TransactionRecorded", "BalanceChanged"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_is_dead_lettered_after_max_attempts() {
+        let docker = Cli::default();
+        let (store, pool, _node) = setup_store(&docker).await;
+
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+        record_credit(&store, account.id, "outbox_004").await;
+
+        let sink = Arc::new(RecordingSink::new(2));
+        let relay = EventRelay::new(store.clone(), sink.clone()).with_max_attempts(2);
+
+        // The first failure holds everything back; the second is the last
+        // allowed attempt, so the event is set aside and the rest go out
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 0);
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 2);
+        assert_eq!(sink.event_types(), vec!["TransactionRecorded", "BalanceChanged"]);
+
+        let (event_type, attempts, dead_lettered): (String, i32, bool) = sqlx::query_as(
+            "SELECT event_type, attempts, dead_lettered_at IS NOT NULL FROM ledger_events WHERE delivered_at IS NULL",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("Failed to read event");
+        assert_eq!(event_type, "AccountCreated");
+        assert_eq!(attempts, 2);
+        assert!(dead_lettered);
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 0);
+
+        assert_eq!(store.replay_dead_letter_events().await.expect("Replay failed"), 1);
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 1);
+        assert_eq!(
+            sink.event_types(),
+            vec!["TransactionRecorded", "BalanceChanged", "AccountCreated"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_seq_follows_commit_order() {
+        let docker = Cli::default();
+        let (store, pool, _node) = setup_store(&docker).await;
+
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+        let sink = Arc::new(RecordingSink::new(0));
+        let relay = EventRelay::new(store.clone(), sink.clone());
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 1);
+
+        // A writer that queues its event before the posting but commits after it
+        let late = Account::new(AccountType::Asset, "USD");
+        let mut writer = pool.begin().await.expect("Failed to begin");
+        sqlx::query("INSERT INTO pending_ledger_events (id, event_type, aggregate_id, payload) VALUES ($1, $2, $3, $4)")
+            .bind(uuid::Uuid::new_v4())
+            .bind("AccountCreated")
+            .bind(late.id)
+            .bind(serde_json::to_value(LedgerEvent::account_created(&late)).unwrap())
+            .execute(&mut *writer)
+            .await
+            .expect("Failed to queue event");
+
+        record_credit(&store, account.id, "outbox_003").await;
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 2);
+
+        writer.commit().await.expect("Failed to commit");
+        assert_eq!(relay.run_once().await.expect("Relay failed"), 1);
+
+        // Delivered once visible, after the posting and with a larger seq
+        let delivered = sink.delivered.lock().unwrap().clone();
+        assert_eq!(delivered.len(), 4);
+        assert!(delivered.windows(2).all(|pair| pair[0].seq < pair[1].seq));
+        assert_eq!(delivered[3].event.aggregate_id(), late.id);
+    }
+
+    #[tokio::test]
+    async fn test_event_payload_cannot_be_rewritten() {
+        let docker = Cli::default();
+        let (store, pool, _node) = setup_store(&docker).await;
+
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+
+        let update = sqlx::query("UPDATE ledger_events SET payload = '{}'::jsonb")
+            .execute(&pool)
+            .await;
+        assert!(update.is_err(), "Event payloads must be immutable");
+    }
+}
+```
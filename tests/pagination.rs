@@ -0,0 +1,249 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    // Twenty-four postings touching `account` in every direction, three to
+    // each hour so that transaction timestamps tie, plus one that does not
+    // touch it at all
+    async fn history(store: &PostgresLedgerStore, account: Uuid, other: Uuid) -> Vec<Transaction> {
+        let base: chrono::DateTime<chrono::Utc> = "2026-03-01T00:00:00Z".parse().unwrap();
+        let mut posted = Vec::new();
+        for i in 0..24i64 {
+            let amount = Decimal::from(5 + i);
+            let (transaction_type, source, destination) = match i % 4 {
+                0 => (TransactionType::Credit, None, Some(account)),
+                1 => (TransactionType::Debit, Some(account), None),
+                2 => (TransactionType::Transfer, Some(account), Some(other)),
+                _ => (TransactionType::Transfer, Some(other), Some(account)),
+            };
+            let reason = if i % 3 == 0 { "fee" } else { "payment" };
+            let mut transaction = Transaction::new(transaction_type, amount, source, destination, reason, &format!("page_{}", i));
+            transaction.timestamp = base + chrono::Duration::hours(i / 3);
+            post(store, &transaction).await;
+            posted.push(transaction);
+        }
+
+        let mut unrelated = Transaction::new(TransactionType::Credit, dec!(1.00), None, Some(other), "fee", "page_other");
+        unrelated.timestamp = base;
+        post(store, &unrelated).await;
+        posted
+    }
+
+    async fn post(store: &PostgresLedgerStore, transaction: &Transaction) {
+        let mut entries = Vec::new();
+        if let Some(source) = transaction.source_account_id {
+            entries.push(Entry::new(transaction.id, source, transaction.amount, EntryType::Debit, Decimal::ZERO));
+        }
+        if let Some(destination) = transaction.destination_account_id {
+            entries.push(Entry::new(transaction.id, destination, transaction.amount, EntryType::Credit, Decimal::ZERO));
+        }
+        for entry in entries.iter_mut() {
+            entry.timestamp = transaction.timestamp;
+        }
+        store.record_transaction(transaction, &entries).await.unwrap();
+    }
+
+    // Follows next_cursor to the end, checking that no page is oversized
+    // and that only the first page can be empty
+    async fn transaction_ids(store: &PostgresLedgerStore, account: Uuid, filter: &TransactionFilter, limit: i64) -> Vec<Uuid> {
+        let mut ids = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = store
+                .get_account_transactions_page(&account, filter, cursor.as_ref(), limit)
+                .await
+                .unwrap();
+            assert!(page.items.len() as i64 <= limit);
+            assert!(!page.items.is_empty() || ids.is_empty());
+            ids.extend(page.items.iter().map(|transaction| transaction.id));
+            match page.next_cursor {
+                Some(next) => cursor = Some(Cursor::decode(&next).unwrap()),
+                None => return ids,
+            }
+        }
+    }
+
+    async fn entry_ids(store: &PostgresLedgerStore, account: Uuid, filter: &EntryFilter, limit: i64) -> Vec<Uuid> {
+        let mut ids = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = store
+                .get_account_entries(&account, filter, cursor.as_ref(), limit)
+                .await
+                .unwrap();
+            assert!(page.items.len() as i64 <= limit);
+            assert!(!page.items.is_empty() || ids.is_empty());
+            ids.extend(page.items.iter().map(|entry| entry.entry.id));
+            match page.next_cursor {
+                Some(next) => cursor = Some(Cursor::decode(&next).unwrap()),
+                None => return ids,
+            }
+        }
+    }
+
+    #[test]
+    fn test_cursor_round_trips() {
+        let cursor = Cursor::new("2026-03-01T12:30:00.123456Z".parse().unwrap(), Uuid::new_v4());
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_malformed_cursors_are_rejected() {
+        // Cursors come from clients; none of these may panic
+        for encoded in ["aéb", "é", "abc", "zz", "", "3a"] {
+            assert!(
+                matches!(Cursor::decode(encoded), Err(LedgerError::InvalidCursor)),
+                "{:?} was accepted",
+                encoded
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transaction_pages_match_the_filtered_history() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+        let account = Account::new(AccountType::Liability, "USD");
+        let other = Account::new(AccountType::Liability, "USD");
+        store.create_account(&account).await.unwrap();
+        store.create_account(&other).await.unwrap();
+        let posted = history(&store, account.id, other.id).await;
+
+        let base: chrono::DateTime<chrono::Utc> = "2026-03-01T00:00:00Z".parse().unwrap();
+        let filters = vec![
+            TransactionFilter::default(),
+            TransactionFilter {
+                from: Some(base + chrono::Duration::hours(2)),
+                to: Some(base + chrono::Duration::hours(5)),
+                ..Default::default()
+            },
+            TransactionFilter { transaction_type: Some(TransactionType::Transfer), ..Default::default() },
+            TransactionFilter { reason_code: Some("fee".to_string()), ..Default::default() },
+            TransactionFilter { min_amount: Some(dec!(10)), max_amount: Some(dec!(20)), ..Default::default() },
+            TransactionFilter { direction: Some(Direction::Incoming), ..Default::default() },
+            TransactionFilter { direction: Some(Direction::Outgoing), ..Default::default() },
+            TransactionFilter {
+                transaction_type: Some(TransactionType::Transfer),
+                direction: Some(Direction::Incoming),
+                min_amount: Some(dec!(10)),
+                ..Default::default()
+            },
+            TransactionFilter { reason_code: Some("missing".to_string()), ..Default::default() },
+        ];
+
+        for filter in &filters {
+            let mut expected: Vec<&Transaction> = posted
+                .iter()
+                .filter(|t| filter.from.into_iter().all(|from| t.timestamp > from))
+                .filter(|t| filter.to.into_iter().all(|to| t.timestamp <= to))
+                .filter(|t| filter.transaction_type.into_iter().all(|kind| t.transaction_type == kind))
+                .filter(|t| filter.reason_code.iter().all(|reason| &t.reason_code == reason))
+                .filter(|t| filter.min_amount.into_iter().all(|min| t.amount >= min))
+                .filter(|t| filter.max_amount.into_iter().all(|max| t.amount <= max))
+                .filter(|t| match filter.direction {
+                    Some(Direction::Incoming) => t.destination_account_id == Some(account.id),
+                    Some(Direction::Outgoing) => t.source_account_id == Some(account.id),
+                    None => true,
+                })
+                .collect();
+            expected.sort_by_key(|t| std::cmp::Reverse((t.timestamp, t.id)));
+            let expected: Vec<Uuid> = expected.iter().map(|t| t.id).collect();
+
+            // Page sizes that split tied timestamps, divide the result
+            // evenly and exceed it
+            for limit in [1, 2, 4, 7, 100] {
+                assert_eq!(
+                    transaction_ids(&store, account.id, filter, limit).await,
+                    expected,
+                    "{:?} with limit {}",
+                    filter,
+                    limit
+                );
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entry_pages_match_the_filtered_history_in_both_orders() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+        let account = Account::new(AccountType::Liability, "USD");
+        let other = Account::new(AccountType::Liability, "USD");
+        store.create_account(&account).await.unwrap();
+        store.create_account(&other).await.unwrap();
+        let posted = history(&store, account.id, other.id).await;
+
+        // Entries as stored, since the store may move a timestamp forward to
+        // keep the account's entries strictly ordered
+        let mut stored = Vec::new();
+        for transaction in &posted {
+            for entry in store.get_entries_for_transaction(&transaction.id).await.unwrap() {
+                if entry.account_id == account.id {
+                    stored.push((entry, transaction));
+                }
+            }
+        }
+
+        let base: chrono::DateTime<chrono::Utc> = "2026-03-01T00:00:00Z".parse().unwrap();
+        let filters = vec![
+            EntryFilter::default(),
+            EntryFilter {
+                from: Some(base + chrono::Duration::hours(2)),
+                to: Some(base + chrono::Duration::hours(5)),
+                ..Default::default()
+            },
+            EntryFilter { entry_type: Some(EntryType::Debit), ..Default::default() },
+            EntryFilter { transaction_type: Some(TransactionType::Credit), ..Default::default() },
+            EntryFilter { reason_code: Some("fee".to_string()), ..Default::default() },
+            EntryFilter { min_amount: Some(dec!(10)), max_amount: Some(dec!(20)), ..Default::default() },
+            EntryFilter {
+                entry_type: Some(EntryType::Credit),
+                transaction_type: Some(TransactionType::Transfer),
+                max_amount: Some(dec!(20)),
+                ..Default::default()
+            },
+        ];
+
+        for filter in filters {
+            for order in [SortOrder::NewestFirst, SortOrder::OldestFirst] {
+                let filter = EntryFilter { order, ..filter.clone() };
+                let mut expected: Vec<&(Entry, &Transaction)> = stored
+                    .iter()
+                    .filter(|(e, _)| filter.from.into_iter().all(|from| e.timestamp > from))
+                    .filter(|(e, _)| filter.to.into_iter().all(|to| e.timestamp <= to))
+                    .filter(|(e, _)| filter.entry_type.into_iter().all(|kind| e.entry_type == kind))
+                    .filter(|(_, t)| filter.transaction_type.into_iter().all(|kind| t.transaction_type == kind))
+                    .filter(|(_, t)| filter.reason_code.iter().all(|reason| &t.reason_code == reason))
+                    .filter(|(e, _)| filter.min_amount.into_iter().all(|min| e.amount >= min))
+                    .filter(|(e, _)| filter.max_amount.into_iter().all(|max| e.amount <= max))
+                    .collect();
+                expected.sort_by_key(|(e, _)| (e.timestamp, e.id));
+                if order == SortOrder::NewestFirst {
+                    expected.reverse();
+                }
+                let expected: Vec<Uuid> = expected.iter().map(|(e, _)| e.id).collect();
+
+                for limit in [1, 3, 100] {
+                    assert_eq!(
+                        entry_ids(&store, account.id, &filter, limit).await,
+                        expected,
+                        "{:?} with limit {}",
+                        filter,
+                        limit
+                    );
+                }
+            }
+        }
+    }
+}
+```
@@ -0,0 +1,110 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    async fn record_transfer(store: &PostgresLedgerStore, from: Uuid, to: Uuid, amount: Decimal, key: &str) {
+        let transaction = Transaction::new(TransactionType::Transfer, amount, Some(from), Some(to), "settlement", key);
+        let entries = vec![
+            Entry::new(transaction.id, from, amount, EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, amount, EntryType::Credit, Decimal::ZERO),
+        ];
+        store.record_transaction(&transaction, &entries).await.unwrap();
+    }
+
+    fn totals(account_type: AccountType, debit_total: Decimal, credit_total: Decimal) -> AccountTotals {
+        AccountTotals {
+            account_id: Uuid::new_v4(),
+            account_type,
+            code: None,
+            parent_code: None,
+            name: None,
+            debit_total,
+            credit_total,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trial_balance_of_double_entry_postings_balances() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+
+        let cash = Account::new(AccountType::Asset, "USD");
+        let wallet = Account::new(AccountType::Liability, "USD");
+        let fees = Account::new(AccountType::Revenue, "USD");
+        let euros = Account::new(AccountType::Asset, "EUR");
+        for account in [&cash, &wallet, &fees, &euros] {
+            store.create_account(account).await.unwrap();
+        }
+        let before = chrono::Utc::now();
+        record_transfer(&store, cash.id, wallet.id, dec!(100.00), "tb_1").await;
+        record_transfer(&store, wallet.id, fees.id, dec!(10.00), "tb_2").await;
+
+        // Other currencies are left out
+        let report = trial_balance(&store, chrono::Utc::now(), "USD").await.unwrap();
+        assert_eq!(report.lines.len(), 3);
+        assert!(report.lines.iter().all(|line| line.account_id != euros.id));
+        assert_eq!(report.total_debits, dec!(110.00));
+        assert_eq!(report.total_credits, dec!(110.00));
+        assert!(report.is_balanced);
+
+        // Net follows the ledger's sign, so it agrees with the balance
+        for line in &report.lines {
+            assert_eq!(line.net_balance, store.get_account_balance(&line.account_id).await.unwrap());
+        }
+        let wallet_line = report.lines.iter().find(|line| line.account_id == wallet.id).unwrap();
+        assert_eq!(wallet_line.debit_total, dec!(10.00));
+        assert_eq!(wallet_line.credit_total, dec!(100.00));
+        assert_eq!(wallet_line.net_balance, dec!(90.00));
+        assert!(report.to_csv().lines().last().unwrap().starts_with("TOTAL,,"));
+
+        // Accounts are listed even before they have any entries
+        let report = trial_balance(&store, before, "USD").await.unwrap();
+        assert_eq!(report.lines.len(), 3);
+        assert!(report.lines.iter().all(|line| line.net_balance == Decimal::ZERO));
+        assert!(report.is_balanced);
+    }
+
+    #[test]
+    fn test_unbalanced_trial_balance_renders() {
+        let as_of = "2026-03-31T23:59:59Z".parse().unwrap();
+        let cash = totals(AccountType::Asset, dec!(100.00), dec!(0));
+        let wallet = totals(AccountType::Liability, dec!(0), dec!(90.00));
+        let report = TrialBalance::from_totals(as_of, "USD", vec![cash.clone(), wallet.clone()]);
+
+        assert_eq!(report.total_debits, dec!(100.00));
+        assert_eq!(report.total_credits, dec!(90.00));
+        assert!(!report.is_balanced);
+        assert_eq!(report.lines[0].net_balance, dec!(-100.00));
+        assert_eq!(report.lines[1].net_balance, dec!(90.00));
+
+        assert_eq!(
+            report.to_csv(),
+            format!(
+                "account_id,account_type,debit_total,credit_total,net_balance\n\
+                 {},Asset,100.00,0,-100.00\n\
+                 {},Liability,0,90.00,90.00\n\
+                 TOTAL,,100.00,90.00,-10.00\n",
+                cash.account_id, wallet.account_id
+            )
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["currency"], "USD");
+        assert_eq!(json["is_balanced"], false);
+        assert_eq!(json["total_debits"], "100.00");
+        assert_eq!(json["total_credits"], "90.00");
+        assert_eq!(json["lines"].as_array().unwrap().len(), 2);
+        assert_eq!(json["lines"][1]["account_id"], wallet.account_id.to_string());
+        assert_eq!(json["lines"][1]["net_balance"], "90.00");
+    }
+}
+```
@@ -0,0 +1,113 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use sqlx::PgPool;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    async fn shard_rows(pool: &PgPool, account_id: Uuid) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM account_current_balances WHERE account_id = $1")
+            .bind(account_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sharded_account_aggregates_balance() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let service = Arc::new(LedgerService::new(Box::new(PostgresLedgerStore::new(pool.clone()))));
+
+        let fees = service.create_account(AccountType::Revenue, "USD").await.unwrap();
+        service.set_balance_shards(fees.id, 8).await.unwrap();
+
+        let mut postings = tokio::task::JoinSet::new();
+        for i in 0..64 {
+            let service = service.clone();
+            postings.spawn(async move {
+                service
+                    .credit_account(fees.id, dec!(0.50), "fee", &format!("fee_{}", i))
+                    .await
+            });
+        }
+        while let Some(result) = postings.join_next().await {
+            result.unwrap().expect("Posting failed");
+        }
+
+        // Same account id, one balance, spread over more than one row
+        assert_eq!(service.get_account_balance(fees.id).await.unwrap(), dec!(32.00));
+        let rows = shard_rows(&pool, fees.id).await;
+        assert!(rows > 1);
+
+        // Every shard's balance_after runs on from its previous entry, with
+        // no value recorded twice
+        let store = PostgresLedgerStore::new(pool.clone());
+        let report = verify_ledger(&store).await.unwrap();
+        assert!(report.is_clean(), "{:?}", report.violations);
+        let duplicates: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM (
+                SELECT 1 FROM entries WHERE account_id = $1
+                GROUP BY balance_shard, balance_after HAVING COUNT(*) > 1
+            ) d
+            "#,
+        )
+        .bind(fees.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(duplicates, 0);
+
+        // Turning sharding off sends new postings to shard 0 and leaves the
+        // balance alone
+        service.set_balance_shards(fees.id, 1).await.unwrap();
+        assert_eq!(service.get_account_balance(fees.id).await.unwrap(), dec!(32.00));
+
+        let fee = service.credit_account(fees.id, dec!(0.50), "fee", "fee_after").await.unwrap();
+        assert_eq!(service.get_account_balance(fees.id).await.unwrap(), dec!(32.50));
+        assert_eq!(shard_rows(&pool, fees.id).await, rows);
+        let entries = store.get_entries_for_transaction(&fee.id).await.unwrap();
+        assert_eq!(entries[0].balance_shard, 0);
+
+        let now = chrono::Utc::now();
+        let statement = account_statement(&store, fees.id, now - chrono::Duration::hours(1), now)
+            .await
+            .unwrap();
+        assert_eq!(statement.closing_balance, dec!(32.50));
+        assert!(statement.lines.iter().all(|line| !line.balance_after_mismatch));
+        let report = verify_ledger(&store).await.unwrap();
+        assert!(report.is_clean(), "{:?}", report.violations);
+    }
+
+    #[tokio::test]
+    async fn test_shard_count_is_validated() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+
+        let account = Account::new(AccountType::Liability, "USD");
+        store.create_account(&account).await.unwrap();
+        assert_eq!(store.get_account_balance_shards(&account.id).await.unwrap(), 1);
+
+        assert!(matches!(
+            store.set_account_balance_shards(&account.id, 0).await,
+            Err(LedgerError::InvalidShardCount { shards: 0, .. })
+        ));
+        assert!(matches!(
+            store.set_account_balance_shards(&account.id, MAX_BALANCE_SHARDS + 1).await,
+            Err(LedgerError::InvalidShardCount { .. })
+        ));
+        assert!(matches!(
+            store.set_account_balance_shards(&Uuid::new_v4(), 4).await,
+            Err(LedgerError::AccountNotFound)
+        ));
+    }
+}
+```
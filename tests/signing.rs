@@ -0,0 +1,146 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use sqlx::Executor;
+    use testcontainers::clients::Cli;
+    use uuid::Uuid;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("ledger-{}-{}.json", name, Uuid::new_v4()))
+    }
+
+    async fn record_transfer(store: &PostgresLedgerStore, from: Uuid, to: Uuid, key: &str) -> Transaction {
+        let transaction = Transaction::new(TransactionType::Transfer, dec!(25.00), Some(from), Some(to), "settlement", key);
+        let entries = vec![
+            Entry::new(transaction.id, from, dec!(25.00), EntryType::Debit, Decimal::ZERO),
+            Entry::new(transaction.id, to, dec!(25.00), EntryType::Credit, Decimal::ZERO),
+        ];
+        store.record_transaction(&transaction, &entries).await.unwrap();
+        transaction
+    }
+
+    #[test]
+    fn test_keystore_and_keyring_files_round_trip() {
+        let signer = TransactionSigner::generate("ledger-node-1");
+        let keystore = temp_path("keystore");
+        signer.write_keystore_file(&keystore).unwrap();
+
+        // An existing keystore is never overwritten
+        assert!(matches!(
+            TransactionSigner::generate("ledger-node-2").write_keystore_file(&keystore),
+            Err(SigningError::Io(_))
+        ));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&keystore).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let loaded = TransactionSigner::from_keystore_file(&keystore).unwrap();
+        assert_eq!(loaded.key_id(), "ledger-node-1");
+        assert_eq!(loaded.verifying_key(), signer.verifying_key());
+
+        let keyring_path = temp_path("keyring");
+        std::fs::write(
+            &keyring_path,
+            serde_json::json!([
+                { "key_id": "ledger-node-1", "public_key": hex::encode(signer.verifying_key().to_bytes()) },
+            ])
+            .to_string(),
+        )
+        .unwrap();
+        let keyring = Keyring::from_file(&keyring_path).unwrap();
+
+        // What the reloaded key signs verifies against the published key
+        let from = Uuid::new_v4();
+        let to = Uuid::new_v4();
+        let transaction = Transaction::new(TransactionType::Transfer, dec!(25.00), Some(from), Some(to), "settlement", "keystore");
+        let entries = vec![
+            Entry::new(transaction.id, from, dec!(25.00), EntryType::Debit, dec!(-25.00)),
+            Entry::new(transaction.id, to, dec!(25.00), EntryType::Credit, dec!(25.00)),
+        ];
+        let signature = loaded.sign(&transaction, &entries);
+        assert_eq!(signature.key_id, "ledger-node-1");
+        keyring.verify(&transaction, &entries, &signature).unwrap();
+
+        let invalid = temp_path("invalid");
+        std::fs::write(&invalid, r#"{"key_id": "ledger-node-1", "secret_key": "abcd"}"#).unwrap();
+        assert!(matches!(
+            TransactionSigner::from_keystore_file(&invalid),
+            Err(SigningError::InvalidKeystore(_))
+        ));
+
+        for path in [keystore, keyring_path, invalid] {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recorded_signature_detects_tampering() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let signer = TransactionSigner::generate("ledger-node-1");
+        let mut keyring = Keyring::new();
+        keyring.insert("ledger-node-1", signer.verifying_key());
+        let store = PostgresLedgerStore::new(pool.clone()).with_signer(signer);
+        let unsigned_store = PostgresLedgerStore::new(pool.clone());
+
+        let clearing = Account::new(AccountType::Asset, "USD");
+        let merchant = Account::new(AccountType::Liability, "USD");
+        store.create_account(&clearing).await.unwrap();
+        store.create_account(&merchant).await.unwrap();
+
+        let signed = record_transfer(&store, clearing.id, merchant.id, "signed_1").await;
+        let other = record_transfer(&store, clearing.id, merchant.id, "signed_2").await;
+        let unsigned = record_transfer(&unsigned_store, clearing.id, merchant.id, "unsigned_1").await;
+
+        // The signature covers balance_after and the shard as the store wrote them
+        let valid = SignatureStatus::Valid { key_id: "ledger-node-1".to_string() };
+        assert_eq!(verify_recorded_signature(&store, &keyring, signed.id).await.unwrap(), valid);
+        assert_eq!(verify_recorded_signature(&store, &keyring, other.id).await.unwrap(), valid);
+        assert_eq!(
+            verify_recorded_signature(&store, &keyring, unsigned.id).await.unwrap(),
+            SignatureStatus::Unsigned
+        );
+        assert_eq!(
+            verify_recorded_signature(&store, &Keyring::new(), signed.id).await.unwrap(),
+            SignatureStatus::UnknownKey { key_id: "ledger-node-1".to_string() }
+        );
+        assert!(matches!(
+            verify_recorded_signature(&store, &keyring, Uuid::new_v4()).await,
+            Err(LedgerError::TransactionNotFound)
+        ));
+
+        // Another key published under the same id does not vouch for it
+        let mut impostor = Keyring::new();
+        impostor.insert("ledger-node-1", TransactionSigner::generate("ledger-node-1").verifying_key());
+        assert_eq!(
+            verify_recorded_signature(&store, &impostor, signed.id).await.unwrap(),
+            SignatureStatus::Invalid { key_id: "ledger-node-1".to_string() }
+        );
+
+        // Rows edited behind the triggers no longer match what was signed
+        let mut db_transaction = pool.begin().await.unwrap();
+        db_transaction.execute("SET LOCAL session_replication_role = replica").await.unwrap();
+        sqlx::query("UPDATE entries SET amount = 2500 WHERE transaction_id = $1")
+            .bind(signed.id)
+            .execute(&mut *db_transaction)
+            .await
+            .unwrap();
+        db_transaction.commit().await.unwrap();
+
+        assert_eq!(
+            verify_recorded_signature(&store, &keyring, signed.id).await.unwrap(),
+            SignatureStatus::Invalid { key_id: "ledger-node-1".to_string() }
+        );
+        assert_eq!(verify_recorded_signature(&store, &keyring, other.id).await.unwrap(), valid);
+    }
+}
+```
@@ -0,0 +1,106 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use tokio_stream::StreamExt;
+    use uuid::Uuid;
+
+    fn update_for(account_id: Uuid, balance: rust_decimal::Decimal) -> AccountUpdate {
+        AccountUpdate {
+            account_id,
+            transaction_id: Uuid::new_v4(),
+            entry_ids: Vec::new(),
+            balance,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcaster_filters_by_account() {
+        let broadcaster = AccountUpdateBroadcaster::new(16);
+        let watched = Uuid::new_v4();
+        let mut stream = broadcaster.subscribe(watched);
+
+        broadcaster.publish(update_for(Uuid::new_v4(), dec!(1)));
+        broadcaster.publish(update_for(watched, dec!(2)));
+
+        let update = stream.next().await.unwrap().unwrap();
+        assert_eq!(update.account_id, watched);
+        assert_eq!(update.balance, dec!(2));
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_is_told_it_lagged() {
+        let broadcaster = AccountUpdateBroadcaster::new(2);
+        let watched = Uuid::new_v4();
+        let mut stream = broadcaster.subscribe(watched);
+
+        for balance in 1..=4 {
+            broadcaster.publish(update_for(watched, balance.into()));
+        }
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(LedgerError::SubscriptionLagged { missed: 2 }))
+        ));
+        assert_eq!(stream.next().await.unwrap().unwrap().balance, dec!(3));
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_receive_committed_postings() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool.clone());
+        let service = LedgerService::new(Box::new(PostgresLedgerStore::new(pool)));
+
+        let watched = service.create_account(AccountType::Asset, "USD").await.unwrap();
+        let other = service.create_account(AccountType::Asset, "USD").await.unwrap();
+        let mut updates = service.subscribe(watched.id).await.expect("Failed to subscribe");
+
+        service.credit_account(other.id, dec!(5.00), "deposit", "sub_001").await.unwrap();
+        let transaction = service
+            .credit_account(watched.id, dec!(12.50), "deposit", "sub_002")
+            .await
+            .unwrap();
+
+        let update = tokio::time::timeout(Duration::from_secs(5), updates.next())
+            .await
+            .expect("No update received")
+            .expect("Stream ended")
+            .expect("Subscription failed");
+        assert_eq!(update.account_id, watched.id);
+        assert_eq!(update.transaction_id, transaction.id);
+        let entries = store.get_entries_for_transaction(&transaction.id).await.unwrap();
+        assert_eq!(update.entry_ids, [entries[0].id]);
+        assert_eq!(update.balance, service.get_account_balance(watched.id).await.unwrap());
+
+        assert!(matches!(
+            service.subscribe(Uuid::new_v4()).await,
+            Err(LedgerError::AccountNotFound)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_update_does_not_fail_the_posting() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = PostgresLedgerStore::new(pool);
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.unwrap();
+
+        // Enough legs on one account that its update exceeds what NOTIFY accepts
+        let transaction = Transaction::new(TransactionType::Adjustment, dec!(300), None, None, "migration", "sub_big");
+        let entries: Vec<Entry> = (0..300)
+            .map(|_| Entry::new(transaction.id, account.id, dec!(1), EntryType::Credit, dec!(0)))
+            .collect();
+        store.record_transaction(&transaction, &entries).await.unwrap();
+
+        assert_eq!(store.get_account_balance(&account.id).await.unwrap(), dec!(300));
+    }
+}
+```
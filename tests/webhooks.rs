@@ -0,0 +1,228 @@
+```rust
+mod common;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+    use ledger_core::*;
+    use super::common;
+    use rust_decimal_macros::dec;
+    use testcontainers::clients::Cli;
+    use testcontainers_postgres::Postgres;
+
+    // Local stand-in for a subscriber: records each request and fails the
+    // first `failures` of them with a 503
+    #[derive(Clone, Default)]
+    struct StandIn {
+        requests: Arc<Mutex<Vec<(HeaderMap, Vec<u8>)>>>,
+        failures: Arc<AtomicUsize>,
+    }
+
+    async fn receive(State(stand_in): State<StandIn>, headers: HeaderMap, body: axum::body::Bytes) -> StatusCode {
+        stand_in.requests.lock().unwrap().push((headers, body.to_vec()));
+        let remaining = stand_in.failures.load(Ordering::SeqCst);
+        if remaining > 0 {
+            stand_in.failures.store(remaining - 1, Ordering::SeqCst);
+            return StatusCode::SERVICE_UNAVAILABLE;
+        }
+        StatusCode::NO_CONTENT
+    }
+
+    async fn start_stand_in(failures: usize) -> (StandIn, String) {
+        let stand_in = StandIn::default();
+        stand_in.failures.store(failures, Ordering::SeqCst);
+        let app = Router::new().route("/hook", post(receive)).with_state(stand_in.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (stand_in, url)
+    }
+
+    async fn setup_store(docker: &Cli) -> (Arc<PostgresLedgerStore>, testcontainers::Container<'_, Postgres>) {
+        let (pool, node) = common::setup_pool(docker).await;
+        (Arc::new(PostgresLedgerStore::new(pool)), node)
+    }
+
+    async fn post_credit(store: &Arc<PostgresLedgerStore>) {
+        let account = Account::new(AccountType::Asset, "USD");
+        store.create_account(&account).await.expect("Failed to create account");
+        let transaction = Transaction::new(
+            TransactionType::Credit,
+            dec!(40.00),
+            None,
+            Some(account.id),
+            "deposit",
+            "webhook_001",
+        );
+        let entries = vec![Entry::new(transaction.id, account.id, dec!(40.00), EntryType::Credit, dec!(40.00))];
+        store
+            .record_transaction(&transaction, &entries)
+            .await
+            .expect("Failed to record transaction");
+
+        let relay = EventRelay::new(store.clone(), Arc::new(WebhookFanout::new(store.clone())));
+        relay.run_once().await.expect("Relay failed");
+    }
+
+    fn no_delay_policy(max_attempts: i32) -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::ZERO,
+            max_delay: Duration::ZERO,
+            max_attempts,
+        }
+    }
+
+    #[test]
+    fn test_signature_round_trip() {
+        let now = chrono::Utc::now();
+        let header = sign_payload("secret", now.timestamp(), b"{\"a\":1}");
+        let tolerance = Duration::from_secs(300);
+
+        assert!(verify_signature("secret", &header, b"{\"a\":1}", now, tolerance));
+        assert!(!verify_signature("other", &header, b"{\"a\":1}", now, tolerance));
+        assert!(!verify_signature("secret", &header, b"{\"a\":2}", now, tolerance));
+        assert!(!verify_signature(
+            "secret",
+            &header,
+            b"{\"a\":1}",
+            now + chrono::Duration::minutes(10),
+            tolerance
+        ));
+        assert!(!verify_signature("secret", "garbage", b"{\"a\":1}", now, tolerance));
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_secs(10),
+            max_delay: Duration::from_secs(60),
+            max_attempts: 5,
+        };
+        let delays: Vec<u64> = (1..=5).map(|n| policy.delay_after(n).as_secs()).collect();
+        assert_eq!(delays, vec![10, 20, 40, 60, 60]);
+
+        let now = chrono::Utc::now();
+        let attempt = policy.failed_attempt(4, Some(500), "boom".to_string(), now);
+        assert_eq!(attempt.status, DeliveryStatus::Pending);
+        let attempt = policy.failed_attempt(5, Some(500), "boom".to_string(), now);
+        assert_eq!(attempt.status, DeliveryStatus::DeadLetter);
+    }
+
+    #[tokio::test]
+    async fn test_filtered_events_are_delivered_signed() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (stand_in, url) = start_stand_in(0).await;
+
+        let subscription = WebhookSubscription::new(&url, &["TransactionRecorded"]);
+        store
+            .create_webhook_subscription(&subscription)
+            .await
+            .expect("Failed to subscribe");
+        post_credit(&store).await;
+
+        let dispatcher = WebhookDispatcher::new(store.clone());
+        let summary = dispatcher.run_once().await.expect("Dispatch failed");
+        assert_eq!(summary.delivered, 1);
+
+        let requests = stand_in.requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 1, "Only the subscribed event type is delivered");
+        let (headers, body) = &requests[0];
+        assert_eq!(headers[EVENT_TYPE_HEADER], "TransactionRecorded");
+        let signature = headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert!(verify_signature(
+            &subscription.secret,
+            signature,
+            body,
+            chrono::Utc::now(),
+            Duration::from_secs(300)
+        ));
+
+        // Delivered events are not sent again
+        assert_eq!(dispatcher.run_once().await.unwrap(), DispatchSummary::default());
+    }
+
+    #[tokio::test]
+    async fn test_attempts_are_stored_on_the_delivery() {
+        let docker = Cli::default();
+        let (pool, _node) = common::setup_pool(&docker).await;
+        let store = Arc::new(PostgresLedgerStore::new(pool.clone()));
+        let (_stand_in, url) = start_stand_in(1).await;
+
+        let subscription = WebhookSubscription::new(&url, &["AccountCreated"]);
+        store
+            .create_webhook_subscription(&subscription)
+            .await
+            .expect("Failed to subscribe");
+        post_credit(&store).await;
+
+        let stored = || async {
+            sqlx::query_as::<_, (String, i32, Option<i32>, Option<chrono::DateTime<chrono::Utc>>)>(
+                "SELECT status, attempts, last_status_code, delivered_at FROM webhook_deliveries",
+            )
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        };
+
+        let dispatcher = WebhookDispatcher::new(store.clone()).with_retry_policy(no_delay_policy(3));
+        assert_eq!(dispatcher.run_once().await.unwrap().retrying, 1);
+        let (status, attempts, last_status_code, delivered_at) = stored().await;
+        assert_eq!((status.as_str(), attempts, last_status_code), ("Pending", 1, Some(503)));
+        assert!(delivered_at.is_none());
+
+        assert_eq!(dispatcher.run_once().await.unwrap().delivered, 1);
+        let (status, attempts, last_status_code, delivered_at) = stored().await;
+        assert_eq!((status.as_str(), attempts, last_status_code), ("Delivered", 2, Some(204)));
+        assert!(delivered_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_retried_then_dead_lettered_and_replayed() {
+        let docker = Cli::default();
+        let (store, _node) = setup_store(&docker).await;
+        let (stand_in, url) = start_stand_in(3).await;
+
+        let subscription = WebhookSubscription::new(&url, &["AccountCreated"]);
+        store
+            .create_webhook_subscription(&subscription)
+            .await
+            .expect("Failed to subscribe");
+        post_credit(&store).await;
+
+        let dispatcher = WebhookDispatcher::new(store.clone()).with_retry_policy(no_delay_policy(2));
+        let summary = dispatcher.run_once().await.unwrap();
+        assert_eq!(summary.retrying, 1);
+        let summary = dispatcher.run_once().await.unwrap();
+        assert_eq!(summary.dead_lettered, 1);
+        assert_eq!(dispatcher.run_once().await.unwrap(), DispatchSummary::default());
+
+        let dead = store
+            .list_webhook_deliveries(&subscription.id, Some(DeliveryStatus::DeadLetter), 10)
+            .await
+            .unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(dead[0].last_status_code, Some(503));
+
+        // A replayed delivery starts over with a fresh retry budget
+        assert_eq!(replay_dead_letters(store.as_ref(), subscription.id).await.unwrap(), 1);
+        let summary = dispatcher.run_once().await.unwrap();
+        assert_eq!(summary.retrying, 1);
+        let summary = dispatcher.run_once().await.unwrap();
+        assert_eq!(summary.delivered, 1);
+        assert_eq!(stand_in.requests.lock().unwrap().len(), 4);
+
+        let delivered = store
+            .list_webhook_deliveries(&subscription.id, Some(DeliveryStatus::Delivered), 10)
+            .await
+            .unwrap();
+        assert_eq!(delivered.len(), 1);
+        assert!(replay_delivery(store.as_ref(), subscription.id, delivered[0].id).await.unwrap());
+        assert_eq!(dispatcher.run_once().await.unwrap().delivered, 1);
+    }
+}
+```